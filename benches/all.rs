@@ -1,5 +1,186 @@
-#[expect(clippy::missing_const_for_fn, reason = "Not authored yet")]
+use std::fmt::{self, Display, Write as _};
+use std::hint::black_box;
+
+use mqi::admin::{BagTree, BagTreeItem, BagTreeValue};
+use mqi::values::{MqaiSelector, MQCC, MQRC};
+use mqi::{mapping, sys, ConstLookup, ConstantItem};
+
 fn main() {
     // Run registered benchmarks.
-    //divan::main();
+    divan::main();
+}
+
+/// Selector lookup chaining the 8 constant sets, as it was before the combined set was generated
+struct ChainedLookup;
+
+impl ConstLookup for ChainedLookup {
+    fn by_value(&self, value: sys::MQLONG) -> impl Iterator<Item = &str> {
+        mapping::MQIA_CONST
+            .by_value(value)
+            .chain(mapping::MQCA_CONST.by_value(value))
+            .chain(mapping::MQIACF_CONST.by_value(value))
+            .chain(mapping::MQCACF_CONST.by_value(value))
+            .chain(mapping::MQIACH_CONST.by_value(value))
+            .chain(mapping::MQCACH_CONST.by_value(value))
+            .chain(mapping::MQIASY_CONST.by_value(value))
+            .chain(mapping::MQHA_CONST.by_value(value))
+    }
+
+    fn by_name(&self, name: &str) -> Option<sys::MQLONG> {
+        mapping::MQIA_CONST
+            .by_name(name)
+            .or_else(|| mapping::MQCA_CONST.by_name(name))
+            .or_else(|| mapping::MQIACF_CONST.by_name(name))
+            .or_else(|| mapping::MQCACF_CONST.by_name(name))
+            .or_else(|| mapping::MQIACH_CONST.by_name(name))
+            .or_else(|| mapping::MQCACH_CONST.by_name(name))
+            .or_else(|| mapping::MQIASY_CONST.by_name(name))
+            .or_else(|| mapping::MQHA_CONST.by_name(name))
+    }
+
+    fn all(&self) -> impl Iterator<Item = ConstantItem> {
+        mapping::MQIA_CONST
+            .all()
+            .chain(mapping::MQCA_CONST.all())
+            .chain(mapping::MQIACF_CONST.all())
+            .chain(mapping::MQCACF_CONST.all())
+            .chain(mapping::MQIACH_CONST.all())
+            .chain(mapping::MQCACH_CONST.all())
+            .chain(mapping::MQIASY_CONST.all())
+            .chain(mapping::MQHA_CONST.all())
+    }
+}
+
+/// Selector rendered through a lookup, as the `Display` of `MqaiSelector` does with the combined set
+struct Selector<'a, L>(&'a L, sys::MQLONG);
+
+impl<L: ConstLookup> Display for Selector<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let &Self(lookup, value) = self;
+        match lookup.by_value(value).next() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Selectors spread across the constant sets, with the later sets costing the most to find when chained
+const SELECTORS: &[sys::MQLONG] = &[
+    sys::MQIA_CURRENT_Q_DEPTH,
+    sys::MQCA_Q_NAME,
+    sys::MQIACF_Q_STATUS_TYPE,
+    sys::MQCACF_LAST_GET_DATE,
+    sys::MQIACH_MSGS,
+    sys::MQCACH_CHANNEL_NAME,
+    sys::MQIASY_COMP_CODE,
+    sys::MQHA_BAG_HANDLE,
+];
+
+#[divan::bench]
+fn selector_chained() -> usize {
+    SELECTORS
+        .iter()
+        .filter_map(|&selector| ChainedLookup.by_value(black_box(selector)).next())
+        .count()
+}
+
+#[divan::bench]
+fn selector_combined() -> usize {
+    SELECTORS
+        .iter()
+        .filter_map(|&selector| mapping::MQAI_SELECTOR_CONST.by_value(black_box(selector)).next())
+        .count()
+}
+
+#[divan::bench]
+fn selector_display() -> String {
+    SELECTORS
+        .iter()
+        .map(|&selector| MqaiSelector(black_box(selector)).to_string())
+        .collect()
+}
+
+#[divan::bench]
+fn selector_display_chained() -> String {
+    SELECTORS
+        .iter()
+        .map(|&selector| Selector(&ChainedLookup, black_box(selector)).to_string())
+        .collect()
+}
+
+/// A typical queue status response, with a nested group bag for each queue
+fn status_response() -> BagTree {
+    let queue = BagTree(
+        SELECTORS[..6]
+            .iter()
+            .map(|&selector| BagTreeItem {
+                selector: MqaiSelector(selector),
+                value: BagTreeValue::Integer(1),
+            })
+            .collect(),
+    );
+    BagTree(
+        [
+            BagTreeItem {
+                selector: MqaiSelector(sys::MQIASY_COMP_CODE),
+                value: BagTreeValue::Integer(sys::MQCC_OK),
+            },
+            BagTreeItem {
+                selector: MqaiSelector(sys::MQIASY_REASON),
+                value: BagTreeValue::Integer(sys::MQRC_NONE),
+            },
+        ]
+        .into_iter()
+        .chain((0..50).map(|_| BagTreeItem {
+            selector: MqaiSelector(sys::MQHA_BAG_HANDLE),
+            value: BagTreeValue::Group(queue.clone()),
+        }))
+        .collect(),
+    )
+}
+
+#[divan::bench]
+fn bag_dump(bencher: divan::Bencher) {
+    let tree = status_response();
+    bencher.bench(|| black_box(&tree).to_string());
+}
+
+/// Renders the status response in the layout of the `Display` of `BagTree`, resolving the selectors through `lookup`
+fn dump(tree: &BagTree, lookup: &impl ConstLookup, depth: usize, out: &mut String) -> fmt::Result {
+    for BagTreeItem { selector, value } in &tree.0 {
+        write!(out, "{:indent$}{}:", "", Selector(lookup, selector.0), indent = depth * 2)?;
+        match value {
+            BagTreeValue::Group(group) => {
+                out.push('\n');
+                dump(group, lookup, depth + 1, out)?;
+            }
+            &BagTreeValue::Integer(value) => match selector.0 {
+                sys::MQIASY_COMP_CODE => writeln!(out, " {} = {value}", MQCC(value))?,
+                sys::MQIASY_REASON => writeln!(out, " {} = {value}", MQRC(value))?,
+                _ => writeln!(out, " {value}")?,
+            },
+            _ => unreachable!("the status response only has integer and group items"),
+        }
+    }
+    Ok(())
+}
+
+#[divan::bench]
+fn bag_dump_chained(bencher: divan::Bencher) {
+    let tree = status_response();
+    bencher.bench(|| {
+        let mut out = String::new();
+        dump(black_box(&tree), &ChainedLookup, 0, &mut out).expect("writing to a string should not fail");
+        out
+    });
+}
+
+#[divan::bench]
+fn bag_dump_combined(bencher: divan::Bencher) {
+    let tree = status_response();
+    bencher.bench(|| {
+        let mut out = String::new();
+        dump(black_box(&tree), &mapping::MQAI_SELECTOR_CONST, 0, &mut out).expect("writing to a string should not fail");
+        out
+    });
 }
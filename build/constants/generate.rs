@@ -57,6 +57,20 @@ fn as_phf(by_value: &[&(mqsys::MQLONG, &str)]) -> String {
     phf_set.build().to_string()
 }
 
+/// Lookups that combine the constants of several prefixes into one perfect hash map. The primary name of a value
+/// is taken from the first prefix in the list that defines it, so the selection doesn't depend on hash ordering.
+const COMBINED: &[(&str, &str, &[&str])] = &[
+    (
+        "MQAI_SELECTOR_",
+        "Selectors including MQIA, MQCA, MQIACF, MQCACF, MQIACH, MQCACH, MQIASY and MQHA",
+        &[
+            "MQIA_", "MQCA_", "MQIACF_", "MQCACF_", "MQIACH_", "MQCACH_", "MQIASY_", "MQHA_",
+        ],
+    ),
+    ("MQXA_FULL_", "Selectors for MQIA and MQCA combined", &["MQIA_", "MQCA_"]),
+    ("MQRC_FULL_", "Combined MQRC and MQRCCF range", &["MQRC_", "MQRCCF_"]),
+];
+
 pub fn generate(target: impl AsRef<Path>) {
     let mut file = BufWriter::new(File::create(target).expect("Failure to create MQ constants file: {target}"));
 
@@ -132,7 +146,7 @@ pub fn generate(target: impl AsRef<Path>) {
 
     // Pick a lookup type based on the size of the constants for a prefix
     // TODO: Determine best ranges for performance
-    for (prefix, (primary, ref extra)) in prefix_constants {
+    for &(prefix, (primary, ref extra)) in &prefix_constants {
         write!(&mut file, "pub const {prefix}CONST: ").unwrap();
         match primary.len() {
             0..=63 => {
@@ -168,6 +182,31 @@ pub fn generate(target: impl AsRef<Path>) {
         }
     }
 
+    // Combined lookups over several prefixes
+    for &(name, doc, prefixes) in COMBINED {
+        let mut combined = Vec::new();
+        for prefix in prefixes {
+            let (primary, extra) = prefix_constants
+                .iter()
+                .find_map(|(p, constants)| (p == prefix).then_some(constants))
+                .expect("Combined lookup prefix should be in the constants list");
+            combined.extend(primary.iter().chain(extra).copied());
+        }
+        // Stable sort keeps the prefix order for the names of each value
+        combined.sort_by_key(|(value, ..)| *value);
+
+        let mut seen = HashSet::new();
+        let (primary, extra): (Vec<_>, Vec<_>) = combined.into_iter().partition(|(value, ..)| seen.insert(*value));
+        writeln!(&mut file, "/// {doc}").unwrap();
+        writeln!(
+            &mut file,
+            "pub const {name}CONST: PhfSource = ConstSource(&{}, &{});",
+            as_phf(&primary),
+            as_array(&extra)
+        )
+        .unwrap();
+    }
+
     // Full MQI_BY_STRING
     let mut mqi_by_string = phf_codegen::Map::<&str>::new();
     for (name, value) in by_name {
@@ -0,0 +1,80 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Generate the phf map of reason code explanations from the tab separated catalogue.
+///
+/// `constants` is the constant mapping the crate is built with. Each row name is checked against the value in
+/// the `MQRC_CONST` and `MQRCCF_CONST` mappings, and every value in those mappings must have a row, so a mistyped
+/// or missing row fails the build. Rows for constants the platform does not define are skipped.
+pub fn generate(source: impl AsRef<Path>, constants: impl AsRef<Path>, target: impl AsRef<Path>) -> Result<(), io::Error> {
+    let catalogue = fs::read_to_string(source)?;
+    let constants = fs::read_to_string(constants)?;
+    let reasons = ["MQRC_CONST", "MQRCCF_CONST"]
+        .into_iter()
+        .flat_map(|name| mapping_items(&constants, name))
+        .collect::<HashMap<_, _>>();
+    if reasons.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "no reason codes in the constant mapping",
+        ));
+    }
+    let mut missing = reasons.values().copied().collect::<BTreeSet<_>>();
+    let mut map = phf_codegen::Map::<i32>::new();
+
+    for (line_number, line) in catalogue.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = |reason: &str| io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {reason}", line_number + 1));
+        let [name, value, severity, explanation, response] = line.split('\t').collect::<Vec<_>>()[..] else {
+            return Err(invalid("expected 5 tab separated columns"));
+        };
+        if !(name.starts_with("MQRC_") || name.starts_with("MQRCCF_")) {
+            return Err(invalid("name must be a MQRC_ or MQRCCF_ constant"));
+        }
+        let value: i32 = value.parse().map_err(|_| invalid("reason code is not an integer"))?;
+        match reasons.get(name) {
+            Some(&expected) if expected != value => return Err(invalid(&format!("{name} is {expected}, not {value}"))),
+            Some(_) => (),
+            None => continue,
+        }
+        if !missing.remove(&value) {
+            return Err(invalid(&format!("reason code {value} is already in the catalogue")));
+        }
+        let severity = match severity {
+            "OK" => "MQCC_OK",
+            "WARNING" => "MQCC_WARNING",
+            "FAILED" => "MQCC_FAILED",
+            _ => return Err(invalid("completion code must be OK, WARNING or FAILED")),
+        };
+        map.entry(
+            value,
+            &format!("Explanation {{ severity: MQCC(sys::{severity}), explanation: {explanation:?}, response: {response:?} }}"),
+        );
+    }
+
+    if !missing.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("reason codes without a row in the catalogue: {missing:?}"),
+        ));
+    }
+
+    let mut file = BufWriter::new(File::create(target)?);
+    writeln!(file, "{}", map.build())
+}
+
+/// The `(name, value)` items of a `ConstSource` in the constant mapping source code
+fn mapping_items<'a>(constants: &'a str, name: &str) -> impl Iterator<Item = (&'a str, i32)> {
+    let source = constants
+        .split_once(&format!("pub const {name}:"))
+        .map_or("", |(.., tail)| tail.split_once("pub const ").map_or(tail, |(body, ..)| body));
+    source.split('(').filter_map(|item| {
+        let (value, tail) = item.split_once(',')?;
+        let (name, ..) = tail.trim_start().strip_prefix('"')?.split_once('"')?;
+        Some((name, value.trim().parse().ok()?))
+    })
+}
@@ -6,6 +6,11 @@ mod constants {
     mod list;
 }
 
+#[cfg(feature = "explain")]
+mod explain {
+    pub mod generate;
+}
+
 #[expect(clippy::allow_attributes)]
 fn main() -> Result<(), io::Error> {
     #[allow(unused_variables, reason = "Triggers when constantgen and pregen are not enabled")]
@@ -13,6 +18,34 @@ fn main() -> Result<(), io::Error> {
     #[cfg(feature = "constantgen")]
     constants::generate::generate(&path);
 
+    #[cfg(feature = "explain")]
+    {
+        println!("cargo:rerun-if-changed=build");
+        let constants = if cfg!(feature = "constantgen") {
+            path.clone()
+        } else {
+            println!("cargo:rerun-if-changed=src/constants/mapping/pregen");
+            // Same selection as the pregenerated mapping in src/constants/mapping/mod.rs
+            let target = |name| env::var(name).unwrap_or_default();
+            let pregen = match (
+                target("CARGO_CFG_TARGET_OS").as_str(),
+                target("CARGO_CFG_TARGET_ARCH").as_str(),
+            ) {
+                ("macos", _) => "any-macos",
+                ("windows", "x86_64") => "x86_64-windows",
+                ("linux", "x86_64") => "x86_64-linux",
+                (os, arch) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Unsupported,
+                        format!("no pregenerated constants for {arch}-{os}"),
+                    ))
+                }
+            };
+            std::path::PathBuf::from(format!("src/constants/mapping/pregen/{pregen}-mqconstants.rs"))
+        };
+        explain::generate::generate("build/explain/reasons.tsv", constants, path.with_file_name("mqexplain.rs"))?;
+    }
+
     #[cfg(feature = "pregen")]
     {
         use std::{env::consts as env_consts, fs, path};
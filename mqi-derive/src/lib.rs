@@ -0,0 +1,235 @@
+//! Derive macros for the `FromBag` and `IntoBag` traits of the [`mqi`](https://crates.io/crates/mqi) crate.
+//!
+//! Each field of the struct is mapped to a bag item with the `#[mq(...)]` attribute:
+//!
+//! | Attribute                       | Description |
+//! |---------------------------------|-------------|
+//! | `selector = "MQCA_Q_NAME"`      | Selector of the bag item, named by the `mqi::sys` constant |
+//! | `list`                          | The field is a `Vec` holding every item with the selector |
+//! | `group`                         | The item is a nested group bag, mapped with `FromBag` / `IntoBag` |
+//! | `skip`                          | The field isn't mapped. `FromBag` sets it to the `Default` value |
+//!
+//! Fields with an `Option` type are optional. The other field types must implement
+//! `BagItemGet` / `BagItemPut`, which includes the integer, string and filter types.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericArgument, Ident, LitStr, PathArguments, Type, TypePath};
+
+/// Derive `mqi::admin::FromBag`, reading each field from the bag item with the `#[mq(selector = "...")]` selector
+#[proc_macro_derive(FromBag, attributes(mq))]
+pub fn derive_from_bag(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, from_bag).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derive `mqi::admin::IntoBag`, adding each field to the bag as an item with the `#[mq(selector = "...")]` selector
+#[proc_macro_derive(IntoBag, attributes(mq))]
+pub fn derive_into_bag(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, into_bag).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+enum Shape<'a> {
+    Required(&'a Type),
+    Optional(&'a Type),
+    List(&'a Type),
+}
+
+struct BagField<'a> {
+    ident: &'a Ident,
+    /// `None` when the field is skipped
+    selector: Option<Ident>,
+    group: bool,
+    shape: Shape<'a>,
+}
+
+impl BagField<'_> {
+    fn selector(&self) -> Option<TokenStream2> {
+        self.selector
+            .as_ref()
+            .map(|selector| quote!(::mqi::values::MqaiSelector(::mqi::sys::#selector)))
+    }
+}
+
+fn expand(input: &DeriveInput, generate: fn(&DeriveInput, &[BagField]) -> TokenStream2) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "bag conversions can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "bag conversions can only be derived for structs with named fields",
+        ));
+    };
+    let fields = fields.named.iter().map(parse_field).collect::<syn::Result<Vec<_>>>()?;
+    Ok(generate(input, &fields))
+}
+
+fn parse_field(field: &syn::Field) -> syn::Result<BagField> {
+    let ident = field.ident.as_ref().expect("named fields should have an identifier");
+    let mut selector = None;
+    let mut list = false;
+    let mut group = false;
+    let mut skip = false;
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("mq")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("selector") {
+                let value: LitStr = meta.value()?.parse()?;
+                selector = Some(value.parse::<Ident>()?);
+            } else if meta.path.is_ident("list") {
+                list = true;
+            } else if meta.path.is_ident("group") {
+                group = true;
+            } else if meta.path.is_ident("skip") {
+                skip = true;
+            } else {
+                return Err(meta.error("unsupported `mq` attribute, expected `selector`, `list`, `group` or `skip`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let shape = if list {
+        Shape::List(
+            generic_argument(&field.ty, "Vec")
+                .ok_or_else(|| syn::Error::new_spanned(&field.ty, "`list` fields must have a `Vec` type"))?,
+        )
+    } else {
+        generic_argument(&field.ty, "Option").map_or(Shape::Required(&field.ty), Shape::Optional)
+    };
+
+    if selector.is_none() && !skip {
+        return Err(syn::Error::new_spanned(
+            field,
+            "missing `#[mq(selector = \"...\")]` or `#[mq(skip)]` attribute",
+        ));
+    }
+    Ok(BagField {
+        ident,
+        selector: selector.filter(|_| !skip),
+        group,
+        shape,
+    })
+}
+
+/// The type argument of a type such as `Option<T>` or `Vec<T>`
+fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last().filter(|segment| segment.ident == wrapper)?;
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first() {
+        Some(GenericArgument::Type(inner)) if arguments.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+fn from_bag(input: &DeriveInput, fields: &[BagField]) -> TokenStream2 {
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    generics.params.push(parse_quote!(__L: ::mqi::admin::MqaiLibrary));
+    let (impl_generics, ..) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let values = fields.iter().map(|field| {
+        let ident = field.ident;
+        let Some(selector) = field.selector() else {
+            return quote!(#ident: ::core::default::Default::default());
+        };
+        let missing = quote!(::mqi::admin::BagMapError::Missing(#selector));
+        let value = match (&field.shape, field.group) {
+            (Shape::Required(ty), false) => quote! {
+                ::mqi::ResultCompExt::warn_as_error(bag.inquire::<#ty>(#selector))?.ok_or(#missing)?
+            },
+            (Shape::Optional(ty), false) => quote! {
+                ::mqi::ResultCompExt::warn_as_error(bag.inquire::<#ty>(#selector))?
+            },
+            (Shape::List(ty), false) => quote! {
+                ::mqi::ResultCompExt::warn_as_error(bag.try_iter::<#ty>(#selector))?
+                    .map(::mqi::ResultCompExt::warn_as_error)
+                    .collect::<::core::result::Result<::std::vec::Vec<_>, _>>()?
+            },
+            (Shape::Required(ty), true) => quote! {
+                bag.inquire_group::<#ty>(#selector)?.ok_or(#missing)?
+            },
+            (Shape::Optional(ty), true) => quote! {
+                bag.inquire_group::<#ty>(#selector)?
+            },
+            (Shape::List(ty), true) => quote! {
+                ::mqi::ResultCompExt::warn_as_error(bag.try_bag_iter(#selector))?
+                    .map(|group| {
+                        <#ty as ::mqi::admin::FromBag<__L>>::from_bag(&::mqi::ResultCompExt::warn_as_error(group)?)
+                    })
+                    .collect::<::core::result::Result<::std::vec::Vec<_>, ::mqi::admin::BagMapError>>()?
+            },
+        };
+        quote!(#ident: #value)
+    });
+
+    quote! {
+        impl #impl_generics ::mqi::admin::FromBag<__L> for #name #ty_generics #where_clause {
+            fn from_bag<__B: ::mqi::admin::BagDrop>(
+                bag: &::mqi::admin::Bag<__B, __L>,
+            ) -> ::core::result::Result<Self, ::mqi::admin::BagMapError> {
+                ::core::result::Result::Ok(Self {
+                    #(#values,)*
+                })
+            }
+        }
+    }
+}
+
+fn into_bag(input: &DeriveInput, fields: &[BagField]) -> TokenStream2 {
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    generics.params.push(parse_quote!(__L: ::mqi::admin::MqaiLibrary));
+    let (impl_generics, ..) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let statements = fields.iter().filter_map(|field| {
+        let ident = field.ident;
+        let selector = field.selector()?;
+        let add = if field.group {
+            quote!(bag.add_group(#selector, value)?;)
+        } else {
+            quote!(::mqi::ResultCompExt::warn_as_error(bag.add(#selector, value))?;)
+        };
+        Some(match field.shape {
+            Shape::Required(_) => quote! {
+                let value = &self.#ident;
+                #add
+            },
+            Shape::Optional(_) => quote! {
+                if let ::core::option::Option::Some(value) = &self.#ident {
+                    #add
+                }
+            },
+            Shape::List(_) => quote! {
+                for value in &self.#ident {
+                    #add
+                }
+            },
+        })
+    });
+
+    quote! {
+        impl #impl_generics ::mqi::admin::IntoBag<__L> for #name #ty_generics #where_clause {
+            fn to_bag<__B: ::mqi::admin::BagDrop>(
+                &self,
+                bag: &::mqi::admin::Bag<__B, __L>,
+            ) -> ::core::result::Result<(), ::mqi::admin::BagMapError> {
+                #(#statements)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
@@ -14,6 +14,13 @@ pub trait BagDrop: Sized {
 use super::{BagItemGet, BagItemPut};
 
 impl MqaiSelector {
+    /// Selects or counts the items of every selector, user and system
+    pub const ANY: Self = Self(sys::MQSEL_ANY_SELECTOR);
+    /// Selects or counts the items of every user selector
+    pub const ANY_USER: Self = Self(sys::MQSEL_ANY_USER_SELECTOR);
+    /// Selects or counts the items of every system selector
+    pub const ANY_SYSTEM: Self = Self(sys::MQSEL_ANY_SYSTEM_SELECTOR);
+
     #[must_use]
     pub const fn with_index(self, index: MQIND) -> (Self, MQIND) {
         (self, index)
@@ -159,6 +166,7 @@ impl<B: BagDrop, L: Library<MQ: function::Mqai>> Drop for Bag<B, L> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::admin::BagValue;
     use crate::sys;
 
     #[test]
@@ -177,4 +185,29 @@ mod tests {
             .discard_warning()
             .expect("deletion of an item should not fail");
     }
+
+    #[test]
+    fn items() -> Result<(), Box<dyn std::error::Error>> {
+        let bag = Bag::new(MQCBO(sys::MQCBO_USER_BAG)).warn_as_error()?;
+        let depth: sys::MQLONG = 5;
+        bag.add(MqaiSelector(sys::MQIA_CURRENT_Q_DEPTH), &depth).warn_as_error()?;
+        bag.add(MqaiSelector(sys::MQCA_Q_NAME), "Q1").warn_as_error()?;
+
+        let items = bag.items().warn_as_error()?.collect::<Result<Vec<_>, _>>()?;
+        let user_items = items
+            .iter()
+            .map(|item| &item.0)
+            .filter(|(selector, ..)| selector.0 >= 0)
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            user_items.as_slice(),
+            [
+                (MqaiSelector(sys::MQIA_CURRENT_Q_DEPTH), BagValue::Integer(5)),
+                (MqaiSelector(sys::MQCA_Q_NAME), BagValue::String(_))
+            ]
+        ));
+        assert!(bag.tree()?.to_string().contains("MQCA_Q_NAME: 'Q1'"));
+
+        Ok(())
+    }
 }
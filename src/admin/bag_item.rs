@@ -2,12 +2,12 @@ use libmqm_sys::function;
 use std::fmt::Debug;
 
 use crate::core::mqai;
-use crate::values::{MqaiSelector, CCSID, MQIND};
+use crate::values::{MqaiSelector, CCSID, MQCC, MQIND, MQITEM, MQRC};
 use crate::core::Library;
 use crate::{prelude::*, StrCcsidOwned, StringCcsid, NATIVE_IS_LE};
 use crate::{sys, Completion, EncodedString, Error, MqStr, ResultComp, ResultCompErr, WithMqError};
 
-use super::{Bag, BagDrop};
+use super::{Bag, BagDrop, Embedded};
 
 #[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
 pub enum PutStringCcsidError {
@@ -380,3 +380,61 @@ impl<L: Library<MQ: function::Mqai>> BagItemGet<L> for mqai::Filter<Vec<sys::MQC
 
     type Error = crate::Error;
 }
+
+/// Item of any type in a bag, as returned by [`Bag::items`]
+#[derive(Debug)]
+pub enum BagValue<L: Library<MQ: function::Mqai>> {
+    Integer(sys::MQLONG),
+    Integer64(i64),
+    String(StrCcsidOwned),
+    ByteString(Vec<sys::MQCHAR>),
+    IntegerFilter(mqai::Filter<sys::MQLONG>),
+    StringFilter(mqai::Filter<StrCcsidOwned>),
+    ByteStringFilter(mqai::Filter<Vec<sys::MQCHAR>>),
+    Bag(Bag<Embedded, L>),
+}
+
+impl<L: Library<MQ: function::Mqai>> BagValue<L> {
+    #[must_use]
+    pub const fn item_type(&self) -> MQITEM {
+        MQITEM(match self {
+            Self::Integer(..) => sys::MQITEM_INTEGER,
+            Self::Integer64(..) => sys::MQITEM_INTEGER64,
+            Self::String(..) => sys::MQITEM_STRING,
+            Self::ByteString(..) => sys::MQITEM_BYTE_STRING,
+            Self::IntegerFilter(..) => sys::MQITEM_INTEGER_FILTER,
+            Self::StringFilter(..) => sys::MQITEM_STRING_FILTER,
+            Self::ByteStringFilter(..) => sys::MQITEM_BYTE_STRING_FILTER,
+            Self::Bag(..) => sys::MQITEM_BAG,
+        })
+    }
+}
+
+impl<L: Library<MQ: function::Mqai> + Clone> BagValue<L> {
+    /// Inquires the selector and value of an item using the type reported by `mqInquireItemInfo`
+    pub fn inq_item<B: BagDrop>(selector: MqaiSelector, index: MQIND, bag: &Bag<B, L>) -> ResultComp<(MqaiSelector, Self)> {
+        let (item_selector, item_type) = bag.mq.mq_inquire_item_info(bag, selector, index).warn_as_error()?;
+        let value = match item_type.0 {
+            sys::MQITEM_INTEGER => sys::MQLONG::inq_bag_item(selector, index, bag).map_completion(Self::Integer),
+            sys::MQITEM_INTEGER64 => i64::inq_bag_item(selector, index, bag).map_completion(Self::Integer64),
+            sys::MQITEM_STRING => StrCcsidOwned::inq_bag_item(selector, index, bag).map_completion(Self::String),
+            sys::MQITEM_BYTE_STRING => Vec::<sys::MQCHAR>::inq_bag_item(selector, index, bag).map_completion(Self::ByteString),
+            sys::MQITEM_INTEGER_FILTER => {
+                mqai::Filter::<sys::MQLONG>::inq_bag_item(selector, index, bag).map_completion(Self::IntegerFilter)
+            }
+            sys::MQITEM_STRING_FILTER => {
+                mqai::Filter::<StrCcsidOwned>::inq_bag_item(selector, index, bag).map_completion(Self::StringFilter)
+            }
+            sys::MQITEM_BYTE_STRING_FILTER => {
+                mqai::Filter::<Vec<sys::MQCHAR>>::inq_bag_item(selector, index, bag).map_completion(Self::ByteStringFilter)
+            }
+            sys::MQITEM_BAG => Bag::<Embedded, L>::inq_bag_item(selector, index, bag).map_completion(Self::Bag),
+            _ => Err(Error(
+                MQCC(sys::MQCC_FAILED),
+                "mqInquireItemInfo",
+                MQRC(sys::MQRC_ITEM_TYPE_ERROR),
+            )),
+        };
+        value.map_completion(|value| (item_selector, value))
+    }
+}
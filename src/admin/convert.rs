@@ -0,0 +1,66 @@
+use libmqm_sys::function;
+
+use crate::core::Library;
+use crate::values::{MqaiSelector, MQCBO};
+use crate::{prelude::*, sys, Error, FromStringCcsidError, WithMqError};
+
+use super::{Bag, BagDrop, Embedded, PutStringCcsidError};
+
+#[cfg(feature = "derive")]
+pub use mqi_derive::{FromBag, IntoBag};
+
+/// An MQ [`Library`] that supports the MQAI functions needed to convert bags to and from structs
+pub trait MqaiLibrary: Library<MQ: function::Mqai> + Clone {}
+
+impl<T: Library<MQ: function::Mqai> + Clone> MqaiLibrary for T {}
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum BagMapError {
+    #[display("Required item {} is not present in the bag", _0)]
+    #[error(ignore)]
+    Missing(MqaiSelector),
+    #[from]
+    PutString(PutStringCcsidError),
+    #[from]
+    String(FromStringCcsidError),
+    #[from]
+    MQ(Error),
+}
+
+impl WithMqError for BagMapError {
+    fn mqi_error(&self) -> Option<&Error> {
+        match self {
+            Self::MQ(e) | Self::PutString(PutStringCcsidError::Mqi(e)) => Some(e),
+            Self::Missing(_) | Self::PutString(_) | Self::String(_) => None,
+        }
+    }
+}
+
+/// A type that can be read from the items of a bag. Use `#[derive(FromBag)]` to implement it for a struct.
+pub trait FromBag<L: MqaiLibrary>: Sized {
+    fn from_bag<B: BagDrop>(bag: &Bag<B, L>) -> Result<Self, BagMapError>;
+}
+
+/// A type that can be added to a bag as items. Use `#[derive(IntoBag)]` to implement it for a struct.
+pub trait IntoBag<L: MqaiLibrary> {
+    fn to_bag<B: BagDrop>(&self, bag: &Bag<B, L>) -> Result<(), BagMapError>;
+}
+
+impl<B: BagDrop, L: MqaiLibrary> Bag<B, L> {
+    /// Read the nested group bag with the selector into a [`FromBag`] type
+    pub fn inquire_group<T: FromBag<L>>(&self, selector: MqaiSelector) -> Result<Option<T>, BagMapError> {
+        self.inquire::<Bag<Embedded, L>>(selector)
+            .warn_as_error()?
+            .map(|group| T::from_bag(&group))
+            .transpose()
+    }
+
+    /// Add an [`IntoBag`] type to the bag as a nested group bag with the selector
+    pub fn add_group<T: IntoBag<L> + ?Sized>(&self, selector: MqaiSelector, value: &T) -> Result<(), BagMapError> {
+        // There shouldn't be any warnings for creating a bag - so treat the warning as an error
+        let group = Bag::new_lib(self.mq.0.clone(), MQCBO(sys::MQCBO_GROUP_BAG)).warn_as_error()?;
+        value.to_bag(&group)?;
+        self.add_bag(selector, &group).warn_as_error()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+use std::fmt::{self, Display, Write as _};
+
+use libmqm_sys::function;
+
+use crate::core::{mqai, Library};
+use crate::values::{MqaiSelector, MQCC, MQCFC, MQCFT, MQCHT, MQCMD, MQOPER, MQOT, MQQT, MQRC, MQTOPT};
+use crate::{prelude::*, sys, Error, HasMqNames as _, StrCcsidOwned};
+
+use super::{Bag, BagDrop, BagValue};
+
+/// Owned snapshot of the items in a bag, including any nested group bags.
+///
+/// The [`Display`] implementation renders the items as an indented tree with the selectors
+/// and integer values resolved to their constant names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BagTree(pub Vec<BagTreeItem>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BagTreeItem {
+    pub selector: MqaiSelector,
+    pub value: BagTreeValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BagTreeValue {
+    Integer(sys::MQLONG),
+    Integer64(i64),
+    String(String),
+    ByteString(Vec<u8>),
+    IntegerFilter(mqai::Filter<sys::MQLONG>),
+    StringFilter(mqai::Filter<String>),
+    ByteStringFilter(mqai::Filter<Vec<u8>>),
+    Group(BagTree),
+}
+
+fn text(value: StrCcsidOwned) -> String {
+    String::from_utf8_lossy(&value.data).trim_end_matches([' ', '\0']).to_string()
+}
+
+fn bytes(value: Vec<sys::MQCHAR>) -> Vec<u8> {
+    value.into_iter().map(|c| c.to_ne_bytes()[0]).collect()
+}
+
+impl BagTree {
+    /// Walks all the items of the bag, descending into the nested group bags
+    pub fn from_bag<B: BagDrop, L: Library<MQ: function::Mqai> + Clone>(bag: &Bag<B, L>) -> Result<Self, Error> {
+        let mut items = Vec::new();
+        for item in bag.items().warn_as_error()? {
+            let (selector, value) = item.warn_as_error()?;
+            let value = match value {
+                BagValue::Integer(value) => BagTreeValue::Integer(value),
+                BagValue::Integer64(value) => BagTreeValue::Integer64(value),
+                BagValue::String(value) => BagTreeValue::String(text(value)),
+                BagValue::ByteString(value) => BagTreeValue::ByteString(bytes(value)),
+                BagValue::IntegerFilter(filter) => BagTreeValue::IntegerFilter(filter),
+                BagValue::StringFilter(mqai::Filter { operator, value }) => {
+                    BagTreeValue::StringFilter(mqai::Filter::new(text(value), operator))
+                }
+                BagValue::ByteStringFilter(mqai::Filter { operator, value }) => {
+                    BagTreeValue::ByteStringFilter(mqai::Filter::new(bytes(value), operator))
+                }
+                BagValue::Bag(group) => BagTreeValue::Group(Self::from_bag(&group)?),
+            };
+            items.push(BagTreeItem { selector, value });
+        }
+        Ok(Self(items))
+    }
+
+    fn fmt_depth(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+        for BagTreeItem { selector, value } in &self.0 {
+            write!(f, "{:indent$}{selector}:", "", indent = depth * 2)?;
+            match value {
+                BagTreeValue::Group(group) => {
+                    f.write_char('\n')?;
+                    group.fmt_depth(f, depth + 1)?;
+                }
+                &BagTreeValue::Integer(value) => match value_name(*selector, value) {
+                    Some(name) => writeln!(f, " {name} = {value}")?,
+                    None => writeln!(f, " {value}")?,
+                },
+                BagTreeValue::Integer64(value) => writeln!(f, " {value}")?,
+                BagTreeValue::String(value) => writeln!(f, " '{value}'")?,
+                BagTreeValue::ByteString(value) => writeln!(f, " {}", Hex(value))?,
+                BagTreeValue::IntegerFilter(filter) => writeln!(f, " {filter}")?,
+                BagTreeValue::StringFilter(mqai::Filter { operator, value }) => writeln!(f, " '{value}' <{operator}>")?,
+                BagTreeValue::ByteStringFilter(mqai::Filter { operator, value }) => writeln!(f, " {} <{operator}>", Hex(value))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for BagTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_depth(f, 0)
+    }
+}
+
+struct Hex<'a>(&'a [u8]);
+
+impl Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("0x")?;
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02X}"))
+    }
+}
+
+/// Constant name of an integer value, where the selector has a known set of values
+fn value_name(selector: MqaiSelector, value: sys::MQLONG) -> Option<&'static str> {
+    match selector.0 {
+        sys::MQIA_Q_TYPE => MQQT(value).mq_primary_name(),
+        sys::MQIACH_CHANNEL_TYPE => MQCHT(value).mq_primary_name(),
+        sys::MQIA_TOPIC_TYPE => MQTOPT(value).mq_primary_name(),
+        sys::MQIACF_OBJECT_TYPE => MQOT(value).mq_primary_name(),
+        sys::MQIACF_OPERATION_TYPE => MQOPER(value).mq_primary_name(),
+        sys::MQIASY_TYPE => MQCFT(value).mq_primary_name(),
+        sys::MQIASY_COMMAND | sys::MQIACF_COMMAND => MQCMD(value).mq_primary_name(),
+        sys::MQIASY_CONTROL => MQCFC(value).mq_primary_name(),
+        sys::MQIASY_COMP_CODE | sys::MQIACF_COMP_CODE => MQCC(value).mq_primary_name(),
+        sys::MQIASY_REASON | sys::MQIACF_REASON_CODE => MQRC(value).mq_primary_name(),
+        sys::MQIACF_PARAMETER_ID => MqaiSelector(value).mq_primary_name(),
+        _ => None,
+    }
+}
+
+impl<B: BagDrop, L: Library<MQ: function::Mqai> + Clone> Bag<B, L> {
+    /// Owned snapshot of all the items in the bag. Use the [`Display`] of [`BagTree`] to render it.
+    pub fn tree(&self) -> Result<BagTree, Error> {
+        BagTree::from_bag(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde::ser::{Serialize, SerializeMap as _, Serializer};
+
+    use super::{value_name, BagTree, BagTreeItem, BagTreeValue, Hex};
+
+    impl Serialize for BagTree {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(&self.0)
+        }
+    }
+
+    impl Serialize for BagTreeItem {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let Self { selector, value } = self;
+            let mut map = serializer.serialize_map(None)?;
+            map.serialize_entry("selector", &selector.to_string())?;
+            match value {
+                BagTreeValue::Integer(value) => {
+                    map.serialize_entry("value", value)?;
+                    if let Some(name) = value_name(*selector, *value) {
+                        map.serialize_entry("name", name)?;
+                    }
+                }
+                BagTreeValue::Integer64(value) => map.serialize_entry("value", value)?,
+                BagTreeValue::String(value) => map.serialize_entry("value", value)?,
+                BagTreeValue::ByteString(value) => map.serialize_entry("value", &Hex(value).to_string())?,
+                BagTreeValue::IntegerFilter(filter) => {
+                    map.serialize_entry("operator", &filter.operator.to_string())?;
+                    map.serialize_entry("value", &filter.value)?;
+                }
+                BagTreeValue::StringFilter(filter) => {
+                    map.serialize_entry("operator", &filter.operator.to_string())?;
+                    map.serialize_entry("value", &filter.value)?;
+                }
+                BagTreeValue::ByteStringFilter(filter) => {
+                    map.serialize_entry("operator", &filter.operator.to_string())?;
+                    map.serialize_entry("value", &Hex(&filter.value).to_string())?;
+                }
+                BagTreeValue::Group(group) => map.serialize_entry("items", group)?,
+            }
+            map.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::mqai;
+    use crate::sys;
+    use crate::values::{MqaiSelector, MQCFOP};
+
+    use super::{BagTree, BagTreeItem, BagTreeValue};
+
+    fn item(selector: sys::MQLONG, value: BagTreeValue) -> BagTreeItem {
+        BagTreeItem {
+            selector: MqaiSelector(selector),
+            value,
+        }
+    }
+
+    fn response() -> BagTree {
+        BagTree(vec![
+            item(sys::MQIASY_COMP_CODE, BagTreeValue::Integer(sys::MQCC_OK)),
+            item(
+                sys::MQHA_BAG_HANDLE,
+                BagTreeValue::Group(BagTree(vec![
+                    item(sys::MQCA_Q_NAME, BagTreeValue::String("DEV.QUEUE.1".to_string())),
+                    item(sys::MQIA_Q_TYPE, BagTreeValue::Integer(sys::MQQT_LOCAL)),
+                    item(sys::MQBACF_GENERIC_CONNECTION_ID, BagTreeValue::ByteString(vec![0xAB, 0x01])),
+                ])),
+            ),
+            item(
+                sys::MQIA_CURRENT_Q_DEPTH,
+                BagTreeValue::IntegerFilter(mqai::Filter::new(10, MQCFOP(sys::MQCFOP_GREATER))),
+            ),
+        ])
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            response().to_string(),
+            "MQIASY_COMP_CODE: MQCC_OK = 0\n\
+             MQHA_BAG_HANDLE:\n  \
+               MQCA_Q_NAME: 'DEV.QUEUE.1'\n  \
+               MQIA_Q_TYPE: MQQT_LOCAL = 1\n  \
+               7007: 0xAB01\n\
+             MQIA_CURRENT_Q_DEPTH: 10 <MQCFOP_GREATER>\n"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() -> Result<(), serde_json::Error> {
+        assert_eq!(
+            serde_json::to_string(&response())?,
+            r#"[{"selector":"MQIASY_COMP_CODE","value":0,"name":"MQCC_OK"},{"selector":"MQHA_BAG_HANDLE","items":[{"selector":"MQCA_Q_NAME","value":"DEV.QUEUE.1"},{"selector":"MQIA_Q_TYPE","value":1,"name":"MQQT_LOCAL"},{"selector":"7007","value":"0xAB01"}]},{"selector":"MQIA_CURRENT_Q_DEPTH","operator":"MQCFOP_GREATER","value":10}]"#
+        );
+        Ok(())
+    }
+}
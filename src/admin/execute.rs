@@ -8,10 +8,10 @@ use crate::{
     core::Library,
     sys,
     values::{MQCBO, MQCMD},
-    Conn, ResultComp,
+    Conn, Error, ResultComp, ResultCompErr,
 };
 
-use super::{Bag, BagDrop, Owned};
+use super::{AdminError, Bag, BagDrop, Owned};
 
 #[derive(Debug, Default)]
 pub struct ExecuteParam<'a> {
@@ -68,6 +68,13 @@ pub trait QueueManagerAdmin: Conn<Lib: Library<MQ: Mqai>> {
         admin: &Bag<impl BagDrop, Self::Lib>,
         options: impl ExecuteOption<'a>,
     ) -> ResultComp<Bag<Owned, Self::Lib>>;
+
+    /// Execute the admin command, returning every failed response in the response bag as an [`AdminError`]
+    fn execute_checked<'a>(
+        &self,
+        admin: &Bag<impl BagDrop, Self::Lib>,
+        options: impl ExecuteOption<'a>,
+    ) -> ResultCompErr<Bag<Owned, Self::Lib>, AdminError>;
 }
 
 impl<C> QueueManagerAdmin for C
@@ -79,23 +86,46 @@ where
         admin: &Bag<impl BagDrop, Self::Lib>,
         options: impl ExecuteOption<'a>,
     ) -> ResultComp<Bag<Owned, Self::Lib>> {
-        let lib = self.mq().0.clone();
-        // There shouldn't be any warnings for creating a bag - so treat the warning as an error
-        let response_bag = Bag::new_lib(lib, MQCBO(sys::MQCBO_ADMIN_BAG)).warn_as_error()?;
-
-        let mut param = ExecuteParam::default();
-        options.apply_param(&mut param);
-
-        self.mq()
-            .mq_execute(
-                self.handle(),
-                param.command,
-                param.options,
-                admin,
-                response_bag.handle(),
-                param.admin_object,
-                param.reply_object,
-            )
-            .map_completion(|()| response_bag)
+        let (response_bag, result) = execute_response(self, admin, options)?;
+        result.map_completion(|()| response_bag)
     }
+
+    fn execute_checked<'a>(
+        &self,
+        admin: &Bag<impl BagDrop, Self::Lib>,
+        options: impl ExecuteOption<'a>,
+    ) -> ResultCompErr<Bag<Owned, Self::Lib>, AdminError> {
+        let (response_bag, result) = execute_response(self, admin, options)?;
+        // The response bag holds the individual responses even when the command has failed
+        response_bag.check_response()?;
+        Ok(result?.map(|()| response_bag))
+    }
+}
+
+/// Execute the admin command, returning the response bag along with the outcome of `mqExecute`
+pub(super) fn execute_response<'a, C>(
+    connection: &C,
+    admin: &Bag<impl BagDrop, C::Lib>,
+    options: impl ExecuteOption<'a>,
+) -> Result<(Bag<Owned, C::Lib>, ResultComp<()>), Error>
+where
+    C: Conn<Lib: Library<MQ: function::Mqai> + Clone>,
+{
+    let lib = connection.mq().0.clone();
+    // There shouldn't be any warnings for creating a bag - so treat the warning as an error
+    let response_bag = Bag::new_lib(lib, MQCBO(sys::MQCBO_ADMIN_BAG)).warn_as_error()?;
+
+    let mut param = ExecuteParam::default();
+    options.apply_param(&mut param);
+
+    let result = connection.mq().mq_execute(
+        connection.handle(),
+        param.command,
+        param.options,
+        admin,
+        response_bag.handle(),
+        param.admin_object,
+        param.reply_object,
+    );
+    Ok((response_bag, result))
 }
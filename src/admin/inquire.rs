@@ -0,0 +1,387 @@
+use libmqm_sys::function;
+
+use crate::core::{mqai, Library};
+use crate::types::{ChannelName, CipherSpec, ConnectionName, ObjectName, QueueManagerName, QueueName};
+use crate::values::{MqaiSelector, CCSID, MQCBO, MQCHT, MQCMD, MQQT, MQTOPT};
+use crate::{prelude::*, sys, Completion, Error, FromStringCcsidError, MqConstant, MqStr, ResultCompErr, WithMqError};
+use crate::{StrCcsid, StrCcsidOwned};
+
+use super::{Bag, BagDrop, BagItemGet, Embedded, Owned, PutStringCcsidError, QueueManagerAdmin};
+
+pub type QueueDescription = MqStr<{ sys::MQ_Q_DESC_LENGTH }>;
+pub type ChannelDescription = MqStr<{ sys::MQ_CHANNEL_DESC_LENGTH }>;
+pub type QueueManagerDescription = MqStr<{ sys::MQ_Q_MGR_DESC_LENGTH }>;
+pub type QueueManagerIdentifier = MqStr<{ sys::MQ_Q_MGR_IDENTIFIER_LENGTH }>;
+pub type TopicDescription = MqStr<{ sys::MQ_TOPIC_DESC_LENGTH }>;
+pub type ClusterName = MqStr<{ sys::MQ_CLUSTER_NAME_LENGTH }>;
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum InquireError {
+    #[display("Response is missing the required {} attribute", _0)]
+    #[error(ignore)]
+    Missing(MqaiSelector),
+    #[display("Filter could not be added to the admin bag: {}", _0)]
+    #[from]
+    Filter(PutStringCcsidError),
+    #[display("Invalid string attribute: {}", _0)]
+    #[from]
+    String(FromStringCcsidError),
+    #[from]
+    MQ(Error),
+}
+
+impl WithMqError for InquireError {
+    fn mqi_error(&self) -> Option<&Error> {
+        match self {
+            Self::MQ(e) | Self::Filter(PutStringCcsidError::Mqi(e)) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Filter on a single attribute of the inquired objects, as supported by the command server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeFilter<'a> {
+    Integer(MqaiSelector, mqai::Filter<sys::MQLONG>),
+    String(MqaiSelector, mqai::Filter<&'a str>),
+}
+
+impl<'a> AttributeFilter<'a> {
+    #[must_use]
+    pub const fn integer(selector: sys::MQLONG, filter: mqai::Filter<sys::MQLONG>) -> Self {
+        Self::Integer(MqaiSelector(selector), filter)
+    }
+
+    #[must_use]
+    pub const fn string(selector: sys::MQLONG, filter: mqai::Filter<&'a str>) -> Self {
+        Self::String(MqaiSelector(selector), filter)
+    }
+
+    fn add_to<B: BagDrop, L: Library<MQ: function::Mqai>>(&self, bag: &Bag<B, L>) -> ResultCompErr<(), InquireError> {
+        match *self {
+            Self::Integer(selector, filter) => bag.add(selector, &filter).map_err(Into::into),
+            Self::String(selector, mqai::Filter { operator, value }) => bag
+                .add(selector, &mqai::Filter::new(StrCcsid::from(value), operator))
+                .map_err(Into::into),
+        }
+    }
+}
+
+/// Generic object name, object type and attribute filter for an inquire command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectFilter<'a, T> {
+    pub name: &'a str,
+    pub object_type: Option<T>,
+    pub attribute: Option<AttributeFilter<'a>>,
+}
+
+pub type QueueFilter<'a> = ObjectFilter<'a, MQQT>;
+pub type ChannelFilter<'a> = ObjectFilter<'a, MQCHT>;
+pub type TopicFilter<'a> = ObjectFilter<'a, MQTOPT>;
+
+impl<'a, T> ObjectFilter<'a, T> {
+    /// Filter objects by a generic name such as `APP.*`
+    #[must_use]
+    pub const fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            object_type: None,
+            attribute: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_type(self, object_type: T) -> Self {
+        Self {
+            object_type: Some(object_type),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_attribute(self, attribute: AttributeFilter<'a>) -> Self {
+        Self {
+            attribute: Some(attribute),
+            ..self
+        }
+    }
+}
+
+impl<'a, T> From<&'a str> for ObjectFilter<'a, T> {
+    fn from(name: &'a str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl<T: MqConstant> ObjectFilter<'_, T> {
+    fn admin_bag<L: Library<MQ: function::Mqai>>(
+        &self,
+        lib: L,
+        name: sys::MQLONG,
+        object_type: sys::MQLONG,
+    ) -> Result<Bag<Owned, L>, InquireError> {
+        // There shouldn't be any warnings for creating a bag - so treat the warning as an error
+        let bag = Bag::new_lib(lib, MQCBO(sys::MQCBO_ADMIN_BAG)).warn_as_error()?;
+        bag.add(MqaiSelector(name), self.name).warn_as_error()?;
+        if let Some(value) = &self.object_type {
+            bag.add(MqaiSelector(object_type), &value.mq_value()).warn_as_error()?;
+        }
+        if let Some(attribute) = &self.attribute {
+            attribute.add_to(&bag).warn_as_error()?;
+        }
+        Ok(bag)
+    }
+}
+
+/// Attributes of a queue returned by [`InquireAdmin::inquire_queues`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueDefinition {
+    pub name: QueueName,
+    pub queue_type: MQQT,
+    pub description: QueueDescription,
+    pub current_depth: Option<sys::MQLONG>,
+    pub max_depth: Option<sys::MQLONG>,
+    pub max_msg_length: Option<sys::MQLONG>,
+    pub open_input_count: Option<sys::MQLONG>,
+    pub open_output_count: Option<sys::MQLONG>,
+    pub get_inhibited: Option<bool>,
+    pub put_inhibited: Option<bool>,
+    pub base_object: Option<ObjectName>,
+    pub remote_queue: Option<QueueName>,
+    pub remote_queue_manager: Option<QueueManagerName>,
+    pub transmission_queue: Option<QueueName>,
+}
+
+/// Attributes of a channel returned by [`InquireAdmin::inquire_channels`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelDefinition {
+    pub name: ChannelName,
+    pub channel_type: MQCHT,
+    pub description: ChannelDescription,
+    pub connection_name: Option<ConnectionName>,
+    pub transmission_queue: Option<QueueName>,
+    pub max_msg_length: Option<sys::MQLONG>,
+    pub heartbeat_interval: Option<sys::MQLONG>,
+    pub sharing_conversations: Option<sys::MQLONG>,
+    pub cipher_spec: Option<CipherSpec>,
+}
+
+/// Attributes of the queue manager returned by [`InquireAdmin::inquire_queue_manager`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueManagerDefinition {
+    pub name: QueueManagerName,
+    pub identifier: Option<QueueManagerIdentifier>,
+    pub description: QueueManagerDescription,
+    pub command_level: sys::MQLONG,
+    pub ccsid: CCSID,
+    pub max_handles: Option<sys::MQLONG>,
+    pub max_msg_length: sys::MQLONG,
+    pub max_priority: sys::MQLONG,
+    pub command_input_queue: Option<QueueName>,
+    pub dead_letter_queue: Option<QueueName>,
+}
+
+/// Attributes of a topic object returned by [`InquireAdmin::inquire_topics`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicDefinition {
+    pub name: ObjectName,
+    pub topic_type: MQTOPT,
+    pub topic_string: String,
+    pub description: TopicDescription,
+    pub cluster_name: Option<ClusterName>,
+}
+
+fn attribute<T, B, L>(bag: &Bag<B, L>, selector: sys::MQLONG) -> Result<Option<T>, InquireError>
+where
+    T: BagItemGet<L, Error = Error>,
+    B: BagDrop,
+    L: Library<MQ: function::Mqai>,
+{
+    Ok(bag.inquire(selector).warn_as_error()?)
+}
+
+fn required<T, B, L>(bag: &Bag<B, L>, selector: sys::MQLONG) -> Result<T, InquireError>
+where
+    T: BagItemGet<L, Error = Error>,
+    B: BagDrop,
+    L: Library<MQ: function::Mqai>,
+{
+    attribute(bag, selector)?.ok_or(InquireError::Missing(MqaiSelector(selector)))
+}
+
+/// Optional name attribute, treating a blank name as not set
+fn name<const N: usize, T, B, L>(bag: &Bag<B, L>, selector: sys::MQLONG) -> Result<Option<T>, InquireError>
+where
+    T: From<MqStr<N>>,
+    B: BagDrop,
+    L: Library<MQ: function::Mqai>,
+{
+    Ok(attribute::<MqStr<N>, _, _>(bag, selector)?
+        .filter(MqStr::has_value)
+        .map(T::from))
+}
+
+impl QueueDefinition {
+    fn from_bag<B: BagDrop, L: Library<MQ: function::Mqai>>(bag: &Bag<B, L>) -> Result<Self, InquireError> {
+        let inhibited = |selector, value| -> Result<_, InquireError> {
+            Ok(attribute::<sys::MQLONG, _, _>(bag, selector)?.map(|attr| attr == value))
+        };
+        Ok(Self {
+            name: QueueName(required(bag, sys::MQCA_Q_NAME)?),
+            queue_type: MQQT(required(bag, sys::MQIA_Q_TYPE)?),
+            description: attribute(bag, sys::MQCA_Q_DESC)?.unwrap_or_default(),
+            current_depth: attribute(bag, sys::MQIA_CURRENT_Q_DEPTH)?,
+            max_depth: attribute(bag, sys::MQIA_MAX_Q_DEPTH)?,
+            max_msg_length: attribute(bag, sys::MQIA_MAX_MSG_LENGTH)?,
+            open_input_count: attribute(bag, sys::MQIA_OPEN_INPUT_COUNT)?,
+            open_output_count: attribute(bag, sys::MQIA_OPEN_OUTPUT_COUNT)?,
+            get_inhibited: inhibited(sys::MQIA_INHIBIT_GET, sys::MQQA_GET_INHIBITED)?,
+            put_inhibited: inhibited(sys::MQIA_INHIBIT_PUT, sys::MQQA_PUT_INHIBITED)?,
+            base_object: name(bag, sys::MQCA_BASE_OBJECT_NAME)?,
+            remote_queue: name(bag, sys::MQCA_REMOTE_Q_NAME)?,
+            remote_queue_manager: name(bag, sys::MQCA_REMOTE_Q_MGR_NAME)?,
+            transmission_queue: name(bag, sys::MQCA_XMIT_Q_NAME)?,
+        })
+    }
+}
+
+impl ChannelDefinition {
+    fn from_bag<B: BagDrop, L: Library<MQ: function::Mqai>>(bag: &Bag<B, L>) -> Result<Self, InquireError> {
+        Ok(Self {
+            name: ChannelName(required(bag, sys::MQCACH_CHANNEL_NAME)?),
+            channel_type: MQCHT(required(bag, sys::MQIACH_CHANNEL_TYPE)?),
+            description: attribute(bag, sys::MQCACH_DESC)?.unwrap_or_default(),
+            connection_name: name(bag, sys::MQCACH_CONNECTION_NAME)?,
+            transmission_queue: name(bag, sys::MQCACH_XMIT_Q_NAME)?,
+            max_msg_length: attribute(bag, sys::MQIACH_MAX_MSG_LENGTH)?,
+            heartbeat_interval: attribute(bag, sys::MQIACH_HB_INTERVAL)?,
+            sharing_conversations: attribute(bag, sys::MQIACH_SHARING_CONVERSATIONS)?,
+            cipher_spec: name(bag, sys::MQCACH_SSL_CIPHER_SPEC)?,
+        })
+    }
+}
+
+impl QueueManagerDefinition {
+    fn from_bag<B: BagDrop, L: Library<MQ: function::Mqai>>(bag: &Bag<B, L>) -> Result<Self, InquireError> {
+        Ok(Self {
+            name: QueueManagerName(required(bag, sys::MQCA_Q_MGR_NAME)?),
+            identifier: name(bag, sys::MQCA_Q_MGR_IDENTIFIER)?,
+            description: attribute(bag, sys::MQCA_Q_MGR_DESC)?.unwrap_or_default(),
+            command_level: required(bag, sys::MQIA_COMMAND_LEVEL)?,
+            ccsid: CCSID(required(bag, sys::MQIA_CODED_CHAR_SET_ID)?),
+            max_handles: attribute(bag, sys::MQIA_MAX_HANDLES)?,
+            max_msg_length: required(bag, sys::MQIA_MAX_MSG_LENGTH)?,
+            max_priority: required(bag, sys::MQIA_MAX_PRIORITY)?,
+            command_input_queue: name(bag, sys::MQCA_COMMAND_INPUT_Q_NAME)?,
+            dead_letter_queue: name(bag, sys::MQCA_DEAD_LETTER_Q_NAME)?,
+        })
+    }
+}
+
+impl TopicDefinition {
+    fn from_bag<B: BagDrop, L: Library<MQ: function::Mqai>>(bag: &Bag<B, L>) -> Result<Self, InquireError> {
+        Ok(Self {
+            name: required(bag, sys::MQCA_TOPIC_NAME)?,
+            topic_type: MQTOPT(required(bag, sys::MQIA_TOPIC_TYPE)?),
+            topic_string: String::try_from(required::<StrCcsidOwned, _, _>(bag, sys::MQCA_TOPIC_STRING)?)?,
+            description: attribute(bag, sys::MQCA_TOPIC_DESC)?.unwrap_or_default(),
+            cluster_name: name(bag, sys::MQCA_CLUSTER_NAME)?,
+        })
+    }
+}
+
+/// Typed inquiries of queue manager objects built on [`QueueManagerAdmin::execute`]
+pub trait InquireAdmin: QueueManagerAdmin {
+    /// Inquire the queues matching the generic name and filter
+    fn inquire_queues<'a>(&self, filter: impl Into<QueueFilter<'a>>) -> ResultCompErr<Vec<QueueDefinition>, InquireError>;
+
+    /// Inquire the channels matching the generic name and filter
+    fn inquire_channels<'a>(&self, filter: impl Into<ChannelFilter<'a>>) -> ResultCompErr<Vec<ChannelDefinition>, InquireError>;
+
+    /// Inquire the topic objects matching the generic name and filter
+    fn inquire_topics<'a>(&self, filter: impl Into<TopicFilter<'a>>) -> ResultCompErr<Vec<TopicDefinition>, InquireError>;
+
+    /// Inquire the attributes of the connected queue manager
+    fn inquire_queue_manager(&self) -> ResultCompErr<QueueManagerDefinition, InquireError>;
+}
+
+/// Executes the command and maps each of the response bags
+fn inquire_all<C, T>(
+    qm: &C,
+    command: sys::MQLONG,
+    admin: &Bag<impl BagDrop, C::Lib>,
+    from_bag: impl Fn(&Bag<Embedded, C::Lib>) -> Result<T, InquireError>,
+) -> ResultCompErr<Vec<T>, InquireError>
+where
+    C: QueueManagerAdmin<Lib: Clone>,
+{
+    let Completion(response, warning) = qm.execute(admin, MQCMD(command))?;
+    let definitions = response
+        .try_bag_iter(MqaiSelector(sys::MQHA_BAG_HANDLE))
+        .warn_as_error()?
+        .map(|bag| from_bag(&bag.warn_as_error()?))
+        .collect::<Result<_, _>>()?;
+    Ok(Completion(definitions, warning))
+}
+
+impl<C> InquireAdmin for C
+where
+    C: QueueManagerAdmin<Lib: Library<MQ: function::Mqai> + Clone>,
+{
+    fn inquire_queues<'a>(&self, filter: impl Into<QueueFilter<'a>>) -> ResultCompErr<Vec<QueueDefinition>, InquireError> {
+        let admin = filter
+            .into()
+            .admin_bag(self.mq().0.clone(), sys::MQCA_Q_NAME, sys::MQIA_Q_TYPE)?;
+        inquire_all(self, sys::MQCMD_INQUIRE_Q, &admin, QueueDefinition::from_bag)
+    }
+
+    fn inquire_channels<'a>(&self, filter: impl Into<ChannelFilter<'a>>) -> ResultCompErr<Vec<ChannelDefinition>, InquireError> {
+        let admin = filter
+            .into()
+            .admin_bag(self.mq().0.clone(), sys::MQCACH_CHANNEL_NAME, sys::MQIACH_CHANNEL_TYPE)?;
+        inquire_all(self, sys::MQCMD_INQUIRE_CHANNEL, &admin, ChannelDefinition::from_bag)
+    }
+
+    fn inquire_topics<'a>(&self, filter: impl Into<TopicFilter<'a>>) -> ResultCompErr<Vec<TopicDefinition>, InquireError> {
+        let admin = filter
+            .into()
+            .admin_bag(self.mq().0.clone(), sys::MQCA_TOPIC_NAME, sys::MQIA_TOPIC_TYPE)?;
+        inquire_all(self, sys::MQCMD_INQUIRE_TOPIC, &admin, TopicDefinition::from_bag)
+    }
+
+    fn inquire_queue_manager(&self) -> ResultCompErr<QueueManagerDefinition, InquireError> {
+        let admin = Bag::new_lib(self.mq().0.clone(), MQCBO(sys::MQCBO_ADMIN_BAG)).warn_as_error()?;
+        let Completion(mut definitions, warning) =
+            inquire_all(self, sys::MQCMD_INQUIRE_Q_MGR, &admin, QueueManagerDefinition::from_bag)?;
+        definitions
+            .pop()
+            .map(|definition| Completion(definition, warning))
+            .ok_or(InquireError::Missing(MqaiSelector(sys::MQHA_BAG_HANDLE)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::mqai;
+    use crate::sys;
+    use crate::values::{MqaiSelector, MQQT};
+
+    use super::{AttributeFilter, QueueFilter};
+
+    #[test]
+    fn object_filter() {
+        let filter = QueueFilter::from("APP.*")
+            .with_type(MQQT(sys::MQQT_LOCAL))
+            .with_attribute(AttributeFilter::integer(sys::MQIA_CURRENT_Q_DEPTH, mqai::Filter::greater(0)));
+        assert_eq!(filter.name, "APP.*");
+        assert_eq!(filter.object_type, Some(MQQT(sys::MQQT_LOCAL)));
+        assert_eq!(
+            filter.attribute,
+            Some(AttributeFilter::Integer(
+                MqaiSelector(sys::MQIA_CURRENT_Q_DEPTH),
+                mqai::Filter::greater(0)
+            ))
+        );
+        assert_eq!(QueueFilter::new("*").with_type(MQQT(sys::MQQT_ALIAS)).attribute, None);
+    }
+}
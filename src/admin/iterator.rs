@@ -9,7 +9,7 @@ use crate::{sys, ResultComp, ResultCompErr, WithMqError as _};
 
 use crate::Error;
 
-use super::{Bag, BagDrop, BagItemGet, Embedded};
+use super::{Bag, BagDrop, BagItemGet, BagValue, Embedded};
 
 pub struct BagItem<'bag, T, B, L>
 where
@@ -74,3 +74,54 @@ where
         self.try_iter(selector)
     }
 }
+
+/// Iterator over every item in a bag, returned by [`Bag::items`]
+pub struct BagItems<'bag, B, L>
+where
+    B: BagDrop,
+    L: Library<MQ: function::Mqai>,
+{
+    index: sys::MQLONG,
+    count: sys::MQLONG,
+    bag: &'bag Bag<B, L>,
+}
+
+impl<B, L> Iterator for BagItems<'_, B, L>
+where
+    B: BagDrop,
+    L: Library<MQ: function::Mqai> + Clone,
+{
+    type Item = ResultComp<(MqaiSelector, BagValue<L>)>;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = (self.count - self.index).try_into().ok();
+        (size.unwrap_or(0), size)
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count == self.index {
+            return None;
+        };
+        let result = BagValue::inq_item(MqaiSelector::ANY, MQIND(self.index), self.bag);
+        self.index += 1;
+
+        Some(result)
+    }
+}
+
+impl<B, L> Bag<B, L>
+where
+    B: BagDrop,
+    L: Library<MQ: function::Mqai> + Clone,
+{
+    /// Iterate over every user and system item in the bag, in the order they were added
+    pub fn items(&self) -> ResultComp<BagItems<B, L>> {
+        self.mq
+            .mq_count_items(self, MqaiSelector::ANY)
+            .map_completion(|count| BagItems {
+                index: 0,
+                count,
+                bag: self,
+            })
+    }
+}
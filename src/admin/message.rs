@@ -0,0 +1,142 @@
+use libmqm_sys::function;
+
+use crate::core::Library;
+use crate::get::{GetOption, GetParam};
+use crate::put::{PutAttr, PutOption};
+use crate::values::{MQCBO, MQCC, MQRC};
+use crate::{prelude::*, sys, Completion, Conn, Error, MqStruct, Object, ResultComp};
+
+use super::{Bag, BagDrop, Owned};
+
+/// Largest buffer [`Bag::to_buffer`] will allocate, matching the maximum length of an MQ message
+const MAX_BUFFER_LENGTH: usize = 100 * 1024 * 1024;
+
+impl<B: BagDrop, L: Library<MQ: function::Mqai>> Bag<B, L> {
+    /// Convert the bag into a PCF formatted buffer, suitable for storing and restoring with [`Bag::from_buffer_lib`]
+    pub fn to_buffer(&self) -> ResultComp<Vec<u8>> {
+        let mut buffer: Vec<u8> = Vec::with_capacity(page_size::get());
+        loop {
+            match self.mq.mq_bag_to_buffer(None, self, buffer.spare_capacity_mut()) {
+                Err(Error(MQCC(sys::MQCC_FAILED), _, MQRC(sys::MQRC_BUFFER_LENGTH_ERROR)))
+                    if buffer.capacity() < MAX_BUFFER_LENGTH =>
+                {
+                    buffer = Vec::with_capacity(buffer.capacity() * 2);
+                }
+                result => {
+                    return result.map_completion(|length| {
+                        unsafe {
+                            buffer.set_len(length.try_into().expect("mq_bag_to_buffer should not return negative"));
+                        }
+                        buffer
+                    })
+                }
+            }
+        }
+    }
+}
+
+impl<L: Library<MQ: function::Mqai>> Bag<Owned, L> {
+    /// Create a bag from a PCF formatted buffer, such as the one returned by [`Bag::to_buffer`]
+    pub fn from_buffer_lib(lib: L, options: MQCBO, data: &[u8]) -> ResultComp<Self> {
+        // There shouldn't be any warnings for creating a bag - so treat the warning as an error
+        let bag = Self::new_lib(lib, options).warn_as_error()?;
+        bag.mq.mq_buffer_to_bag(None, data, &bag).map_completion(|()| bag)
+    }
+}
+
+impl<C> Object<C>
+where
+    C: Conn<Lib: Library<MQ: function::Mqai>>,
+{
+    /// Put the contents of the bag on the object as a PCF message
+    pub fn put_bag(&self, put_options: impl PutOption, bag: &Bag<impl BagDrop, C::Lib>) -> ResultComp<()> {
+        self.put_bag_with(put_options, bag)
+    }
+
+    /// Put the contents of the bag on the object as a PCF message, returning the requested [`PutAttr`]
+    pub fn put_bag_with<R>(&self, put_options: impl PutOption, bag: &Bag<impl BagDrop, C::Lib>) -> ResultComp<R>
+    where
+        R: PutAttr,
+    {
+        let mut put_param = (
+            MqStruct::default(),
+            MqStruct::new(sys::MQPMO {
+                Version: sys::MQPMO_VERSION_3,
+                ..sys::MQPMO::default()
+            }),
+        );
+        put_options.apply_param(&mut put_param);
+
+        let connection = self.connection();
+        R::extract(&mut put_param, |(md, pmo)| {
+            connection
+                .mq()
+                .mq_put_bag(connection.handle(), self.handle(), &mut **md, pmo, bag)
+        })
+        .map_completion(|(attr, ..)| attr)
+    }
+
+    /// Get a PCF message from the object into a new bag. Returns `None` when there is no message available.
+    pub fn get_bag(&self, bag_options: MQCBO, options: impl GetOption) -> ResultComp<Option<Bag<Owned, C::Lib>>>
+    where
+        C::Lib: Clone,
+    {
+        let connection = self.connection();
+        // There shouldn't be any warnings for creating a bag - so treat the warning as an error
+        let bag = Bag::new_lib(connection.mq().0.clone(), bag_options).warn_as_error()?;
+
+        let mut param = GetParam {
+            md: MqStruct::default(),
+            gmo: MqStruct::new(sys::MQGMO {
+                Version: sys::MQGMO_VERSION_4,
+                ..sys::MQGMO::default()
+            }),
+        };
+        options.apply_param(&mut param);
+
+        match connection
+            .mq()
+            .mq_get_bag(connection.handle(), self.handle(), &mut *param.md, &mut param.gmo, Some(&bag))
+        {
+            Err(Error(MQCC(sys::MQCC_FAILED), _, MQRC(sys::MQRC_NO_MSG_AVAILABLE))) => Ok(Completion::new(None)),
+            result => result.map_completion(|()| Some(bag)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::admin::Bag;
+    use crate::prelude::*;
+    use crate::sys;
+    use crate::values::{MqaiSelector, MQCBO};
+
+    #[test]
+    fn buffer_round_trip() -> Result<(), Box<dyn Error>> {
+        let bag = Bag::new(MQCBO(sys::MQCBO_USER_BAG)).warn_as_error()?;
+        let depth: sys::MQLONG = 5;
+        bag.add(MqaiSelector(sys::MQIA_CURRENT_Q_DEPTH), &depth).warn_as_error()?;
+        bag.add(MqaiSelector(sys::MQCA_Q_NAME), "DEV.QUEUE.1").warn_as_error()?;
+
+        let buffer = bag.to_buffer().warn_as_error()?;
+        assert!(!buffer.is_empty());
+
+        let restored = Bag::from_buffer(MQCBO(sys::MQCBO_USER_BAG), &buffer).warn_as_error()?;
+        assert_eq!(
+            restored
+                .inquire::<sys::MQLONG>(MqaiSelector(sys::MQIA_CURRENT_Q_DEPTH))
+                .warn_as_error()?,
+            Some(depth)
+        );
+        let name = restored
+            .inquire::<crate::StrCcsidOwned>(MqaiSelector(sys::MQCA_Q_NAME))
+            .warn_as_error()?
+            .map(String::try_from)
+            .transpose()?;
+        assert_eq!(name.as_deref().map(str::trim_end), Some("DEV.QUEUE.1"));
+
+        Ok(())
+    }
+}
@@ -1,8 +1,19 @@
 mod bag;
 mod bag_item;
+mod convert;
+mod dump;
 mod execute;
+mod inquire;
 pub mod iterator;
+mod message;
+mod mqsc;
+mod response;
 
 pub use bag::*;
 pub use bag_item::*;
+pub use convert::*;
+pub use dump::*;
 pub use execute::*;
+pub use inquire::*;
+pub use mqsc::*;
+pub use response::*;
@@ -0,0 +1,307 @@
+use libmqm_sys::function;
+
+use crate::core::Library;
+use crate::values::{MqaiSelector, MQCBO, MQCMD};
+use crate::{prelude::*, sys, Completion, Error, FromStringCcsidError, ResultCompErr, StrCcsidOwned, WithMqError};
+
+use super::{execute_response, Bag, BagDrop, PutStringCcsidError, QueueManagerAdmin};
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum MqscError {
+    /// The command failed. `blocks` holds the parsed response, such as the `AMQ` messages explaining the failure.
+    #[display("MQSC command '{command}' failed: {source}")]
+    Command {
+        command: String,
+        source: Error,
+        blocks: Vec<MqscBlock>,
+    },
+    #[display("MQSC command could not be added to the admin bag: {}", _0)]
+    #[from]
+    Text(PutStringCcsidError),
+    #[display("Invalid MQSC response text: {}", _0)]
+    #[from]
+    String(FromStringCcsidError),
+    #[from]
+    MQ(Error),
+}
+
+impl WithMqError for MqscError {
+    fn mqi_error(&self) -> Option<&Error> {
+        match self {
+            Self::Command { source: e, .. } | Self::MQ(e) | Self::Text(PutStringCcsidError::Mqi(e)) => Some(e),
+            Self::Text(_) | Self::String(_) => None,
+        }
+    }
+}
+
+/// Attribute displayed in an MQSC response, such as `MAXDEPTH(5000)` or `NOTRIGGER`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqscAttribute {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+/// Message of an MQSC response, such as `AMQ8409I: Display Queue details.`, and the attributes that follow it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MqscBlock {
+    pub message_id: Option<String>,
+    pub message: String,
+    pub attributes: Vec<MqscAttribute>,
+}
+
+/// Response of a single command run by [`MqscAdmin::run_mqsc_script`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqscOutput {
+    pub command: String,
+    pub blocks: Vec<MqscBlock>,
+}
+
+impl MqscBlock {
+    /// Value of the named attribute. Attribute names are case insensitive.
+    #[must_use]
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name.eq_ignore_ascii_case(name))
+            .and_then(|attribute| attribute.value.as_deref())
+    }
+
+    /// Whether the named attribute, with or without a value, is present
+    #[must_use]
+    pub fn has(&self, name: &str) -> bool {
+        self.attributes
+            .iter()
+            .any(|attribute| attribute.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Split the text of an MQSC response into a block for each message
+    #[must_use]
+    pub fn parse(text: &str) -> Vec<Self> {
+        let mut blocks: Vec<Self> = Vec::new();
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if let Some((message_id, message)) = message(line) {
+                blocks.push(Self {
+                    message_id: Some(message_id.to_string()),
+                    message: message.to_string(),
+                    attributes: Vec::new(),
+                });
+            } else {
+                if blocks.is_empty() {
+                    blocks.push(Self::default());
+                }
+                if let Some(block) = blocks.last_mut() {
+                    parse_attributes(line, &mut block.attributes);
+                }
+            }
+        }
+        blocks
+    }
+}
+
+/// Message identifier and text of a line such as `AMQ8409I: Display Queue details.`
+fn message(line: &str) -> Option<(&str, &str)> {
+    let (message_id, message) = line.split_once(':')?;
+    let bytes = message_id.as_bytes();
+    (bytes.len() == 8
+        && bytes[..3].iter().all(u8::is_ascii_uppercase)
+        && bytes[7].is_ascii_uppercase()
+        && bytes.iter().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()))
+    .then_some((message_id, message.trim()))
+}
+
+fn parse_attributes(line: &str, attributes: &mut Vec<MqscAttribute>) {
+    let mut rest = line.trim_start();
+    while !rest.is_empty() {
+        let end = rest.find(|c: char| c == '(' || c.is_whitespace()).unwrap_or(rest.len());
+        let (name, tail) = rest.split_at(end);
+        let (value, tail) = match tail.strip_prefix('(') {
+            Some(tail) => {
+                let close = closing_paren(tail);
+                (
+                    Some(tail[..close].trim().to_string()),
+                    tail.get(close + 1..).unwrap_or_default(),
+                )
+            }
+            None => (None, tail),
+        };
+        attributes.push(MqscAttribute {
+            name: name.to_string(),
+            value,
+        });
+        rest = tail.trim_start();
+    }
+}
+
+/// Offset of the parenthesis that closes a value.
+///
+/// Displayed values are not quoted, so quotes and apostrophes are ordinary characters. The value closes at the
+/// first unbalanced `)` that is followed by white space or the end of the line.
+fn closing_paren(value: &str) -> usize {
+    let mut depth = 0_usize;
+    for (offset, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth > 0 => depth -= 1,
+            ')' if value[offset + 1..].chars().next().map_or(true, char::is_whitespace) => return offset,
+            _ => {}
+        }
+    }
+    value.len()
+}
+
+/// Split an MQSC script into commands.
+///
+/// Lines starting with `*` are comments. A line ending in `+` continues with the next line from its
+/// first non-blank character, and a line ending in `-` continues with the next line from column 1.
+#[must_use]
+pub fn mqsc_commands(script: &str) -> Vec<String> {
+    let mut commands = Vec::new();
+    let mut continued: Option<(String, bool)> = None;
+    for line in script.lines().map(str::trim_end) {
+        let (mut command, trim) = match continued.take() {
+            Some(continuation) => continuation,
+            None if line.trim_start().is_empty() || line.trim_start().starts_with('*') => continue,
+            None => (String::new(), true),
+        };
+        let line = if trim { line.trim_start() } else { line };
+        if let Some(text) = line.strip_suffix('+') {
+            command.push_str(text);
+            continued = Some((command, true));
+        } else if let Some(text) = line.strip_suffix('-') {
+            command.push_str(text);
+            continued = Some((command, false));
+        } else {
+            command.push_str(line);
+            commands.push(command);
+        }
+    }
+    commands.extend(
+        continued
+            .map(|(command, _)| command)
+            .filter(|command| !command.trim().is_empty()),
+    );
+    commands
+}
+
+/// Run MQSC commands through the command server using the `MQCMD_ESCAPE` PCF command
+pub trait MqscAdmin: QueueManagerAdmin {
+    /// Run a single MQSC command, returning the parsed response blocks
+    fn execute_mqsc(&self, command: &str) -> ResultCompErr<Vec<MqscBlock>, MqscError>;
+
+    /// Run each command in an MQSC script, stopping at the first command that fails
+    fn run_mqsc_script(&self, script: &str) -> ResultCompErr<Vec<MqscOutput>, MqscError> {
+        let mut warning = None;
+        let mut outputs = Vec::new();
+        for command in mqsc_commands(script) {
+            let Completion(blocks, command_warning) = self.execute_mqsc(&command)?;
+            warning = warning.or(command_warning);
+            outputs.push(MqscOutput { command, blocks });
+        }
+        Ok(Completion(outputs, warning))
+    }
+}
+
+impl<C> MqscAdmin for C
+where
+    C: QueueManagerAdmin<Lib: Library<MQ: function::Mqai> + Clone>,
+{
+    fn execute_mqsc(&self, command: &str) -> ResultCompErr<Vec<MqscBlock>, MqscError> {
+        // There shouldn't be any warnings for creating a bag - so treat the warning as an error
+        let admin = Bag::new_lib(self.mq().0.clone(), MQCBO(sys::MQCBO_ADMIN_BAG)).warn_as_error()?;
+        admin
+            .add(MqaiSelector(sys::MQIACF_ESCAPE_TYPE), &sys::MQET_MQSC)
+            .warn_as_error()?;
+        admin.add(MqaiSelector(sys::MQCACF_ESCAPE_TEXT), command).warn_as_error()?;
+
+        let (response, result) = execute_response(self, &admin, MQCMD(sys::MQCMD_ESCAPE))?;
+        match result {
+            Ok(Completion((), warning)) => Ok(Completion(MqscBlock::parse(&response_text(&response)?), warning)),
+            // The response bag holds the messages explaining the failure, so keep what can be read of them
+            Err(source) => Err(MqscError::Command {
+                command: command.to_string(),
+                source,
+                blocks: response_text(&response)
+                    .map(|text| MqscBlock::parse(&text))
+                    .unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+/// Escape text of each response in the response bag, one line per item
+fn response_text<L>(response: &Bag<impl BagDrop, L>) -> Result<String, MqscError>
+where
+    L: Library<MQ: function::Mqai> + Clone,
+{
+    let mut text = String::new();
+    for bag in response.try_bag_iter(MqaiSelector(sys::MQHA_BAG_HANDLE)).warn_as_error()? {
+        let bag = bag.warn_as_error()?;
+        for item in bag
+            .try_iter::<StrCcsidOwned>(MqaiSelector(sys::MQCACF_ESCAPE_TEXT))
+            .warn_as_error()?
+        {
+            text.push_str(&String::try_from(item.warn_as_error()?)?);
+            text.push('\n');
+        }
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mqsc_commands, MqscAttribute, MqscBlock};
+
+    #[test]
+    fn parse_response() {
+        let blocks = MqscBlock::parse(
+            "AMQ8409I: Display Queue details.\n   QUEUE(DEV.QUEUE.1)      TYPE(QLOCAL)\n   \
+             DESCR(Queue (one) 'a)b')    NOTRIGGER\n   MAXDEPTH(5000)\n\
+             AMQ8409I: Display Queue details.\n   QUEUE(DEV.QUEUE.2)      TYPE(QLOCAL)\n   DESCR( )\n",
+        );
+        assert_eq!(blocks.len(), 2);
+        let first = &blocks[0];
+        assert_eq!(first.message_id.as_deref(), Some("AMQ8409I"));
+        assert_eq!(first.message, "Display Queue details.");
+        assert_eq!(first.value("queue"), Some("DEV.QUEUE.1"));
+        assert_eq!(first.value("DESCR"), Some("Queue (one) 'a)b'"));
+        assert_eq!(first.value("MAXDEPTH"), Some("5000"));
+        assert!(first.has("NOTRIGGER"));
+        assert_eq!(first.value("NOTRIGGER"), None);
+        assert_eq!(
+            blocks[1].attributes.last(),
+            Some(&MqscAttribute {
+                name: "DESCR".to_string(),
+                value: Some(String::new())
+            })
+        );
+    }
+
+    #[test]
+    fn parse_apostrophe() {
+        let blocks = MqscBlock::parse("   QUEUE(BOB.Q)  DESCR(Bob's queue)  MAXDEPTH(5000)\n   DESCR(it's (a) test's)\n");
+        let attributes = &blocks[0].attributes;
+        assert_eq!(blocks[0].value("DESCR"), Some("Bob's queue"));
+        assert_eq!(blocks[0].value("MAXDEPTH"), Some("5000"));
+        assert_eq!(attributes.len(), 4);
+        assert_eq!(attributes[3].value.as_deref(), Some("it's (a) test's"));
+    }
+
+    #[test]
+    fn script() {
+        let commands = mqsc_commands(
+            "* Define the application queues\n\
+             DEFINE QLOCAL(APP.IN) +\n       DESCR('In') +\n   REPLACE\n\n\
+             ALTER QLOCAL(APP.IN) DESCR('Multi-\n line')\n\
+             *DISPLAY QLOCAL(APP.IN)\n\
+             DISPLAY QLOCAL(APP.*)",
+        );
+        assert_eq!(
+            commands,
+            [
+                "DEFINE QLOCAL(APP.IN) DESCR('In') REPLACE",
+                "ALTER QLOCAL(APP.IN) DESCR('Multi line')",
+                "DISPLAY QLOCAL(APP.*)",
+            ]
+        );
+    }
+}
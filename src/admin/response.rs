@@ -0,0 +1,176 @@
+use std::fmt::{self, Display};
+
+use libmqm_sys::function;
+
+use crate::core::Library;
+use crate::values::{MqaiSelector, MQCC, MQCMD, MQRC};
+use crate::{prelude::*, sys, Error, WithMqError};
+
+use super::{Bag, BagDrop};
+
+/// Failed response of an admin command, as reported in the response bag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseFailure {
+    /// Position of the `MQHA_BAG_HANDLE` response in the response bag, or `None` when the failure is
+    /// only reported by the response bag itself
+    pub response: Option<usize>,
+    pub command: Option<MQCMD>,
+    pub comp_code: MQCC,
+    pub reason: MQRC,
+    /// Parameter in error, as reported by `MQIACF_PARAMETER_ID`
+    pub parameter: Option<MqaiSelector>,
+}
+
+impl Display for ResponseFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self {
+            response,
+            command,
+            comp_code,
+            reason,
+            parameter,
+        } = self;
+        if let Some(response) = response {
+            write!(f, "response {response}: ")?;
+        }
+        if let Some(command) = command {
+            write!(f, "{command} ")?;
+        }
+        write!(f, "{comp_code} - {reason}")?;
+        if let Some(parameter) = parameter {
+            write!(f, " (parameter {parameter})")?;
+        }
+        Ok(())
+    }
+}
+
+struct Failures<'a>(&'a [ResponseFailure]);
+
+impl Display for Failures<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, failure) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            Display::fmt(failure, f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum AdminError {
+    #[display("Admin command failed: {}", Failures(_0))]
+    #[error(ignore)]
+    Failed(Vec<ResponseFailure>),
+    #[from]
+    MQ(Error),
+}
+
+impl WithMqError for AdminError {
+    fn mqi_error(&self) -> Option<&Error> {
+        match self {
+            Self::MQ(e) => Some(e),
+            Self::Failed(_) => None,
+        }
+    }
+}
+
+impl AdminError {
+    /// Failed responses reported by the queue manager
+    #[must_use]
+    pub fn failures(&self) -> &[ResponseFailure] {
+        match self {
+            Self::Failed(failures) => failures,
+            Self::MQ(_) => &[],
+        }
+    }
+}
+
+fn failure<B: BagDrop, L: Library<MQ: function::Mqai>>(
+    bag: &Bag<B, L>,
+    response: Option<usize>,
+) -> Result<Option<ResponseFailure>, Error> {
+    let comp_code = bag
+        .inquire::<sys::MQLONG>(MqaiSelector(sys::MQIASY_COMP_CODE))
+        .warn_as_error()?;
+    let reason = bag.inquire::<sys::MQLONG>(MqaiSelector(sys::MQIASY_REASON)).warn_as_error()?;
+    Ok(match (comp_code, reason) {
+        (Some(sys::MQCC_FAILED), Some(reason)) => Some(ResponseFailure {
+            response,
+            command: bag
+                .inquire::<sys::MQLONG>(MqaiSelector(sys::MQIASY_COMMAND))
+                .warn_as_error()?
+                .map(MQCMD),
+            comp_code: MQCC(sys::MQCC_FAILED),
+            reason: MQRC(reason),
+            parameter: bag
+                .inquire::<sys::MQLONG>(MqaiSelector(sys::MQIACF_PARAMETER_ID))
+                .warn_as_error()?
+                .map(MqaiSelector),
+        }),
+        _ => None,
+    })
+}
+
+impl<B: BagDrop, L: Library<MQ: function::Mqai> + Clone> Bag<B, L> {
+    /// Failures of the `MQHA_BAG_HANDLE` responses in an admin response bag. When no individual response has
+    /// failed, a failure of the response bag itself is returned instead.
+    pub fn response_failures(&self) -> Result<Vec<ResponseFailure>, Error> {
+        let mut failures = Vec::new();
+        for (index, response) in self
+            .try_bag_iter(MqaiSelector(sys::MQHA_BAG_HANDLE))
+            .warn_as_error()?
+            .enumerate()
+        {
+            failures.extend(failure(&response.warn_as_error()?, Some(index))?);
+        }
+        if failures.is_empty() {
+            failures.extend(failure(self, None)?);
+        }
+        Ok(failures)
+    }
+
+    /// Check an admin response bag, returning all the failed responses as an [`AdminError`]
+    pub fn check_response(&self) -> Result<(), AdminError> {
+        let failures = self.response_failures()?;
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(AdminError::Failed(failures))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sys;
+    use crate::values::{MqaiSelector, MQCC, MQCMD, MQRC};
+
+    use super::{AdminError, ResponseFailure};
+
+    #[test]
+    fn display() {
+        let failures = vec![
+            ResponseFailure {
+                response: Some(1),
+                command: Some(MQCMD(sys::MQCMD_CHANGE_Q)),
+                comp_code: MQCC(sys::MQCC_FAILED),
+                reason: MQRC(sys::MQRCCF_CFST_STRING_LENGTH_ERR),
+                parameter: Some(MqaiSelector(sys::MQCA_Q_DESC)),
+            },
+            ResponseFailure {
+                response: None,
+                command: None,
+                comp_code: MQCC(sys::MQCC_FAILED),
+                reason: MQRC(sys::MQRCCF_COMMAND_FAILED),
+                parameter: None,
+            },
+        ];
+        assert_eq!(
+            AdminError::Failed(failures).to_string(),
+            "Admin command failed: response 1: MQCMD_CHANGE_Q MQCC_FAILED - MQRCCF_CFST_STRING_LENGTH_ERR \
+             (parameter MQCA_Q_DESC); MQCC_FAILED - MQRCCF_COMMAND_FAILED"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt::{self, Display};
+
+use crate::sys;
+use crate::values::{MQCC, MQRC, MQRCCF};
+
+/// Offline description of a reason code, summarising the IBM MQ documentation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explanation {
+    /// Completion code that is usually returned with the reason code
+    pub severity: MQCC,
+    pub explanation: &'static str,
+    /// Action the programmer or operator should take
+    pub response: &'static str,
+}
+
+static EXPLANATIONS: phf::Map<sys::MQLONG, Explanation> = include!(concat!(env!("OUT_DIR"), "/mqexplain.rs"));
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self {
+            explanation, response, ..
+        } = self;
+        write!(f, "{explanation} Response: {response}")
+    }
+}
+
+impl MQRC {
+    /// Explanation and response of the reason code, when it is in the catalogue
+    #[must_use]
+    pub fn explanation(&self) -> Option<&'static Explanation> {
+        EXPLANATIONS.get(&self.value())
+    }
+}
+
+impl MQRCCF {
+    /// Explanation and response of the PCF reason code, when it is in the catalogue
+    #[must_use]
+    pub fn explanation(&self) -> Option<&'static Explanation> {
+        EXPLANATIONS.get(&self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::{mapping, ConstLookup};
+    use crate::sys;
+    use crate::values::{MQCC, MQRC, MQRCCF};
+    use crate::Error;
+
+    #[test]
+    fn explanation() {
+        let explanation = MQRC(sys::MQRC_Q_FULL)
+            .explanation()
+            .expect("MQRC_Q_FULL should be in the catalogue");
+        assert_eq!(explanation.severity, MQCC(sys::MQCC_FAILED));
+        assert!(explanation.response.contains("MAXDEPTH"));
+        assert!(MQRCCF(sys::MQRCCF_COMMAND_FAILED).explanation().is_some());
+        assert_eq!(MQRC(-1).explanation(), None);
+    }
+
+    #[test]
+    fn catalogue_complete() {
+        for (value, name) in mapping::MQRC_CONST.all() {
+            assert!(MQRC(value).explanation().is_some(), "{name} is not in the catalogue");
+        }
+        for (value, name) in mapping::MQRCCF_CONST.all() {
+            assert!(MQRCCF(value).explanation().is_some(), "{name} is not in the catalogue");
+        }
+    }
+
+    #[test]
+    fn error_alternate() {
+        let error = Error(MQCC(sys::MQCC_FAILED), "MQPUT", MQRC(sys::MQRC_Q_FULL));
+        assert_eq!(error.to_string(), "MQCC_FAILED: MQPUT - MQRC_Q_FULL");
+        assert!(format!("{error:#}").starts_with("MQCC_FAILED: MQPUT - MQRC_Q_FULL: The queue already contains"));
+    }
+}
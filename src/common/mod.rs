@@ -1,10 +1,17 @@
+#[cfg(feature = "explain")]
+mod explain;
 pub mod macros;
 pub mod mqmask;
 mod mqstr;
 pub mod mqvalue;
 mod parameters;
 mod result;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod serialize;
 
+#[cfg(feature = "explain")]
+pub use explain::*;
 pub use mqstr::*;
 pub use result::*;
 pub use parameters::*;
@@ -19,3 +26,40 @@ macro_rules! impl_default_mqvalue {
         }
     };
 }
+
+/// Implements `Serialize` and `Deserialize` for a `define_mqvalue!` or `define_mqmask!` type
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_mqconst_serde {
+    (value $i:ident) => {
+        impl $crate::serialize::serde::Serialize for $i {
+            fn serialize<S: $crate::serialize::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $crate::serialize::serialize_value(self.0, self.mq_primary_name(), serializer)
+            }
+        }
+        $crate::impl_mqconst_serde!(deserialize $i);
+    };
+    (mask $i:ident) => {
+        impl $crate::serialize::serde::Serialize for $i {
+            fn serialize<S: $crate::serialize::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $crate::serialize::serialize_mask(self.0, Self::const_lookup(), self, serializer)
+            }
+        }
+        $crate::impl_mqconst_serde!(deserialize $i);
+    };
+    (deserialize $i:ident) => {
+        impl<'de> $crate::serialize::serde::Deserialize<'de> for $i {
+            fn deserialize<D: $crate::serialize::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $crate::serialize::deserialize_const(deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_mqconst_serde {
+    ($kind:ident $i:ident) => {};
+}
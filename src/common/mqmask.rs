@@ -1,6 +1,6 @@
 #![allow(clippy::allow_attributes, reason = "Macro include 'allow' for generation purposes")]
 
-use std::borrow::Cow;
+use std::{borrow::Cow, num::ParseIntError};
 
 use crate::{sys, ConstLookup, ConstantItem};
 
@@ -40,15 +40,12 @@ macro_rules! define_mqmask {
             }
         }
 
+        // Parses the `Display` format, see `mqmask::parse_mask`
         impl std::str::FromStr for $i {
-            type Err = <$crate::sys::MQLONG as std::str::FromStr>::Err;
-
-            fn from_str(name: &str) -> Result<Self, Self::Err> {
-                Ok(Self(
-                    Self::const_lookup()
-                        .by_name(name)
-                        .map_or_else(|| std::str::FromStr::from_str(name), Ok)?,
-                ))
+            type Err = $crate::mqmask::MaskParseError;
+
+            fn from_str(mask: &str) -> Result<Self, Self::Err> {
+                $crate::mqmask::parse_mask(Self::const_lookup(), mask).map(Self)
             }
         }
 
@@ -124,9 +121,75 @@ macro_rules! define_mqmask {
                 $crate::mqmask::mask_debug(stringify!($i), self.0, Self::const_lookup(), f)
             }
         }
+
+        $crate::impl_mqconst_serde!(mask $i);
     };
 }
 
+/// Error parsing a `|` separated mask of constant names and numbers
+#[derive(derive_more::Error, derive_more::Display, Debug, Clone, PartialEq, Eq)]
+pub enum MaskParseError {
+    #[display("Mask term {_0} is empty")]
+    #[error(ignore)]
+    Empty(usize),
+    #[display("Mask term {position} `{name}` is not a known constant")]
+    Unknown { name: String, position: usize },
+    #[display("Mask term {position} `{term}` is not a valid number: {source}")]
+    Number {
+        term: String,
+        position: usize,
+        source: ParseIntError,
+    },
+}
+
+/// Parse a mask of `|` separated terms, as rendered by the `Display` of a mask. Each term is either a
+/// constant name or a decimal or `0x` prefixed hexadecimal number. Names are case-insensitive and the
+/// constant prefix (such as `MQOO_`) is optional. Term positions in errors start at 1.
+pub fn parse_mask(lookup: &impl ConstLookup, mask: &str) -> Result<sys::MQLONG, MaskParseError> {
+    // The prefix shared by all the constant names, up to the last underscore (such as `MQBNO_BALTYPE_`)
+    let prefix = lookup
+        .all()
+        .map(|(.., name)| name)
+        .reduce(|prefix, name| {
+            let common = prefix.bytes().zip(name.bytes()).take_while(|(a, b)| a == b).count();
+            &prefix[..common]
+        })
+        .and_then(|prefix| prefix.rfind('_').map(|index| &prefix[..=index]));
+
+    mask.split('|').zip(1..).try_fold(0, |acc, (term, position)| {
+        let term = term.trim();
+        if term.is_empty() {
+            return Err(MaskParseError::Empty(position));
+        }
+        let value = if term.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+') {
+            parse_number(term).map_err(|source| MaskParseError::Number {
+                term: term.to_string(),
+                position,
+                source,
+            })?
+        } else {
+            let name = term.to_ascii_uppercase();
+            lookup
+                .by_name(&name)
+                .or_else(|| prefix.and_then(|prefix| lookup.by_name(&format!("{prefix}{name}"))))
+                .ok_or_else(|| MaskParseError::Unknown {
+                    name: term.to_string(),
+                    position,
+                })?
+        };
+        Ok(acc | value)
+    })
+}
+
+fn parse_number(term: &str) -> Result<sys::MQLONG, ParseIntError> {
+    match term.strip_prefix("0x").or_else(|| term.strip_prefix("0X")) {
+        // Hexadecimal masks are rendered as unsigned, so the top bit is the sign bit
+        #[expect(clippy::cast_possible_wrap)]
+        Some(hex) => u32::from_str_radix(hex, 16).map(|value| value as sys::MQLONG),
+        None => term.parse(),
+    }
+}
+
 pub(crate) fn mask_debug(
     type_name: &str,
     value: sys::MQLONG,
@@ -183,8 +246,12 @@ pub(crate) fn mask_str<'a>(
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
+
     use crate::ConstantItem;
 
+    use super::MaskParseError;
+
     const ONEB: &[ConstantItem] = &[
         (0, "ZERO"),
         (0, "ZERO_ALIAS"),
@@ -217,4 +284,32 @@ mod test {
         assert_eq!(format!("{:?}", MaskOne::from(0b100)), "MaskOne(0x4)");
         assert_eq!(format!("{:?}", NoZero::from(0)), "NoZero(0x0)");
     }
+
+    const PREFIXED: &[ConstantItem] = &[(0, "MQXX_A_NONE"), (1, "MQXX_A_ONE"), (2, "MQXX_A_TWO"), (4, "MQXX_A_FOUR")];
+    define_mqmask!(Prefixed, PREFIXED);
+
+    #[test]
+    fn mask_from_str() {
+        assert_eq!(Prefixed::from_str("MQXX_A_ONE|MQXX_A_FOUR"), Ok(Prefixed::from(5)));
+        assert_eq!(Prefixed::from_str("one | mqxx_a_two|0x100"), Ok(Prefixed::from(0x103)));
+        assert_eq!(Prefixed::from_str("8|MQXX_A_NONE"), Ok(Prefixed::from(8)));
+        assert_eq!(Prefixed::from_str("0xFFFFFFFF"), Ok(Prefixed::from(-1)));
+        assert_eq!(Prefixed::from_str("ONE||TWO"), Err(MaskParseError::Empty(2)));
+        assert_eq!(
+            Prefixed::from_str("ONE|THREE"),
+            Err(MaskParseError::Unknown {
+                name: "THREE".to_string(),
+                position: 2
+            })
+        );
+        assert_eq!(
+            Prefixed::from_str("0xZZ").map_err(|e| e.to_string()),
+            Err("Mask term 1 `0xZZ` is not a valid number: invalid digit found in string".to_string())
+        );
+
+        for value in [0, 1, 6, 0x107, -8] {
+            let mask = Prefixed::from(value);
+            assert_eq!(Prefixed::from_str(&mask.to_string()), Ok(mask));
+        }
+    }
 }
@@ -67,6 +67,8 @@ macro_rules! define_mqvalue {
                 $crate::mqvalue::value_debug(stringify!($i), *attribute, self.mq_names(), f)
             }
         }
+
+        $crate::impl_mqconst_serde!(value $i);
     };
 }
 
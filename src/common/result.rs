@@ -1,17 +1,38 @@
-use crate::values::{MQCC, MQRC};
+use crate::values::{MQCC, MQRC, MQRCCF};
 use crate::sys;
 use crate::HasMqNames;
 use std::fmt::{Debug, Display};
 
+fn ibm_reference_url(name: &str, code: sys::MQLONG, language: &str, version: Option<&str>) -> String {
+    let name = name.to_lowercase().replace('_', "-");
+    let version = version.unwrap_or("latest");
+    format!("https://www.ibm.com/docs/{language}/ibm-mq/{version}?topic=codes-{code}-{code:04x}-rc{code}-{name}")
+}
+
 impl MQRC {
     #[must_use]
     pub fn ibm_reference_url(&self, language: &str, version: Option<&str>) -> Option<String> {
-        let name = self.mq_primary_name()?.to_lowercase().replace('_', "-");
-        let version = version.unwrap_or("latest");
-        let code = self.value();
-        Some(format!(
-            "https://www.ibm.com/docs/{language}/ibm-mq/{version}?topic=codes-{code}-{code:04x}-rc{code}-{name}"
-        ))
+        Some(ibm_reference_url(self.mq_primary_name()?, self.value(), language, version))
+    }
+
+    /// The PCF reason code, when the reason code is in the `MQRCCF` range
+    #[must_use]
+    pub fn pcf_reason(&self) -> Option<MQRCCF> {
+        let reason = MQRCCF(self.value());
+        reason.mq_primary_name().is_some().then_some(reason)
+    }
+}
+
+impl MQRCCF {
+    #[must_use]
+    pub fn ibm_reference_url(&self, language: &str, version: Option<&str>) -> Option<String> {
+        Some(ibm_reference_url(self.mq_primary_name()?, self.value(), language, version))
+    }
+}
+
+impl From<MQRCCF> for MQRC {
+    fn from(MQRCCF(reason): MQRCCF) -> Self {
+        Self(reason)
     }
 }
 /// A value returned from an MQ API call, optionally with a warning [`MQRC`]
@@ -86,10 +107,23 @@ impl<T: Display> Display for Completion<T> {
 }
 
 /// MQ failure with [`MQCC`] != `MQCC_OK`. Has the associated verb and [`MQRC`].
-#[derive(Debug, derive_more::Error, derive_more::Display)]
-#[display("{_0}: {_1} - {_2}")]
+///
+/// The alternate form of [`Display`] (`{:#}`) includes the explanation of the reason code when the `explain` feature is enabled.
+#[derive(Debug, derive_more::Error)]
 pub struct Error(pub MQCC, pub &'static str, pub MQRC);
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Self(cc, verb, rc) = self;
+        write!(f, "{cc}: {verb} - {rc}")?;
+        #[cfg(feature = "explain")]
+        if let Some(explanation) = rc.explanation().filter(|_| f.alternate()) {
+            write!(f, ": {explanation}")?;
+        }
+        Ok(())
+    }
+}
+
 /// Result of an MQI API call wrapped in a `Completion` for warnings
 pub type ResultCompErr<T, E> = Result<Completion<T>, E>;
 /// Result of an MQI API call wrapped in a `Completion` for warnings and with an MQ `Error` for errors
@@ -160,7 +194,7 @@ impl<T, E: From<Error>> ResultCompExt<T, E> for ResultCompErr<T, E> {
 #[cfg(test)]
 mod tests {
     use crate::sys;
-    use crate::values::MQRC;
+    use crate::values::{MQRC, MQRCCF};
 
     #[test]
     fn mqmd_new() {
@@ -175,6 +209,20 @@ mod tests {
         assert_eq!(MQRC::from(-1).to_string(), "-1");
     }
 
+    #[test]
+    fn pcf_reason_code() {
+        assert_eq!(MQRCCF::from(sys::MQRCCF_COMMAND_FAILED).to_string(), "MQRCCF_COMMAND_FAILED");
+        assert_eq!(
+            MQRC::from(sys::MQRCCF_CFST_STRING_LENGTH_ERR).pcf_reason(),
+            Some(MQRCCF(sys::MQRCCF_CFST_STRING_LENGTH_ERR))
+        );
+        assert_eq!(MQRC::from(sys::MQRC_Q_MGR_ACTIVE).pcf_reason(), None);
+        assert_eq!(
+            "MQRCCF_COMMAND_FAILED".parse::<MQRCCF>().map(MQRC::from),
+            Ok(MQRC(sys::MQRCCF_COMMAND_FAILED))
+        );
+    }
+
     #[test]
     fn ibm_reference_url() {
         assert_eq!(
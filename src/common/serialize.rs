@@ -0,0 +1,202 @@
+//! Serde support for the MQ value and mask types, [`MqStr`] and the object names
+
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{
+    mqmask, sys,
+    types::{Identifier, QueueManagerName, QueueName},
+    ConstLookup, MqStr,
+};
+
+// Used by the `define_mqvalue!` and `define_mqmask!` macros
+pub use serde;
+
+/// Serialize an MQ value as its constant name, falling back to the integer when there is no name
+pub fn serialize_value<S: Serializer>(value: sys::MQLONG, name: Option<&str>, serializer: S) -> Result<S::Ok, S::Error> {
+    match name {
+        Some(name) => serializer.serialize_str(name),
+        None => serializer.serialize_i32(value),
+    }
+}
+
+/// Serialize an MQ mask in its `Display` form, falling back to the integer when none of the constants match
+pub fn serialize_mask<S: Serializer>(
+    value: sys::MQLONG,
+    lookup: &impl ConstLookup,
+    mask: &impl Display,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let (.., residual) = mqmask::masked_list(value, lookup.all());
+    if residual != value || (value == 0 && lookup.by_value(0).next().is_some()) {
+        serializer.collect_str(mask)
+    } else {
+        serializer.serialize_i32(value)
+    }
+}
+
+/// Deserialize an MQ value or mask from the constant names (using `FromStr`) or an integer
+pub fn deserialize_const<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err: Display> + From<sys::MQLONG>,
+{
+    deserializer.deserialize_any(ConstVisitor(PhantomData))
+}
+
+struct ConstVisitor<T>(PhantomData<T>);
+
+impl<T: FromStr<Err: Display> + From<sys::MQLONG>> Visitor<'_> for ConstVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an MQ constant name or integer")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        sys::MQLONG::try_from(value)
+            .map(T::from)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        sys::MQLONG::try_from(value)
+            .map(T::from)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+/// Visitor for the types that deserialize from their `FromStr` implementation
+struct StrVisitor<T>(PhantomData<T>, &'static str);
+
+impl<T: FromStr<Err: Display>> Visitor<'_> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.1)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+impl<const N: usize> Serialize for MqStr<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for MqStr<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor(PhantomData, "a string no longer than the MQ field"))
+    }
+}
+
+impl<const N: usize> Serialize for Identifier<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Identifier<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor(PhantomData, "an identifier in the `ID:` hex form"))
+    }
+}
+
+impl Serialize for QueueName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for QueueName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MqStr::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Serialize for QueueManagerName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for QueueManagerName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MqStr::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sys;
+    use crate::types::{Identifier, QueueName};
+    use crate::values::{MQCC, MQOO, MQRC};
+    use crate::MqStr;
+
+    #[test]
+    fn values() -> Result<(), serde_json::Error> {
+        assert_eq!(serde_json::to_string(&MQCC(sys::MQCC_WARNING))?, "\"MQCC_WARNING\"");
+        assert_eq!(serde_json::to_string(&MQCC(99))?, "99");
+        assert_eq!(
+            serde_json::from_str::<MQRC>("\"MQRC_NO_MSG_AVAILABLE\"")?,
+            MQRC(sys::MQRC_NO_MSG_AVAILABLE)
+        );
+        assert_eq!(serde_json::from_str::<MQRC>("2033")?, MQRC(sys::MQRC_NO_MSG_AVAILABLE));
+        assert!(serde_json::from_str::<MQRC>("\"MQRC_NOT_A_REASON\"").is_err());
+        assert!(serde_json::from_str::<MQRC>("4294967296").is_err());
+
+        for value in [sys::MQCC_OK, sys::MQCC_FAILED, 99] {
+            let json = serde_json::to_string(&MQCC(value))?;
+            assert_eq!(serde_json::from_str::<MQCC>(&json)?, MQCC(value));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn masks() -> Result<(), serde_json::Error> {
+        assert_eq!(serde_json::to_string(&MQOO(sys::MQOO_INPUT_SHARED))?, "\"MQOO_INPUT_SHARED\"");
+        assert_eq!(
+            serde_json::from_str::<MQOO>("\"input_shared|FAIL_IF_QUIESCING\"")?,
+            MQOO(sys::MQOO_INPUT_SHARED | sys::MQOO_FAIL_IF_QUIESCING)
+        );
+        assert_eq!(serde_json::from_str::<MQOO>("16")?, MQOO(sys::MQOO_OUTPUT));
+
+        for value in [
+            sys::MQOO_INPUT_SHARED | sys::MQOO_FAIL_IF_QUIESCING,
+            sys::MQOO_OUTPUT | 0x0100_0000,
+            0,
+        ] {
+            let json = serde_json::to_string(&MQOO(value))?;
+            assert_eq!(serde_json::from_str::<MQOO>(&json)?, MQOO(value));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn strings() -> Result<(), serde_json::Error> {
+        let queue = QueueName(MqStr::def_from_str("DEV.QUEUE.1"));
+        assert_eq!(serde_json::to_string(&queue)?, "\"DEV.QUEUE.1\"");
+        assert_eq!(serde_json::from_str::<QueueName>("\"DEV.QUEUE.1\"")?, queue);
+        assert!(serde_json::from_str::<MqStr<4>>("\"TOO LONG\"").is_err());
+
+        let id = Identifier([0xab; 24]);
+        let json = serde_json::to_string(&id)?;
+        assert_eq!(json, format!("\"ID:{}\"", "ab".repeat(24)));
+        assert_eq!(serde_json::from_str::<Identifier<24>>(&json)?, id);
+        assert!(serde_json::from_str::<Identifier<24>>("\"ID:abcd\"").is_err());
+        Ok(())
+    }
+}
@@ -29,10 +29,18 @@ mod generated {
 
 pub use generated::*;
 
-type MqxaSource<'a> = ConstSource<BinarySearchSource<'a>, BinarySearchSource<'a>>;
-
-/// Selectors for MQIA and MQCA combined
-pub const MQXA_FULL_CONST: MqxaSource = ConstSource(mapping::MQIA_CONST, mapping::MQCA_CONST);
-
-/// Combined MQRCCF and MQRC range
-pub const MQRC_FULL_CONST: ConstSource<PhfSource, PhfSource> = ConstSource(mapping::MQRC_CONST, mapping::MQRCCF_CONST);
+/// Group status of the `MQGMO`. The `MQGS_*` constants are single `MQCHAR` values so they aren't generated.
+pub const MQGS_CONST: LinearSource = ConstSource(
+    &[
+        (32, "MQGS_NOT_IN_GROUP"),
+        (71, "MQGS_MSG_IN_GROUP"),
+        (76, "MQGS_LAST_MSG_IN_GROUP"),
+    ],
+    &[],
+);
+
+/// Segment status of the `MQGMO`. The `MQSS_*` constants are single `MQCHAR` values so they aren't generated.
+pub const MQSS_CONST: LinearSource = ConstSource(
+    &[(32, "MQSS_NOT_A_SEGMENT"), (76, "MQSS_LAST_SEGMENT"), (83, "MQSS_SEGMENT")],
+    &[],
+);
@@ -1974,6 +1974,2999 @@ pub const MQZTO_CONST: LinearSource = ConstSource(&[(0,"MQZTO_PRIMARY"),(1,"MQZT
 pub const MQ_CERT_CONST: LinearSource = ConstSource(&[(0,"MQ_CERT_VAL_POLICY_ANY"),(1,"MQ_CERT_VAL_POLICY_RFC5280"),(2,"MQ_CERT_VAL_POLICY_NONE"),], &[(0,"MQ_CERT_VAL_POLICY_DEFAULT"),]);
 pub const MQ_MQTT_CONST: LinearSource = ConstSource(&[(65536,"MQ_MQTT_MAX_KEEP_ALIVE"),], &[]);
 pub const MQ_SUITE_CONST: LinearSource = ConstSource(&[(0,"MQ_SUITE_B_NOT_AVAILABLE"),(1,"MQ_SUITE_B_NONE"),(2,"MQ_SUITE_B_128_BIT"),(4,"MQ_SUITE_B_192_BIT"),], &[(4,"MQ_SUITE_B_SIZE"),]);
+/// Selectors including MQIA, MQCA, MQIACF, MQCACF, MQIACH, MQCACH, MQIASY and MQHA
+pub const MQAI_SELECTOR_CONST: PhfSource = ConstSource(&::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 578),
+        (0, 10),
+        (0, 380),
+        (0, 1),
+        (0, 166),
+        (0, 512),
+        (0, 66),
+        (0, 73),
+        (0, 2),
+        (0, 566),
+        (0, 5),
+        (0, 238),
+        (0, 1),
+        (0, 93),
+        (0, 2),
+        (0, 1),
+        (0, 250),
+        (0, 1),
+        (0, 247),
+        (0, 3),
+        (0, 141),
+        (0, 9),
+        (0, 5),
+        (0, 92),
+        (1, 4),
+        (0, 216),
+        (0, 1),
+        (0, 5),
+        (0, 0),
+        (0, 139),
+        (0, 87),
+        (0, 442),
+        (0, 762),
+        (0, 110),
+        (0, 782),
+        (0, 23),
+        (0, 6),
+        (0, 27),
+        (0, 248),
+        (0, 194),
+        (1, 828),
+        (0, 2),
+        (0, 17),
+        (0, 1),
+        (0, 599),
+        (2, 491),
+        (0, 90),
+        (0, 252),
+        (0, 0),
+        (0, 13),
+        (0, 55),
+        (1, 529),
+        (0, 9),
+        (0, 12),
+        (2, 45),
+        (0, 104),
+        (0, 573),
+        (2, 354),
+        (2, 285),
+        (0, 0),
+        (0, 68),
+        (0, 83),
+        (0, 2),
+        (4, 367),
+        (0, 160),
+        (0, 159),
+        (1, 16),
+        (0, 7),
+        (0, 1028),
+        (0, 1),
+        (0, 15),
+        (0, 110),
+        (0, 515),
+        (0, 23),
+        (0, 1),
+        (0, 250),
+        (2, 658),
+        (0, 3),
+        (0, 3),
+        (0, 1),
+        (0, 1),
+        (0, 88),
+        (0, 292),
+        (1, 187),
+        (0, 0),
+        (0, 1078),
+        (0, 94),
+        (0, 55),
+        (0, 334),
+        (0, 176),
+        (0, 220),
+        (3, 251),
+        (4, 995),
+        (0, 15),
+        (0, 0),
+        (0, 1091),
+        (0, 2),
+        (0, 430),
+        (0, 0),
+        (0, 101),
+        (2, 377),
+        (2, 707),
+        (0, 1),
+        (0, 141),
+        (0, 7),
+        (0, 849),
+        (0, 402),
+        (0, 6),
+        (0, 15),
+        (0, 367),
+        (0, 3),
+        (0, 3),
+        (0, 19),
+        (1, 623),
+        (4, 681),
+        (0, 10),
+        (0, 0),
+        (1, 947),
+        (0, 949),
+        (0, 2),
+        (0, 0),
+        (0, 55),
+        (1, 96),
+        (0, 0),
+        (0, 129),
+        (1, 663),
+        (0, 14),
+        (0, 53),
+        (0, 286),
+        (0, 634),
+        (0, 412),
+        (0, 127),
+        (0, 8),
+        (0, 3),
+        (0, 0),
+        (0, 42),
+        (1, 1086),
+        (0, 401),
+        (0, 1),
+        (0, 364),
+        (0, 139),
+        (0, 551),
+        (0, 0),
+        (0, 711),
+        (0, 0),
+        (0, 20),
+        (17, 310),
+        (9, 1148),
+        (0, 241),
+        (0, 95),
+        (0, 21),
+        (2, 495),
+        (0, 275),
+        (0, 113),
+        (2, 800),
+        (0, 273),
+        (1, 159),
+        (0, 192),
+        (0, 1),
+        (0, 53),
+        (0, 19),
+        (0, 1),
+        (0, 873),
+        (13, 582),
+        (0, 0),
+        (11, 431),
+        (0, 13),
+        (0, 52),
+        (0, 1),
+        (0, 267),
+        (0, 150),
+        (0, 124),
+        (0, 3),
+        (0, 106),
+        (0, 110),
+        (0, 292),
+        (0, 0),
+        (0, 16),
+        (1, 534),
+        (15, 554),
+        (1, 48),
+        (1, 38),
+        (1, 18),
+        (0, 437),
+        (0, 482),
+        (1, 0),
+        (0, 913),
+        (1, 558),
+        (2, 805),
+        (0, 2),
+        (0, 163),
+        (0, 131),
+        (0, 463),
+        (4, 1070),
+        (2, 412),
+        (0, 303),
+        (0, 50),
+        (0, 10),
+        (0, 42),
+        (1, 1134),
+        (0, 247),
+        (3, 748),
+        (3, 659),
+        (0, 0),
+        (12, 100),
+        (2, 598),
+        (0, 249),
+        (0, 2),
+        (0, 10),
+        (0, 23),
+        (0, 6),
+        (0, 133),
+        (0, 44),
+        (0, 306),
+        (0, 174),
+        (0, 1039),
+        (0, 83),
+        (12, 619),
+        (7, 185),
+        (0, 2),
+        (2, 809),
+        (13, 1062),
+        (0, 803),
+        (25, 661),
+        (0, 132),
+        (0, 3),
+        (0, 0),
+        (12, 51),
+        (0, 28),
+        (0, 359),
+        (27, 390),
+        (0, 833),
+        (0, 0),
+        (0, 420),
+        (1, 642),
+    ],
+    entries: &[
+        (250, "MQIA_DEF_CLUSTER_XMIT_Q_TYPE"),
+        (1381, "MQIACF_DISCONNECT_TIME"),
+        (2032, "MQCA_Q_MGR_IDENTIFIER"),
+        (1074, "MQIACF_INQUIRY"),
+        (3142, "MQCACF_STRUC_ID"),
+        (3092, "MQCACF_CONFIGURATION_TIME"),
+        (3103, "MQCACF_CF_STRUC_LOG_Q_MGRS"),
+        (3065, "MQCACF_PRINCIPAL_ENTITY_NAMES"),
+        (231, "MQIA_RESPONSE_RESTART_POINT"),
+        (37, "MQIA_MSG_ENQ_COUNT"),
+        (3116, "MQCACF_SYSP_ARCHIVE_UNIT1"),
+        (1387, "MQIACF_XA_HANDLE"),
+        (3014, "MQCACF_ESCAPE_TEXT"),
+        (3210, "MQCACF_APPL_IMMOVABLE_TIME"),
+        (96, "MQIA_CLWL_Q_PRIORITY"),
+        (1406, "MQIACF_AMQP_DIAGNOSTICS_TYPE"),
+        (1022, "MQIACF_OPEN_OPTIONS"),
+        (1105, "MQIACF_Q_STATUS"),
+        (3090, "MQCACF_SECURITY_PROFILE"),
+        (1625, "MQIACH_MSG_HISTORY"),
+        (161, "MQIA_QMOPT_LOG_CRITICAL_MSGS"),
+        (1614, "MQIACH_DEF_CHANNEL_DISP"),
+        (3004, "MQCACF_TO_PROCESS_NAME"),
+        (1378, "MQIACF_ITEM_COUNT"),
+        (2098, "MQCA_RESUME_DATE"),
+        (2031, "MQCA_CLUSTER_Q_MGR_NAME"),
+        (38, "MQIA_MSG_DEQ_COUNT"),
+        (1503, "MQIACH_DISC_INTERVAL"),
+        (1567, "MQIACH_BATCH_HB"),
+        (1590, "MQIACH_CURRENT_CHL_MAX"),
+        (3057, "MQCACF_REG_SUB_USER_DATA"),
+        (2126, "MQCA_LDAP_BASE_DN_USERS"),
+        (107, "MQIA_LISTENER_TIMER"),
+        (3025, "MQCACF_USER_IDENTIFIER"),
+        (3146, "MQCACF_SYSP_OFFLINE_RBA"),
+        (232, "MQIA_COMM_EVENT"),
+        (12, "MQIA_USAGE"),
+        (1452, "MQIACF_LOG_FS_IN_USE"),
+        (1093, "MQIACF_CLUSTER_Q_MGR_ATTRS"),
+        (3520, "MQCACH_LOCAL_ADDRESS"),
+        (3176, "MQCACF_Q_MGR_START_TIME"),
+        (1193, "MQIACF_SYSP_Q_INDEX_DEFER"),
+        (1417, "MQIACF_MEDIA_LOG_SIZE"),
+        (244, "MQIA_CF_RECAUTO"),
+        (8, "MQIA_HARDEN_GET_BACKOUT"),
+        (243, "MQIA_XR_CAPABILITY"),
+        (1532, "MQIACH_CURRENT_SEQ_NUMBER"),
+        (1645, "MQIACH_SECURITY_PROTOCOL"),
+        (70, "MQIA_CF_LEVEL"),
+        (259, "MQIA_AUTHENTICATION_FAIL_DELAY"),
+        (2023, "MQCA_TRIGGER_DATA"),
+        (3044, "MQCACF_REG_CORREL_ID"),
+        (1190, "MQIACF_SYSP_EXIT_TASKS"),
+        (75, "MQIA_SSL_EVENT"),
+        (113, "MQIA_RECEIVE_TIMEOUT_MIN"),
+        (1081, "MQIACF_REGISTRATION_OPTIONS"),
+        (1535, "MQIACH_BYTES_SENT"),
+        (3048, "MQCACF_AUTH_INFO_NAMES"),
+        (1223, "MQIACF_LISTENER_STATUS_ATTRS"),
+        (1129, "MQIACF_SECURITY_ITEM"),
+        (219, "MQIA_PUB_SCOPE"),
+        (3147, "MQCACF_SYSP_ARCHIVE_PFX2"),
+        (2080, "MQCA_SERVICE_START_ARGS"),
+        (117, "MQIA_CHINIT_TRACE_AUTO_START"),
+        (227, "MQIA_CF_OFFLOAD_THRESHOLD3"),
+        (1142, "MQIACF_CF_STRUC_ENTRIES_MAX"),
+        (264, "MQIA_LDAP_NESTGRP"),
+        (2065, "MQCA_SYSTEM_LOG_Q_NAME"),
+        (1288, "MQIACF_WILDCARD_SCHEMA"),
+        (1361, "MQIACF_CALL_TYPE"),
+        (1291, "MQIACF_Q_MGR_PUBSUB"),
+        (2026, "MQCA_CHANNEL_AUTO_DEF_EXIT"),
+        (1244, "MQIACF_EXPIRY"),
+        (1384, "MQIACF_XA_RMID"),
+        (1394, "MQIACF_INT_ATTRS"),
+        (1004, "MQIACF_NAMELIST_ATTRS"),
+        (3074, "MQCACF_LOG_PATH"),
+        (165, "MQIA_QMOPT_TRACE_MQI_CALLS"),
+        (1631, "MQIACH_IN_DOUBT_IN"),
+        (1523, "MQIACH_CHANNEL_INSTANCE_TYPE"),
+        (1354, "MQIACF_MQXR_DIAGNOSTICS_TYPE"),
+        (254, "MQIA_REVERSE_DNS_LOOKUP"),
+        (1597, "MQIACH_ACTIVE_CHL_STOPPED"),
+        (1428, "MQIACF_APPL_INFO_TYPE"),
+        (272, "MQIA_MEDIA_IMAGE_RECOVER_Q"),
+        (131, "MQIA_STATISTICS_INTERVAL"),
+        (2097, "MQCA_MODEL_NON_DURABLE_Q"),
+        (2019, "MQCA_BACKOUT_REQ_Q_NAME"),
+        (3154, "MQCACF_DESTINATION"),
+        (2002, "MQCA_BASE_OBJECT_NAME"),
+        (31, "MQIA_COMMAND_LEVEL"),
+        (1586, "MQIACH_DISPS_MAX"),
+        (1192, "MQIACF_SYSP_OTMA_INTERVAL"),
+        (82, "MQIA_Q_USERS"),
+        (3127, "MQCACF_TO_SERVICE_NAME"),
+        (32, "MQIA_PLATFORM"),
+        (2111, "MQCA_COMM_INFO_DESC"),
+        (1024, "MQIACF_PROCESS_ID"),
+        (3202, "MQCACF_XQH_REMOTE_Q_MGR"),
+        (3093, "MQCACF_FROM_CF_STRUC_NAME"),
+        (1182, "MQIACF_SYSP_ARCHIVE"),
+        (3104, "MQCACF_FROM_STORAGE_CLASS"),
+        (3197, "MQCACF_RESOLVED_Q_MGR"),
+        (85, "MQIA_LISTENER_PORT_NUMBER"),
+        (1392, "MQIACF_SELECTORS"),
+        (78, "MQIA_NPM_CLASS"),
+        (3190, "MQCACF_DYNAMIC_Q_NAME"),
+        (3568, "MQCACH_MCA_USER_ID_LIST"),
+        (1628, "MQIACH_MC_HB_INTERVAL"),
+        (1365, "MQIACF_CLOSE_OPTIONS"),
+        (34, "MQIA_DIST_LISTS"),
+        (221, "MQIA_GROUP_UR"),
+        (3139, "MQCACF_REPLY_TO_Q"),
+        (1132, "MQIACF_UOW_TYPE"),
+        (1583, "MQIACH_ADAPS_STARTED"),
+        (1265, "MQIACF_USAGE_EXPAND_TYPE"),
+        (226, "MQIA_CF_OFFLOAD_THRESHOLD2"),
+        (1149, "MQIACF_Q_MGR_STATUS"),
+        (1391, "MQIACF_SELECTOR_COUNT"),
+        (3503, "MQCACH_MODE_NAME"),
+        (52, "MQIA_START_STOP_EVENT"),
+        (3027, "MQCACF_AUX_ERROR_DATA_STR_2"),
+        (1634, "MQIACH_MSGS_RCVD"),
+        (2042, "MQCA_STORAGE_CLASS_DESC"),
+        (4000, "MQCA_USER_LIST"),
+        (3080, "MQCACF_COMMAND_SCOPE"),
+        (1094, "MQIACF_REFRESH_INTERVAL"),
+        (3219, "MQCACF_NHA_GROUP_INITIAL_TIME"),
+        (3555, "MQCACH_LISTENER_DESC"),
+        (3121, "MQCACF_SYSP_Q_MGR_RBA"),
+        (1120, "MQIACF_COMMAND_INFO"),
+        (3067, "MQCACF_AUTH_PROFILE_NAME"),
+        (1168, "MQIACF_USAGE_PAGESET"),
+        (1170, "MQIACF_USAGE_BUFFER_POOL"),
+        (3026, "MQCACF_AUX_ERROR_DATA_STR_1"),
+        (2069, "MQCA_SSL_KEY_LIBRARY"),
+        (3069, "MQCACF_SERVICE_COMPONENT"),
+        (139, "MQIA_SERVICE_CONTROL"),
+        (1263, "MQIACF_INTERFACE_VERSION"),
+        (1102, "MQIACF_OPEN_BROWSE"),
+        (1146, "MQIACF_MOVE_TYPE_MOVE"),
+        (3169, "MQCACF_SUBSCRIPTION_POINT"),
+        (1235, "MQIACF_RECORDED_ACTIVITIES"),
+        (3006, "MQCACF_TO_NAMELIST_NAME"),
+        (1419, "MQIACF_REUSABLE_LOG_SIZE"),
+        (1457, "MQIACF_NHA_INSTANCE_ACTV_CONNS"),
+        (186, "MQIA_MASTER_ADMIN"),
+        (3571, "MQCACH_TOPIC_ROOT"),
+        (64, "MQIA_INTRA_GROUP_QUEUING"),
+        (1510, "MQIACH_MAX_MSG_LENGTH"),
+        (1533, "MQIACH_SSL_RETURN_CODE"),
+        (3062, "MQCACF_UOW_LOG_START_DATE"),
+        (3081, "MQCACF_ASID"),
+        (1418, "MQIACF_RESTART_LOG_SIZE"),
+        (2127, "MQCA_LDAP_SHORT_USER_FIELD"),
+        (3003, "MQCACF_FROM_PROCESS_NAME"),
+        (4001, "MQHA_BAG_HANDLE"),
+        (1076, "MQIACF_OPTIONS"),
+        (183, "MQIA_TREE_LIFE_TIME"),
+        (3123, "MQCACF_SYSP_SERVICE"),
+        (3029, "MQCACF_BRIDGE_NAME"),
+        (1422, "MQIACF_LOG_REDUCTION"),
+        (1330, "MQIACF_USAGE_FREE_BUFF"),
+        (1543, "MQIACH_STOP_REQUESTED"),
+        (7, "MQIA_DEFINITION_TYPE"),
+        (266, "MQIA_AUTHENTICATION_METHOD"),
+        (2021, "MQCA_USER_DATA"),
+        (1224, "MQIACF_SERVICE_ATTRS"),
+        (1414, "MQIACF_SYSP_ZHYPERWRITE"),
+        (3032, "MQCACF_PARENT_Q_MGR_NAME"),
+        (1509, "MQIACH_SEQUENCE_NUMBER_WRAP"),
+        (109, "MQIA_MAX_CHANNELS"),
+        (1458, "MQIACF_NHA_INSTANCE_BACKLOG"),
+        (1339, "MQIACF_USAGE_INUSE_BUFFERS"),
+        (1578, "MQIACH_CLWL_CHANNEL_PRIORITY"),
+        (1619, "MQIACH_MAX_INSTS_PER_CLIENT"),
+        (1600, "MQIACH_SHARED_CHL_RESTART"),
+        (1290, "MQIACF_MESSAGE_COUNT"),
+        (1612, "MQIACH_COMPRESSION_TIME"),
+        (1259, "MQIACF_ROUTE_FORWARDING"),
+        (3047, "MQCACF_EVENT_Q_MGR"),
+        (1243, "MQIACF_ENCODING"),
+        (3079, "MQCACF_USAGE_LOG_LRSN"),
+        (1318, "MQIACF_PUBSUB_STATUS_ATTRS"),
+        (1610, "MQIACH_SSL_KEY_RESETS"),
+        (3549, "MQCACH_SSL_CERT_USER_ID"),
+        (1274, "MQIACF_DURABLE_SUBSCRIPTION"),
+        (30, "MQIA_SYNCPOINT"),
+        (3056, "MQCACF_SUBSCRIPTION_USER_DATA"),
+        (1130, "MQIACF_CF_STRUC_STATUS"),
+        (1589, "MQIACH_CURRENT_CHL"),
+        (1587, "MQIACH_SSLTASKS_STARTED"),
+        (223, "MQIA_COMM_INFO_TYPE"),
+        (1368, "MQIACF_RECS_PRESENT"),
+        (1160, "MQIACF_USAGE_UNUSED_PAGES"),
+        (1538, "MQIACH_BUFFERS_SENT"),
+        (1198, "MQIACF_SYSP_SMF_STATS"),
+        (2070, "MQCA_SSL_KEY_MEMBER"),
+        (3173, "MQCACF_ROUTING_FINGER_PRINT"),
+        (1602, "MQIACH_BACKLOG"),
+        (1214, "MQIACF_SYSP_UNIT_ADDRESS"),
+        (91, "MQIA_TRIGGER_RESTART"),
+        (1356, "MQIACF_OPERATION_ID"),
+        (1324, "MQIACF_MULC_CAPTURE"),
+        (1426, "MQIACF_APPL_MOVABLE"),
+        (1640, "MQIACH_DEF_RECONNECT"),
+        (1204, "MQIACF_SYSP_ARCHIVE_RETAIN"),
+        (1254, "MQIACF_REASON_CODE"),
+        (1338, "MQIACF_USAGE_EMPTY_BUFFERS"),
+        (1188, "MQIACF_SYSP_MAX_CONNS_BACK"),
+        (153, "MQIA_QMOPT_CONS_ERROR_MSGS"),
+        (1524, "MQIACH_CHANNEL_INSTANCE_ATTRS"),
+        (3119, "MQCACF_SYSP_Q_MGR_TIME"),
+        (3186, "MQCACF_CF_SMDSCONN"),
+        (3573, "MQCACH_TEMPORARY_Q_PREFIX"),
+        (1396, "MQIACF_NUM_PUBS"),
+        (1574, "MQIACH_PORT_NUMBER"),
+        (1267, "MQIACF_SYSP_DB2_BLOB_TASKS"),
+        (1305, "MQIACF_CLEAR_TYPE"),
+        (2137, "MQCA_AMQP_SSL_CIPHER_SUITES"),
+        (111, "MQIA_RECEIVE_TIMEOUT"),
+        (3108, "MQCACF_DSG_NAME"),
+        (3105, "MQCACF_TO_STORAGE_CLASS"),
+        (239, "MQIA_ACTIVITY_CONN_OVERRIDE"),
+        (3516, "MQCACH_RCV_EXIT_USER_DATA"),
+        (195, "MQIA_PM_DELIVERY"),
+        (238, "MQIA_POLICY_VERSION"),
+        (1220, "MQIACF_SYSP_TOTAL_LOGS"),
+        (2086, "MQCA_PASS_TICKET_APPL"),
+        (3054, "MQCACF_SUBSCRIPTION_IDENTITY"),
+        (2028, "MQCA_ALTERATION_TIME"),
+        (3009, "MQCACF_FROM_AUTH_INFO_NAME"),
+        (3055, "MQCACF_REG_SUB_IDENTITY"),
+        (119, "MQIA_CHINIT_CONTROL"),
+        (1539, "MQIACH_BUFFERS_RCVD"),
+        (53, "MQIA_PERFORMANCE_EVENT"),
+        (-9, "MQIASY_VERSION"),
+        (3120, "MQCACF_SYSP_Q_MGR_DATE"),
+        (2053, "MQCA_AUTH_INFO_CONN_NAME"),
+        (274, "MQIA_MAX_Q_FILE_SIZE"),
+        (2096, "MQCA_MODEL_DURABLE_Q"),
+        (-6, "MQIASY_COMP_CODE"),
+        (1121, "MQIACF_CMDSCOPE_Q_MGR_COUNT"),
+        (1601, "MQIACH_LISTENER_CONTROL"),
+        (1087, "MQIACF_SUSPEND"),
+        (3059, "MQCACF_DATA_SET_NAME"),
+        (62, "MQIA_PAGESET_ID"),
+        (3524, "MQCACH_LAST_MSG_TIME"),
+        (130, "MQIA_STATISTICS_AUTO_CLUSSDR"),
+        (1388, "MQIACF_XA_RETVAL"),
+        (3167, "MQCACF_LAST_MSG_TIME"),
+        (27, "MQIA_CPI_LEVEL"),
+        (1098, "MQIACF_OPEN_INPUT_TYPE"),
+        (2047, "MQCA_LDAP_USER_NAME"),
+        (1238, "MQIACF_ROUTE_ACCUMULATION"),
+        (72, "MQIA_NAMELIST_TYPE"),
+        (1020, "MQIACF_REASON_QUALIFIER"),
+        (1231, "MQIACF_Q_MGR_FACILITY"),
+        (1203, "MQIACF_SYSP_ALLOC_UNIT"),
+        (1227, "MQIACF_OLDEST_MSG_AGE"),
+        (1451, "MQIACF_LOG_EXTENT_SIZE"),
+        (1359, "MQIACF_TRACE_DETAIL"),
+        (3024, "MQCACF_APPL_NAME"),
+        (1624, "MQIACH_BATCH_DATA_LIMIT"),
+        (2122, "MQCA_XR_VERSION"),
+        (1, "MQIA_APPL_TYPE"),
+        (2094, "MQCA_TOPIC_STRING"),
+        (2049, "MQCA_SSL_KEY_REPOSITORY"),
+        (3185, "MQCACF_RECOVERY_TIME"),
+        (1382, "MQIACF_HSUB"),
+        (20, "MQIA_Q_TYPE"),
+        (3512, "MQCACH_CHANNEL_NAMES"),
+        (3137, "MQCACF_PUT_DATE"),
+        (1106, "MQIACF_SECURITY_TYPE"),
+        (22, "MQIA_BACKOUT_THRESHOLD"),
+        (164, "MQIA_QMOPT_LOG_SYSTEM_MSGS"),
+        (3010, "MQCACF_TO_AUTH_INFO_NAME"),
+        (1636, "MQIACH_AVAILABLE_CIPHERSPECS"),
+        (3036, "MQCACF_SUPPORTED_STREAM_NAME"),
+        (2027, "MQCA_ALTERATION_DATE"),
+        (2062, "MQCA_TRIGGER_PROGRAM_NAME"),
+        (1423, "MQIACF_IGNORE_STATE"),
+        (3218, "MQCACF_NHA_GROUP_INITIAL_LSN"),
+        (3163, "MQCACF_FROM_SUB_NAME"),
+        (190, "MQIA_PROPERTY_CONTROL"),
+        (155, "MQIA_QMOPT_CONS_COMMS_MSGS"),
+        (1527, "MQIACH_CHANNEL_STATUS"),
+        (28, "MQIA_TRIGGER_TYPE"),
+        (3128, "MQCACF_LAST_PUT_DATE"),
+        (256, "MQIA_CLUSTER_OBJECT_STATE"),
+        (2081, "MQCA_SERVICE_STOP_COMMAND"),
+        (65, "MQIA_IGQ_PUT_AUTHORITY"),
+        (1542, "MQIACH_MCA_STATUS"),
+        (1015, "MQIACF_CHANNEL_ATTRS"),
+        (1336, "MQIACF_USAGE_BLOCK_SIZE"),
+        (2043, "MQCA_XCF_GROUP_NAME"),
+        (1116, "MQIACF_AUTH_ADD_AUTHS"),
+        (222, "MQIA_UR_DISP"),
+        (1071, "MQIACF_AUX_ERROR_DATA_INT_2"),
+        (3058, "MQCACF_APPL_TAG"),
+        (224, "MQIA_CF_OFFLOAD"),
+        (1247, "MQIACF_MSG_FLAGS"),
+        (3033, "MQCACF_CORREL_ID"),
+        (2063, "MQCA_TRIGGER_TERM_ID"),
+        (10, "MQIA_INHIBIT_PUT"),
+        (1080, "MQIACF_INTEGER_DATA"),
+        (1442, "MQIACF_SYSP_SMF_STAT_TIME_SECS"),
+        (273, "MQIA_ADVANCED_CAPABILITY"),
+        (3194, "MQCACF_RESOLVED_LOCAL_Q_MGR"),
+        (1564, "MQIACH_BATCH_INTERVAL"),
+        (1136, "MQIACF_CF_STATUS_SUMMARY"),
+        (1011, "MQIACF_EVENT_ORIGIN"),
+        (204, "MQIA_SUB_COUNT"),
+        (3083, "MQCACF_PST_ID"),
+        (1242, "MQIACF_COMP_CODE"),
+        (3212, "MQCACF_Q_MGR_DATA_PATH"),
+        (1108, "MQIACF_CONNECT_OPTIONS"),
+        (181, "MQIA_INHIBIT_PUB"),
+        (187, "MQIA_PUBSUB_MODE"),
+        (1212, "MQIACF_SYSP_QUIESCE_INTERVAL"),
+        (1366, "MQIACF_CTL_OPERATION"),
+        (1113, "MQIACF_CONN_INFO_ALL"),
+        (2012, "MQCA_PROCESS_NAME"),
+        (3164, "MQCACF_TO_SUB_NAME"),
+        (1202, "MQIACF_SYSP_WLM_INTERVAL"),
+        (246, "MQIA_CF_CFCONLOS"),
+        (2024, "MQCA_XMIT_Q_NAME"),
+        (1413, "MQIACF_SYSP_MAX_CONC_OFFLOADS"),
+        (3075, "MQCACF_COMMAND_MQSC"),
+        (-5, "MQIASY_CONTROL"),
+        (2073, "MQCA_LU_NAME"),
+        (1322, "MQIACF_LOG_COMPRESSION"),
+        (3216, "MQCACF_LOG_START_TIME"),
+        (2129, "MQCA_LDAP_USER_ATTR_FIELD"),
+        (3174, "MQCACF_APPL_DESC"),
+        (4, "MQIA_DEF_INPUT_OPEN_OPTION"),
+        (1576, "MQIACH_MSG_COMPRESSION"),
+        (1331, "MQIACF_USAGE_FREE_BUFF_PERC"),
+        (21, "MQIA_RETENTION_INTERVAL"),
+        (3133, "MQCACF_OPERATION_TIME"),
+        (1156, "MQIACF_STORAGE_CLASS_ATTRS"),
+        (237, "MQIA_ENCRYPTION_ALGORITHM"),
+        (1383, "MQIACF_SUBRQ_OPTIONS"),
+        (3554, "MQCACH_LISTENER_NAME"),
+        (1462, "MQIACF_NHA_TOTAL_INSTANCES"),
+        (2128, "MQCA_LDAP_USER_OBJECT_CLASS"),
+        (3566, "MQCACH_CONNECTION_NAME_LIST"),
+        (242, "MQIA_SUB_CONFIGURATION_EVENT"),
+        (205, "MQIA_PUBSUB_NP_RESP"),
+        (1273, "MQIACF_DESTINATION_CLASS"),
+        (2017, "MQCA_REMOTE_Q_MGR_NAME"),
+        (1335, "MQIACF_USAGE_SMDS"),
+        (1172, "MQIACF_EXPIRY_Q_COUNT"),
+        (1376, "MQIACF_SMDS_EXPANDST"),
+        (3016, "MQCACF_MODEL_Q_NAMES"),
+        (1545, "MQIACH_MR_INTERVAL"),
+        (116, "MQIA_TCP_STACK_TYPE"),
+        (103, "MQIA_ADOPTNEWMCA_TYPE"),
+        (2, "MQIA_CODED_CHAR_SET_ID"),
+        (2022, "MQCA_STORAGE_CLASS"),
+        (1083, "MQIACF_CLUSTER_INFO"),
+        (3567, "MQCACH_CLIENT_USER_ID"),
+        (1575, "MQIACH_HDR_COMPRESSION"),
+        (3561, "MQCACH_REMOTE_PRODUCT"),
+        (1082, "MQIACF_PUBLICATION_OPTIONS"),
+        (3126, "MQCACF_FROM_SERVICE_NAME"),
+        (1261, "MQIACF_Q_TYPES"),
+        (1088, "MQIACF_BROKER_COUNT"),
+        (59, "MQIA_CLUSTER_Q_TYPE"),
+        (3012, "MQCACF_PROCESS_NAMES"),
+        (162, "MQIA_QMOPT_LOG_COMMS_MSGS"),
+        (3019, "MQCACF_SENDER_CHANNEL_NAMES"),
+        (3100, "MQCACF_SYSTEM_NAME"),
+        (1296, "MQIACF_TOPIC_SUB"),
+        (2048, "MQCA_LDAP_PASSWORD"),
+        (83, "MQIA_MAX_GLOBAL_LOCKS"),
+        (3160, "MQCACF_SUB_SELECTOR"),
+        (1639, "MQIACH_WARNING"),
+        (1349, "MQIACF_SMDS_STATUS"),
+        (1308, "MQIACF_ASYNC_STATE"),
+        (1463, "MQIACF_Q_MGR_FS_ENCRYPTED"),
+        (1505, "MQIACH_SHORT_RETRY"),
+        (3149, "MQCACF_TO_TOPIC_NAME"),
+        (3085, "MQCACF_TRANSACTION_ID"),
+        (1157, "MQIACF_USAGE_TYPE"),
+        (1077, "MQIACF_BROKER_OPTIONS"),
+        (3193, "MQCACF_OBJECT_STRING"),
+        (9, "MQIA_INHIBIT_GET"),
+        (1138, "MQIACF_CF_STATUS_BACKUP"),
+        (1026, "MQIACF_Q_STATUS_ATTRS"),
+        (2091, "MQCA_AUTO_REORG_CATALOG"),
+        (1437, "MQIACF_CUR_Q_FILE_SIZE"),
+        (1443, "MQIACF_SYSP_SMF_ACCT_TIME_MINS"),
+        (90, "MQIA_MAX_Q_TRIGGERS"),
+        (2068, "MQCA_BATCH_INTERFACE_ID"),
+        (1385, "MQIACF_XA_FLAGS"),
+        (1234, "MQIACF_ROUTE_DETAIL"),
+        (92, "MQIA_SSL_FIPS_REQUIRED"),
+        (3199, "MQCACF_XA_INFO"),
+        (128, "MQIA_STATISTICS_Q"),
+        (54, "MQIA_Q_SERVICE_INTERVAL"),
+        (2051, "MQCA_SSL_CRYPTO_HARDWARE"),
+        (3558, "MQCACH_SSL_KEY_RESET_DATE"),
+        (160, "MQIA_QMOPT_LOG_ERROR_MSGS"),
+        (1403, "MQIACF_EXPORT_ATTRS"),
+        (3150, "MQCACF_FROM_TOPIC_NAME"),
+        (1404, "MQIACF_SYSTEM_OBJECTS"),
+        (1233, "MQIACF_CMD_SERVER_STATUS"),
+        (1333, "MQIACF_CF_STATUS_SMDS"),
+        (1528, "MQIACH_INDOUBT_STATUS"),
+        (1397, "MQIACF_POINTER_SIZE"),
+        (3209, "MQCACF_APPL_IMMOVABLE_DATE"),
+        (1439, "MQIACF_BALANCING_TYPE"),
+        (170, "MQIA_QMOPT_INTERNAL_DUMP"),
+        (1183, "MQIACF_SYSP_DUAL_ACTIVE"),
+        (1540, "MQIACH_LONG_RETRIES_LEFT"),
+        (2077, "MQCA_SERVICE_NAME"),
+        (100, "MQIA_ACTIVE_CHANNELS"),
+        (1370, "MQIACF_UNKNOWN_DEST_COUNT"),
+        (16, "MQIA_MSG_DELIVERY_SEQUENCE"),
+        (1594, "MQIACH_ACTIVE_CHL_MAX"),
+        (3143, "MQCACF_VALUE_NAME"),
+        (3525, "MQCACH_LAST_MSG_DATE"),
+        (3545, "MQCACH_SSL_PEER_NAME"),
+        (1145, "MQIACF_MOVE_TYPE"),
+        (1075, "MQIACF_WAIT_INTERVAL"),
+        (1332, "MQIACF_CF_STRUC_ACCESS"),
+        (1128, "MQIACF_UOW_STATE"),
+        (3521, "MQCACH_LOCAL_NAME"),
+        (3008, "MQCACF_TO_CHANNEL_NAME"),
+        (1266, "MQIACF_SYSP_CLUSTER_CACHE"),
+        (3124, "MQCACF_FROM_LISTENER_NAME"),
+        (1424, "MQIACF_MOVABLE_APPL_COUNT"),
+        (199, "MQIA_PROXY_SUB"),
+        (1018, "MQIACF_ERROR_OFFSET"),
+        (1191, "MQIACF_SYSP_CHKPOINT_COUNT"),
+        (1563, "MQIACH_HB_INTERVAL"),
+        (2038, "MQCA_CLUSTER_TIME"),
+        (1161, "MQIACF_USAGE_PERSIST_PAGES"),
+        (2060, "MQCA_CICS_FILE_NAME"),
+        (206, "MQIA_PUBSUB_MAXMSG_RETRY_COUNT"),
+        (270, "MQIA_MEDIA_IMAGE_LOG_LENGTH"),
+        (1646, "MQIACH_SPL_PROTECTION"),
+        (69, "MQIA_SSL_TASKS"),
+        (1225, "MQIACF_SERVICE_STATUS_ATTRS"),
+        (3533, "MQCACH_FORMAT_NAME"),
+        (1596, "MQIACH_ACTIVE_CHL_STARTED"),
+        (1209, "MQIACF_SYSP_ALLOC_PRIMARY"),
+        (1456, "MQIACF_LOG_TYPE"),
+        (1009, "MQIACF_ALL"),
+        (138, "MQIA_ACTIVITY_RECORDING"),
+        (1140, "MQIACF_CF_STRUC_SIZE_MAX"),
+        (249, "MQIA_PUBSUB_CLUSTER"),
+        (3002, "MQCACF_TO_Q_NAME"),
+        (1256, "MQIACF_VERSION"),
+        (2046, "MQCA_AUTH_INFO_DESC"),
+        (26, "MQIA_TRIGGER_MSG_PRIORITY"),
+        (245, "MQIA_QMGR_CFCONLOS"),
+        (1187, "MQIACF_SYSP_MAX_CONNS_FORE"),
+        (2130, "MQCA_SSL_CERT_ISSUER_NAME"),
+        (1501, "MQIACH_XMIT_PROTOCOL_TYPE"),
+        (51, "MQIA_CONFIGURATION_EVENT"),
+        (1159, "MQIACF_USAGE_TOTAL_PAGES"),
+        (1208, "MQIACF_SYSP_COMPACT"),
+        (13, "MQIA_MAX_MSG_LENGTH"),
+        (1269, "MQIACF_TOPIC_ATTRS"),
+        (2109, "MQCA_AUTH_INFO_OCSP_URL"),
+        (1453, "MQIACF_LOG_FS_SIZE"),
+        (1352, "MQIACF_CHLAUTH_TYPE"),
+        (1517, "MQIACH_MCA_TYPE"),
+        (1580, "MQIACH_CHANNEL_DISP"),
+        (1297, "MQIACF_TOPIC_PUB"),
+        (3040, "MQCACF_CHILD_Q_MGR_NAME"),
+        (1292, "MQIACF_Q_MGR_VERSION"),
+        (1143, "MQIACF_CF_STRUC_ENTRIES_USED"),
+        (247, "MQIA_SUITE_B_STRENGTH"),
+        (55, "MQIA_CHANNEL_AUTO_DEF"),
+        (3514, "MQCACH_MSG_EXIT_USER_DATA"),
+        (2074, "MQCA_LU62_ARM_SUFFIX"),
+        (3518, "MQCACH_PASSWORD"),
+        (1526, "MQIACH_CHANNEL_TABLE"),
+        (2067, "MQCA_COMMAND_REPLY_Q_NAME"),
+        (1337, "MQIACF_USAGE_DATA_BLOCKS"),
+        (3115, "MQCACF_SYSP_ARCHIVE_PFX1"),
+        (1091, "MQIACF_REG_REG_OPTIONS"),
+        (1350, "MQIACF_SMDS_AVAIL"),
+        (3068, "MQCACF_ENTITY_NAME"),
+        (141, "MQIA_SECURITY_CASE"),
+        (3183, "MQCACF_CF_SMDS"),
+        (2099, "MQCA_RESUME_TIME"),
+        (1364, "MQIACF_MQCB_OPTIONS"),
+        (1090, "MQIACF_ANONYMOUS_COUNT"),
+        (3007, "MQCACF_FROM_CHANNEL_NAME"),
+        (2001, "MQCA_APPL_ID"),
+        (2034, "MQCA_CLUSTER_WORKLOAD_DATA"),
+        (1400, "MQIACF_APPL_FUNCTION_TYPE"),
+        (1570, "MQIACH_ALLOC_RETRY"),
+        (1006, "MQIACF_REPLACE"),
+        (1210, "MQIACF_SYSP_ALLOC_SECONDARY"),
+        (3200, "MQCACF_APPL_FUNCTION"),
+        (1181, "MQIACF_SYSP_OUT_BUFFER_COUNT"),
+        (1178, "MQIACF_SYSP_MAX_READ_TAPES"),
+        (3189, "MQCACF_CHAR_ATTRS"),
+        (2005, "MQCA_CREATION_TIME"),
+        (1228, "MQIACF_AUTH_OPTIONS"),
+        (1461, "MQIACF_NHA_IN_SYNC_INSTANCES"),
+        (3187, "MQCACF_CF_STRUC_NAME"),
+        (3145, "MQCACF_SERVICE_START_TIME"),
+        (1581, "MQIACH_INBOUND_DISP"),
+        (1389, "MQIACF_STATUS_TYPE"),
+        (2118, "MQCA_CHLAUTH_DESC"),
+        (1134, "MQIACF_EXCLUDE_INTERVAL"),
+        (2093, "MQCA_TOPIC_DESC"),
+        (1369, "MQIACF_KNOWN_DEST_COUNT"),
+        (1427, "MQIACF_REMOTE_QMGR_ACTIVE"),
+        (230, "MQIA_MAX_RESPONSES"),
+        (1186, "MQIACF_SYSP_MAX_CONNS"),
+        (3101, "MQCACF_CF_STRUC_BACKUP_START"),
+        (3148, "MQCACF_SYSP_ARCHIVE_UNIT2"),
+        (3505, "MQCACH_XMIT_Q_NAME"),
+        (114, "MQIA_TCP_CHANNELS"),
+        (2064, "MQCA_TRIGGER_CHANNEL_NAME"),
+        (1307, "MQIACF_SUB_LEVEL"),
+        (3030, "MQCACF_STREAM_NAME"),
+        (1362, "MQIACF_MQCB_OPERATION"),
+        (2010, "MQCA_NAMELIST_NAME"),
+        (1441, "MQIACF_BALANCING_TIMEOUT"),
+        (3506, "MQCACH_CONNECTION_NAME"),
+        (1095, "MQIACF_REFRESH_REPOSITORY"),
+        (1229, "MQIACF_Q_MGR_STATUS_ATTRS"),
+        (2008, "MQCA_INITIATION_Q_NAME"),
+        (1642, "MQIACH_CHANNEL_SUMMARY_ATTRS"),
+        (1144, "MQIACF_CF_STRUC_BACKUP_SIZE"),
+        (1405, "MQIACF_CONNECTION_SWAP"),
+        (1438, "MQIACF_CUR_MAX_FILE_SIZE"),
+        (1249, "MQIACF_MSG_TYPE"),
+        (156, "MQIA_QMOPT_CONS_REORG_MSGS"),
+        (47, "MQIA_AUTHORITY_EVENT"),
+        (1579, "MQIACH_CLWL_CHANNEL_WEIGHT"),
+        (43, "MQIA_Q_DEPTH_HIGH_EVENT"),
+        (84, "MQIA_MAX_LOCAL_LOCKS"),
+        (3535, "MQCACH_MR_EXIT_USER_DATA"),
+        (3152, "MQCACF_SUB_NAME"),
+        (1003, "MQIACF_PROCESS_ATTRS"),
+        (1459, "MQIACF_NHA_INSTANCE_IN_SYNC"),
+        (2101, "MQCA_CHILD"),
+        (261, "MQIA_LDAP_SECURE_COMM"),
+        (3117, "MQCACF_SYSP_LOG_CORREL_ID"),
+        (3039, "MQCACF_REG_USER_ID"),
+        (1386, "MQIACF_XA_RETCODE"),
+        (3570, "MQCACH_WEBCONTENT_PATH"),
+        (-3, "MQIASY_COMMAND"),
+        (208, "MQIA_TOPIC_TYPE"),
+        (1629, "MQIACH_USE_CLIENT_ID"),
+        (1595, "MQIACH_ACTIVE_CHL_PAUSED"),
+        (1177, "MQIACF_SYSP_MAX_ARCHIVE"),
+        (1431, "MQIACF_APPL_INFO_LOCAL"),
+        (1613, "MQIACH_MAX_XMIT_SIZE"),
+        (1117, "MQIACF_AUTH_REMOVE_AUTHS"),
+        (2052, "MQCA_CF_STRUC_DESC"),
+        (1627, "MQIACH_NEW_SUBSCRIBER_HISTORY"),
+        (1502, "MQIACH_BATCH_SIZE"),
+        (1618, "MQIACH_MAX_INSTANCES"),
+        (3073, "MQCACF_MEDIA_LOG_EXTENT_NAME"),
+        (1169, "MQIACF_USAGE_DATA_SET"),
+        (203, "MQIA_PUBSUB_NP_MSG"),
+        (1529, "MQIACH_LAST_SEQ_NUMBER"),
+        (1264, "MQIACF_AUTH_SERVICE_ATTRS"),
+        (2133, "MQCA_LDAP_GROUP_OBJECT_CLASS"),
+        (1118, "MQIACF_ENTITY_TYPE"),
+        (1154, "MQIACF_SECURITY_SWITCH"),
+        (3171, "MQCACF_NONE"),
+        (1626, "MQIACH_MULTICAST_PROPERTIES"),
+        (1084, "MQIACF_Q_MGR_DEFINITION_TYPE"),
+        (1139, "MQIACF_CF_STRUC_TYPE"),
+        (3138, "MQCACF_PUT_TIME"),
+        (3180, "MQCACF_CF_OFFLOAD_SIZE2"),
+        (3547, "MQCACH_SSL_SHORT_PEER_NAME"),
+        (1179, "MQIACF_SYSP_IN_BUFFER_SIZE"),
+        (3072, "MQCACF_RESTART_LOG_EXTENT_NAME"),
+        (1357, "MQIACF_API_CALLER_TYPE"),
+        (93, "MQIA_IP_ADDRESS_VERSION"),
+        (188, "MQIA_DEF_READ_AHEAD"),
+        (1341, "MQIACF_USAGE_OFFLOAD_MSGS"),
+        (1107, "MQIACF_CONNECTION_ATTRS"),
+        (1167, "MQIACF_USAGE_DATA_SET_TYPE"),
+        (2072, "MQCA_LU_GROUP_NAME"),
+        (3035, "MQCACF_STRING_DATA"),
+        (1444, "MQIACF_SYSP_SMF_ACCT_TIME_SECS"),
+        (3001, "MQCACF_FROM_Q_NAME"),
+        (1454, "MQIACF_LOG_PRIMARIES"),
+        (3508, "MQCACH_SEC_EXIT_NAME"),
+        (1201, "MQIACF_SYSP_TRACE_SIZE"),
+        (1644, "MQIACH_AMQP_KEEP_ALIVE"),
+        (3021, "MQCACF_REQUESTER_CHANNEL_NAMES"),
+        (3129, "MQCACF_LAST_PUT_TIME"),
+        (1401, "MQIACF_AMQP_ATTRS"),
+        (1334, "MQIACF_SMDS_ATTRS"),
+        (1566, "MQIACH_KEEP_ALIVE_INTERVAL"),
+        (1025, "MQIACF_THREAD_ID"),
+        (1541, "MQIACH_SHORT_RETRIES_LEFT"),
+        (6, "MQIA_DEF_PRIORITY"),
+        (1504, "MQIACH_SHORT_TIMER"),
+        (1609, "MQIACH_CHANNEL_SUBSTATE"),
+        (1367, "MQIACF_GET_OPTIONS"),
+        (33, "MQIA_MAX_UNCOMMITTED_MSGS"),
+        (1632, "MQIACH_IN_DOUBT_OUT"),
+        (2114, "MQCA_RECIPIENT_DN"),
+        (1592, "MQIACH_CURRENT_CHL_LU62"),
+        (3507, "MQCACH_MCA_NAME"),
+        (23, "MQIA_SHAREABILITY"),
+        (2061, "MQCA_TRIGGER_TRANS_ID"),
+        (3214, "MQCACF_LOG_START_DATE"),
+        (1565, "MQIACH_NETWORK_PRIORITY"),
+        (1380, "MQIACF_CONNECT_TIME"),
+        (2138, "MQCA_STREAM_QUEUE_NAME"),
+        (1325, "MQIACF_PERMIT_STANDBY"),
+        (3184, "MQCACF_RECOVERY_DATE"),
+        (3205, "MQCACF_EXCL_OPERATOR_MESSAGES"),
+        (3064, "MQCACF_UOW_LOG_EXTENT_NAME"),
+        (193, "MQIA_BASE_TYPE"),
+        (3098, "MQCACF_BACKUP_DATE"),
+        (1304, "MQIACF_PUBLISH_COUNT"),
+        (1027, "MQIACF_UNCOMMITTED_MSGS"),
+        (39, "MQIA_EXPIRY_INTERVAL"),
+        (1133, "MQIACF_CF_STRUC_ATTRS"),
+        (1287, "MQIACF_SUB_ATTRS"),
+        (3015, "MQCACF_LOCAL_Q_NAMES"),
+        (1086, "MQIACF_ACTION"),
+        (1110, "MQIACF_CONN_INFO_TYPE"),
+        (2036, "MQCA_REPOSITORY_NAMELIST"),
+        (120, "MQIA_CMD_SERVER_CONTROL"),
+        (1028, "MQIACF_HANDLE_STATE"),
+        (2113, "MQCA_SIGNER_DN"),
+        (3043, "MQCACF_REG_Q_NAME"),
+        (1588, "MQIACH_SSLTASKS_MAX"),
+        (2066, "MQCA_MONITOR_Q_NAME"),
+        (1070, "MQIACF_AUX_ERROR_DATA_INT_1"),
+        (1007, "MQIACF_PURGE"),
+        (1002, "MQIACF_Q_ATTRS"),
+        (3017, "MQCACF_ALIAS_Q_NAMES"),
+        (1262, "MQIACF_USER_ID_SUPPORT"),
+        (1310, "MQIACF_OBSOLETE_MSGS"),
+        (1232, "MQIACF_CHINIT_STATUS"),
+        (1508, "MQIACH_PUT_AUTHORITY"),
+        (3151, "MQCACF_TOPIC_NAMES"),
+        (2004, "MQCA_CREATION_DATE"),
+        (1536, "MQIACH_BYTES_RCVD"),
+        (1507, "MQIACH_LONG_RETRY"),
+        (2132, "MQCA_LDAP_BASE_DN_GROUPS"),
+        (1415, "MQIACF_Q_MGR_STATUS_LOG"),
+        (3106, "MQCACF_STORAGE_CLASS_NAMES"),
+        (1346, "MQIACF_USAGE_USED_RATE"),
+        (1421, "MQIACF_LOG_UTILIZATION"),
+        (1023, "MQIACF_OPEN_TYPE"),
+        (1072, "MQIACF_CONV_REASON_CODE"),
+        (1099, "MQIACF_OPEN_OUTPUT"),
+        (3082, "MQCACF_PSB_NAME"),
+        (1017, "MQIACF_ESCAPE_TYPE"),
+        (29, "MQIA_TRIGGER_DEPTH"),
+        (1605, "MQIACH_NETWORK_TIME_INDICATOR"),
+        (1255, "MQIACF_REPORT"),
+        (123, "MQIA_MONITORING_Q"),
+        (2009, "MQCA_NAMELIST_DESC"),
+        (-8, "MQIASY_BAG_OPTIONS"),
+        (1164, "MQIACF_USAGE_EXPAND_COUNT"),
+        (1184, "MQIACF_SYSP_DUAL_ARCHIVE"),
+        (1180, "MQIACF_SYSP_OUT_BUFFER_SIZE"),
+        (1449, "MQIACF_DATA_FS_IN_USE"),
+        (1635, "MQIACH_PENDING_OUT"),
+        (3172, "MQCACF_ADMIN_TOPIC_NAMES"),
+        (1402, "MQIACF_EXPORT_TYPE"),
+        (1363, "MQIACF_MQCB_TYPE"),
+        (3513, "MQCACH_SEC_EXIT_USER_DATA"),
+        (216, "MQIA_WILDCARD_OPERATION"),
+        (252, "MQIA_CERT_VAL_POLICY"),
+        (1141, "MQIACF_CF_STRUC_SIZE_USED"),
+        (57, "MQIA_INDEX_TYPE"),
+        (3560, "MQCACH_REMOTE_VERSION"),
+        (1150, "MQIACF_DB2_CONN_STATUS"),
+        (1326, "MQIACF_OPERATION_MODE"),
+        (1250, "MQIACF_OFFSET"),
+        (1275, "MQIACF_SUBSCRIPTION_SCOPE"),
+        (86, "MQIA_BATCH_INTERFACE_AUTO"),
+        (35, "MQIA_TIME_SINCE_RESET"),
+        (89, "MQIA_CMD_SERVER_DLQ_MSG"),
+        (154, "MQIA_QMOPT_CONS_CRITICAL_MSGS"),
+        (2054, "MQCA_INITIAL_KEY"),
+        (3018, "MQCACF_REMOTE_Q_NAMES"),
+        (1126, "MQIACF_QSG_DISPS"),
+        (1623, "MQIACH_RESET_REQUESTED"),
+        (1446, "MQIACF_Q_MGR_STATUS_INFO_Q_MGR"),
+        (218, "MQIA_SUB_SCOPE"),
+        (1211, "MQIACF_SYSP_PROTECT"),
+        (1390, "MQIACF_XA_COUNT"),
+        (1295, "MQIACF_TOPIC_STATUS"),
+        (1348, "MQIACF_SMDS_OPENMODE"),
+        (2134, "MQCA_LDAP_GROUP_ATTR_FIELD"),
+        (207, "MQIA_PUBSUB_SYNC_PT"),
+        (3125, "MQCACF_TO_LISTENER_NAME"),
+        (71, "MQIA_CF_RECOVER"),
+        (1205, "MQIACF_SYSP_ARCHIVE_WTOR"),
+        (2014, "MQCA_Q_MGR_DESC"),
+        (1372, "MQIACF_RESOLVED_TYPE"),
+        (3191, "MQCACF_HOST_NAME"),
+        (1611, "MQIACH_COMPRESSION_RATE"),
+        (1607, "MQIACH_BATCH_SIZE_INDICATOR"),
+        (1236, "MQIACF_MAX_ACTIVITIES"),
+        (2105, "MQCA_ADMIN_TOPIC_NAME"),
+        (1375, "MQIACF_TRACE_DATA_LENGTH"),
+        (265, "MQIA_AMQP_CAPABILITY"),
+        (1122, "MQIACF_Q_MGR_SYSTEM"),
+        (3088, "MQCACF_ORIGIN_NAME"),
+        (1165, "MQIACF_PAGESET_STATUS"),
+        (24, "MQIA_TRIGGER_CONTROL"),
+        (3509, "MQCACH_MSG_EXIT_NAME"),
+        (3111, "MQCACF_SYSP_OTMA_GROUP"),
+        (3156, "MQCACF_SUB_USER_ID"),
+        (110, "MQIA_OUTBOUND_PORT_MIN"),
+        (168, "MQIA_QMOPT_TRACE_CONVERSION"),
+        (1303, "MQIACF_SUB_OPTIONS"),
+        (225, "MQIA_CF_OFFLOAD_THRESHOLD1"),
+        (1217, "MQIACF_SYSP_LOG_USED"),
+        (2123, "MQCA_XR_SSL_CIPHER_SUITES"),
+        (3089, "MQCACF_ENV_INFO"),
+        (260, "MQIA_ADOPT_CONTEXT"),
+        (3023, "MQCACF_OBJECT_Q_MGR_NAME"),
+        (2040, "MQCA_QSG_NAME"),
+        (2003, "MQCA_COMMAND_INPUT_Q_NAME"),
+        (167, "MQIA_QMOPT_TRACE_REORG"),
+        (44, "MQIA_Q_DEPTH_LOW_EVENT"),
+        (15, "MQIA_MAX_Q_DEPTH"),
+        (184, "MQIA_DEF_PUT_RESPONSE_TYPE"),
+        (169, "MQIA_QMOPT_TRACE_SYSTEM"),
+        (1008, "MQIACF_QUIESCE"),
+        (257, "MQIA_CHECK_LOCAL_BINDING"),
+        (151, "MQIA_QMOPT_CONS_INFO_MSGS"),
+        (1562, "MQIACH_NPM_SPEED"),
+        (1506, "MQIACH_LONG_TIMER"),
+        (3546, "MQCACH_SSL_HANDSHAKE_STAGE"),
+        (172, "MQIA_MAX_CLIENTS"),
+        (2110, "MQCA_COMM_INFO_NAME"),
+        (2055, "MQCA_SSL_KEY_REPO_PASSWORD"),
+        (1420, "MQIACF_LOG_IN_USE"),
+        (3544, "MQCACH_SSL_CIPHER_SPEC"),
+        (1371, "MQIACF_INVALID_DEST_COUNT"),
+        (3094, "MQCACF_TO_CF_STRUC_NAME"),
+        (2044, "MQCA_XCF_MEMBER_NAME"),
+        (3110, "MQCACF_SYSP_CMD_USER_ID"),
+        (1268, "MQIACF_SYSP_WLM_INT_UNITS"),
+        (1289, "MQIACF_SUB_TYPE"),
+        (1280, "MQIACF_REQUEST_ONLY"),
+        (3201, "MQCACF_XQH_REMOTE_Q_NAME"),
+        (1112, "MQIACF_CONN_INFO_HANDLE"),
+        (3178, "MQCACF_TO_COMM_INFO_NAME"),
+        (3528, "MQCACH_CHANNEL_START_TIME"),
+        (3132, "MQCACF_OPERATION_DATE"),
+        (2007, "MQCA_ENV_DATA"),
+        (3188, "MQCACF_ALTERNATE_USERID"),
+        (94, "MQIA_LOGGER_EVENT"),
+        (1447, "MQIACF_Q_MGR_STATUS_INFO_NHA"),
+        (175, "MQIA_DURABLE_SUB"),
+        (263, "MQIA_LDAP_AUTHORMD"),
+        (3, "MQIA_CURRENT_Q_DEPTH"),
+        (2115, "MQCA_INSTALLATION_DESC"),
+        (1123, "MQIACF_Q_MGR_EVENT"),
+        (3562, "MQCACH_GROUP_ADDRESS"),
+        (3553, "MQCACH_TCP_NAME"),
+        (3192, "MQCACF_MQCB_NAME"),
+        (3563, "MQCACH_JAAS_CONFIG"),
+        (3531, "MQCACH_LAST_LUWID"),
+        (135, "MQIA_ACCOUNTING_INTERVAL"),
+        (-2, "MQIASY_TYPE"),
+        (1345, "MQIACF_USAGE_USED_BLOCKS"),
+        (3140, "MQCACF_REPLY_TO_Q_MGR"),
+        (2033, "MQCA_CLUSTER_WORKLOAD_EXIT"),
+        (1096, "MQIACF_REMOVE_QUEUES"),
+        (1314, "MQIACF_PS_STATUS_TYPE"),
+        (105, "MQIA_CHINIT_DISPATCHERS"),
+        (1373, "MQIACF_PUT_OPTIONS"),
+        (2025, "MQCA_DEF_XMIT_Q_NAME"),
+        (1073, "MQIACF_BRIDGE_TYPE"),
+        (1239, "MQIACF_ROUTE_DELIVERY"),
+        (3517, "MQCACH_USER_ID"),
+        (228, "MQIA_CF_SMDS_BUFFERS"),
+        (1257, "MQIACF_UNRECORDED_ACTIVITIES"),
+        (251, "MQIA_PROT_POLICY_CAPABILITY"),
+        (3215, "MQCACF_LOG_START_LSN"),
+        (262, "MQIA_DISPLAY_TYPE"),
+        (1519, "MQIACH_ADAPTER"),
+        (1450, "MQIACF_DATA_FS_SIZE"),
+        (1430, "MQIACF_APPL_INFO_QMGR"),
+        (134, "MQIA_ACCOUNTING_Q"),
+        (66, "MQIA_AUTH_INFO_TYPE"),
+        (1085, "MQIACF_Q_MGR_TYPE"),
+        (2084, "MQCA_STDERR_DESTINATION"),
+        (1537, "MQIACH_BATCHES"),
+        (163, "MQIA_QMOPT_LOG_REORG_MSGS"),
+        (3049, "MQCACF_EVENT_APPL_IDENTITY"),
+        (1344, "MQIACF_USAGE_TOTAL_BLOCKS"),
+        (3022, "MQCACF_RECEIVER_CHANNEL_NAMES"),
+        (1151, "MQIACF_SECURITY_ATTRS"),
+        (1358, "MQIACF_API_ENVIRONMENT"),
+        (1432, "MQIACF_APPL_IMMOVABLE_COUNT"),
+        (1643, "MQIACH_PROTOCOL"),
+        (3557, "MQCACH_LISTENER_START_TIME"),
+        (1230, "MQIACF_CONNECTION_COUNT"),
+        (3051, "MQCACF_EVENT_APPL_ORIGIN"),
+        (2120, "MQCA_VERSION"),
+        (1163, "MQIACF_USAGE_RESTART_EXTENTS"),
+        (1171, "MQIACF_MOVE_COUNT"),
+        (1328, "MQIACF_CF_SMDS_BLOCK_SIZE"),
+        (3028, "MQCACF_AUX_ERROR_DATA_STR_3"),
+        (1277, "MQIACF_VARIABLE_USER_ID"),
+        (3182, "MQCACF_CF_SMDS_GENERIC_NAME"),
+        (3565, "MQCACH_SSL_KEY_PASSPHRASE"),
+        (2125, "MQCA_CONN_AUTH"),
+        (1514, "MQIACH_MSG_SEQUENCE_NUMBER"),
+        (159, "MQIA_QMOPT_LOG_WARNING_MSGS"),
+        (1355, "MQIACF_CHLAUTH_ATTRS"),
+        (3196, "MQCACF_RESOLVED_OBJECT_STRING"),
+        (42, "MQIA_Q_DEPTH_MAX_EVENT"),
+        (1577, "MQIACH_CLWL_CHANNEL_RANK"),
+        (1395, "MQIACF_SUBRQ_ACTION"),
+        (1166, "MQIACF_USAGE_TOTAL_BUFFERS"),
+        (1124, "MQIACF_Q_MGR_DQM"),
+        (3060, "MQCACF_UOW_START_DATE"),
+        (275, "MQIA_STREAM_QUEUE_QOS"),
+        (2050, "MQCA_SSL_CRL_NAMELIST"),
+        (1176, "MQIACF_SYSP_DEALLOC_INTERVAL"),
+        (3204, "MQCACF_XQH_PUT_DATE"),
+        (1078, "MQIACF_REFRESH_TYPE"),
+        (3515, "MQCACH_SEND_EXIT_USER_DATA"),
+        (124, "MQIA_MONITORING_AUTO_CLUSSDR"),
+        (1175, "MQIACF_SYSP_TYPE"),
+        (3113, "MQCACF_SYSP_OTMA_DRU_EXIT"),
+        (2131, "MQCA_QSG_CERT_LABEL"),
+        (1608, "MQIACH_XMITQ_MSGS_AVAILABLE"),
+        (1343, "MQIACF_USAGE_SAVED_BUFFERS"),
+        (2000, "MQIA_USER_LIST"),
+        (136, "MQIA_ACCOUNTING_CONN_OVERRIDE"),
+        (60, "MQIA_ARCHIVE"),
+        (173, "MQIA_AUTO_REORGANIZATION"),
+        (2013, "MQCA_Q_DESC"),
+        (1620, "MQIACH_CLIENT_CHANNEL_WEIGHT"),
+        (129, "MQIA_STATISTICS_CHANNEL"),
+        (185, "MQIA_TOPIC_DEF_PERSISTENCE"),
+        (3070, "MQCACF_RESPONSE_Q_MGR_NAME"),
+        (2112, "MQCA_POLICY_NAME"),
+        (3134, "MQCACF_ACTIVITY_DESC"),
+        (176, "MQIA_MULTICAST"),
+        (3208, "MQCACF_ARCHIVE_LOG_EXTENT_NAME"),
+        (236, "MQIA_SIGNATURE_ALGORITHM"),
+        (3175, "MQCACF_Q_MGR_START_DATE"),
+        (3112, "MQCACF_SYSP_OTMA_MEMBER"),
+        (2082, "MQCA_SERVICE_STOP_ARGS"),
+        (3181, "MQCACF_CF_OFFLOAD_SIZE3"),
+        (11, "MQIA_MAX_HANDLES"),
+        (1398, "MQIACF_REMOVE_AUTHREC"),
+        (74, "MQIA_BRIDGE_EVENT"),
+        (271, "MQIA_MEDIA_IMAGE_RECOVER_OBJ"),
+        (1531, "MQIACH_CURRENT_MSGS"),
+        (1584, "MQIACH_ADAPS_MAX"),
+        (3556, "MQCACH_LISTENER_START_DATE"),
+        (1435, "MQIACF_APPL_IMMOVABLE_REASON"),
+        (1283, "MQIACF_PUB_PRIORITY"),
+        (36, "MQIA_HIGH_Q_DEPTH"),
+        (3551, "MQCACH_LU_NAME"),
+        (1433, "MQIACF_BALANCED"),
+        (80, "MQIA_MAX_OPEN_Q"),
+        (3534, "MQCACH_MR_EXIT_NAME"),
+        (3066, "MQCACF_GROUP_ENTITY_NAMES"),
+        (1340, "MQIACF_USAGE_LOWEST_FREE"),
+        (63, "MQIA_QSG_DISP"),
+        (45, "MQIA_SCOPE"),
+        (1294, "MQIACF_SUB_STATUS_ATTRS"),
+        (5, "MQIA_DEF_PERSISTENCE"),
+        (2090, "MQCA_AUTO_REORG_START_TIME"),
+        (268, "MQIA_MEDIA_IMAGE_SCHEDULING"),
+        (2119, "MQCA_CUSTOM"),
+        (17, "MQIA_OPEN_INPUT_COUNT"),
+        (3084, "MQCACF_TASK_NUMBER"),
+        (3559, "MQCACH_SSL_KEY_RESET_TIME"),
+        (1327, "MQIACF_COMM_INFO_ATTRS"),
+        (1591, "MQIACH_CURRENT_CHL_TCP"),
+        (248, "MQIA_CHLAUTH_RECORDS"),
+        (1021, "MQIACF_COMMAND"),
+        (1248, "MQIACF_MSG_LENGTH"),
+        (1425, "MQIACF_APPL_INFO_ATTRS"),
+        (1515, "MQIACH_DATA_CONVERSION"),
+        (3220, "MQCACF_NHA_REPL_ADDRESS"),
+        (1240, "MQIACF_OPERATION_TYPE"),
+        (1311, "MQIACF_PUBSUB_STATUS"),
+        (1516, "MQIACH_IN_DOUBT"),
+        (1174, "MQIACF_CONFIGURATION_EVENTS"),
+        (1147, "MQIACF_MOVE_TYPE_ADD"),
+        (3168, "MQCACF_LAST_MSG_DATE"),
+        (140, "MQIA_OUTBOUND_PORT_MAX"),
+        (1194, "MQIACF_SYSP_DB2_TASKS"),
+        (3159, "MQCACF_SUB_USER_DATA"),
+        (1016, "MQIACF_OBJECT_TYPE"),
+        (1464, "MQIACF_Q_MGR_FS_IN_USE"),
+        (3011, "MQCACF_Q_NAMES"),
+        (2018, "MQCA_REMOTE_Q_NAME"),
+        (3207, "MQCACF_AMQP_CLIENT_ID"),
+        (50, "MQIA_REMOTE_EVENT"),
+        (3569, "MQCACH_SSL_CIPHER_SUITE"),
+        (1512, "MQIACH_DATA_COUNT"),
+        (3179, "MQCACF_CF_OFFLOAD_SIZE1"),
+        (3550, "MQCACH_SSL_CERT_ISSUER_NAME"),
+        (1412, "MQIACF_AUTH_REC_TYPE"),
+        (215, "MQIA_PUB_COUNT"),
+        (1301, "MQIACF_TOPIC_STATUS_ATTRS"),
+        (95, "MQIA_CLWL_Q_RANK"),
+        (1300, "MQIACF_RETAINED_PUBLICATION"),
+        (3130, "MQCACF_LAST_GET_DATE"),
+        (3211, "MQCACF_NHA_INSTANCE_NAME"),
+        (1323, "MQIACF_GROUPUR_CHECK_ID"),
+        (1638, "MQIACH_USER_SOURCE"),
+        (157, "MQIA_QMOPT_CONS_SYSTEM_MSGS"),
+        (3052, "MQCACF_SUBSCRIPTION_NAME"),
+        (3045, "MQCACF_EVENT_USER_ID"),
+        (1079, "MQIACF_SEQUENCE_NUMBER"),
+        (171, "MQIA_MAX_RECOVERY_TASKS"),
+        (3195, "MQCACF_RESOLVED_LOCAL_Q_NAME"),
+        (1544, "MQIACH_MR_COUNT"),
+        (2006, "MQCA_DEAD_LETTER_Q_NAME"),
+        (3042, "MQCACF_REG_Q_MGR_NAME"),
+        (14, "MQIA_MAX_PRIORITY"),
+        (2092, "MQCA_TOPIC_NAME"),
+        (1379, "MQIACF_EXPIRY_TIME"),
+        (255, "MQIA_CLUSTER_PUB_ROUTE"),
+        (2121, "MQCA_CERT_LABEL"),
+        (1089, "MQIACF_APPL_COUNT"),
+        (1429, "MQIACF_APPL_INFO_APPL"),
+        (2075, "MQCA_TCP_NAME"),
+        (1200, "MQIACF_SYSP_TRACE_CLASS"),
+        (61, "MQIA_DEF_BIND"),
+        (1585, "MQIACH_DISPS_STARTED"),
+        (3206, "MQCACF_CSP_USER_IDENTIFIER"),
+        (1593, "MQIACH_ACTIVE_CHL"),
+        (1329, "MQIACF_CF_SMDS_EXPAND"),
+        (3170, "MQCACF_FILTER"),
+        (1125, "MQIACF_Q_MGR_CLUSTER"),
+        (40, "MQIA_Q_DEPTH_HIGH_LIMIT"),
+        (76, "MQIA_SSL_RESET_COUNT"),
+        (2039, "MQCA_CF_STRUC_NAME"),
+        (1399, "MQIACF_XR_ATTRS"),
+        (1630, "MQIACH_MQTT_KEEP_ALIVE"),
+        (3572, "MQCACH_TEMPORARY_MODEL_Q"),
+        (2071, "MQCA_DNS_GROUP"),
+        (3131, "MQCACF_LAST_GET_TIME"),
+        (1260, "MQIACF_SERVICE_STATUS"),
+        (1520, "MQIACH_COMMAND_COUNT"),
+        (2108, "MQCA_TOPIC_STRING_FILTER"),
+        (1568, "MQIACH_SSL_CLIENT_AUTH"),
+        (150, "MQIA_QMOPT_CSMT_ON_ERROR"),
+        (3034, "MQCACF_PUBLISH_TIMESTAMP"),
+        (2030, "MQCA_CLUSTER_NAMELIST"),
+        (112, "MQIA_RECEIVE_TIMEOUT_TYPE"),
+        (46, "MQIA_Q_SERVICE_INTERVAL_EVENT"),
+        (276, "MQIA_CAP_EXPIRY"),
+        (98, "MQIA_CLWL_USEQ"),
+        (1104, "MQIACF_Q_HANDLE"),
+        (1252, "MQIACF_PERSISTENCE"),
+        (3013, "MQCACF_NAMELIST_NAMES"),
+        (19, "MQIA_NAME_COUNT"),
+        (2045, "MQCA_AUTH_INFO_NAME"),
+        (1237, "MQIACF_DISCONTINUITY_COUNT"),
+        (1360, "MQIACF_HOBJ"),
+        (1148, "MQIACF_Q_MGR_NUMBER"),
+        (1393, "MQIACF_INTATTR_COUNT"),
+        (1115, "MQIACF_AUTHORIZATION_LIST"),
+        (182, "MQIA_INHIBIT_SUB"),
+        (3097, "MQCACF_FAIL_TIME"),
+        (3504, "MQCACH_TP_NAME"),
+        (1197, "MQIACF_SYSP_SMF_ACCOUNTING"),
+        (3153, "MQCACF_DESTINATION_Q_MGR"),
+        (137, "MQIA_TRACE_ROUTE_RECORDING"),
+        (1582, "MQIACH_CHANNEL_TYPES"),
+        (88, "MQIA_CMD_SERVER_CONVERT_MSG"),
+        (1465, "MQIACF_Q_MGR_FS_SIZE"),
+        (1633, "MQIACH_MSGS_SENT"),
+        (267, "MQIA_KEY_REUSE_COUNT"),
+        (41, "MQIA_Q_DEPTH_LOW_LIMIT"),
+        (3501, "MQCACH_CHANNEL_NAME"),
+        (3020, "MQCACF_SERVER_CHANNEL_NAMES"),
+        (1302, "MQIACF_TOPIC_STATUS_TYPE"),
+        (1374, "MQIACF_BUFFER_LENGTH"),
+        (3141, "MQCACF_RESOLVED_Q_NAME"),
+        (3091, "MQCACF_CONFIGURATION_DATE"),
+        (3530, "MQCACH_MCA_JOB_NAME"),
+        (1092, "MQIACF_DELETE_OPTIONS"),
+        (1019, "MQIACF_AUTH_INFO_ATTRS"),
+        (1347, "MQIACF_USAGE_WAIT_RATE"),
+        (133, "MQIA_ACCOUNTING_MQI"),
+        (1010, "MQIACF_EVENT_APPL_TYPE"),
+        (3203, "MQCACF_XQH_PUT_TIME"),
+        (3096, "MQCACF_FAIL_DATE"),
+        (3038, "MQCACF_REG_TIME"),
+        (1111, "MQIACF_CONN_INFO_CONN"),
+        (1321, "MQIACF_SELECTOR_TYPE"),
+        (3217, "MQCACF_NHA_GROUP_INITIAL_DATE"),
+        (192, "MQIA_MAX_PROPERTIES_LENGTH"),
+        (1342, "MQIACF_USAGE_READS_SAVED"),
+        (3099, "MQCACF_BACKUP_TIME"),
+        (3114, "MQCACF_SYSP_OTMA_TPIPE_PFX"),
+        (3144, "MQCACF_SERVICE_START_DATE"),
+        (2037, "MQCA_CLUSTER_DATE"),
+        (2102, "MQCA_PARENT"),
+        (1245, "MQIACF_FEEDBACK"),
+        (81, "MQIA_MONITOR_INTERVAL"),
+        (3071, "MQCACF_CURRENT_LOG_EXTENT_NAME"),
+        (1615, "MQIACH_SHARING_CONVERSATIONS"),
+        (1309, "MQIACF_SUB_SUMMARY"),
+        (3532, "MQCACH_CURRENT_LUWID"),
+        (234, "MQIA_USE_DEAD_LETTER_Q"),
+        (166, "MQIA_QMOPT_TRACE_COMMS"),
+        (2116, "MQCA_INSTALLATION_NAME"),
+        (97, "MQIA_CLWL_MRU_CHANNELS"),
+        (3552, "MQCACH_IP_ADDRESS"),
+        (1436, "MQIACF_DS_ENCRYPTED"),
+        (3529, "MQCACH_CHANNEL_START_DATE"),
+        (1219, "MQIACF_SYSP_OFFLOAD_STATUS"),
+        (1218, "MQIACF_SYSP_LOG_SUSPEND"),
+        (1216, "MQIACF_SYSP_LOG_COPY"),
+        (1114, "MQIACF_AUTH_PROFILE_ATTRS"),
+        (1518, "MQIACH_SESSION_COUNT"),
+        (87, "MQIA_CMD_SERVER_AUTO"),
+        (3564, "MQCACH_CLIENT_ID"),
+        (58, "MQIA_CLUSTER_WORKLOAD_LENGTH"),
+        (1351, "MQIACF_MCAST_REL_INDICATOR"),
+        (1513, "MQIACH_NAME_COUNT"),
+        (2015, "MQCA_Q_MGR_NAME"),
+        (189, "MQIA_READ_AHEAD"),
+        (1622, "MQIACH_AUTH_INFO_TYPES"),
+        (1207, "MQIACF_SYSP_CATALOG"),
+        (3046, "MQCACF_OBJECT_NAME"),
+        (3037, "MQCACF_REG_TOPIC"),
+        (233, "MQIA_MCAST_BRIDGE"),
+        (3122, "MQCACF_SYSP_LOG_RBA"),
+        (3177, "MQCACF_FROM_COMM_INFO_NAME"),
+        (1162, "MQIACF_USAGE_NONPERSIST_PAGES"),
+        (1199, "MQIACF_SYSP_SMF_INTERVAL"),
+        (3213, "MQCACF_UNIFORM_CLUSTER_NAME"),
+        (1155, "MQIACF_SECURITY_SETTING"),
+        (2078, "MQCA_SERVICE_DESC"),
+        (1573, "MQIACH_DISC_RETRY"),
+        (1522, "MQIACH_PORT"),
+        (1606, "MQIACH_EXIT_TIME_INDICATOR"),
+        (-7, "MQIASY_REASON"),
+        (1185, "MQIACF_SYSP_DUAL_BSDS"),
+        (1215, "MQIACF_SYSP_UNIT_STATUS"),
+        (240, "MQIA_ACTIVITY_TRACE"),
+        (127, "MQIA_STATISTICS_MQI"),
+        (102, "MQIA_ADOPTNEWMCA_CHECK"),
+        (2124, "MQCA_CLUS_CHL_NAME"),
+        (1616, "MQIACH_MAX_SHARING_CONVS"),
+        (253, "MQIA_TOPIC_NODE_COUNT"),
+        (3041, "MQCACF_REG_STREAM_NAME"),
+        (1434, "MQIACF_BALSTATE"),
+        (99, "MQIA_COMMAND_EVENT"),
+        (235, "MQIA_TOLERATE_UNPROTECTED"),
+        (3162, "MQCACF_LAST_PUB_TIME"),
+        (3005, "MQCACF_FROM_NAMELIST_NAME"),
+        (1222, "MQIACF_LISTENER_ATTRS"),
+        (1103, "MQIACF_Q_STATUS_TYPE"),
+        (1158, "MQIACF_BUFFER_POOL_ID"),
+        (1137, "MQIACF_CF_STATUS_CONNECT"),
+        (1409, "MQIACF_LDAP_CONNECTION_STATUS"),
+        (-4, "MQIASY_MSG_SEQ_NUMBER"),
+        (1445, "MQIACF_Q_MGR_STATUS_INFO_TYPE"),
+        (1258, "MQIACF_MONITORING"),
+        (1189, "MQIACF_SYSP_EXIT_INTERVAL"),
+        (1411, "MQIACF_PAGECLAS"),
+        (2041, "MQCA_IGQ_USER_ID"),
+        (1001, "MQIACF_Q_MGR_ATTRS"),
+        (3502, "MQCACH_DESC"),
+        (1521, "MQIACH_SOCKET"),
+        (2079, "MQCA_SERVICE_START_COMMAND"),
+        (3198, "MQCACF_SELECTION_STRING"),
+        (3102, "MQCACF_CF_STRUC_BACKUP_END"),
+        (1195, "MQIACF_SYSP_RESLEVEL_AUDIT"),
+        (1621, "MQIACH_CONNECTION_AFFINITY"),
+        (106, "MQIA_DNS_WLM"),
+        (1014, "MQIACF_SELECTOR"),
+        (1206, "MQIACF_SYSP_BLOCK_SIZE"),
+        (2016, "MQCA_Q_NAME"),
+        (2136, "MQCA_AMQP_VERSION"),
+        (73, "MQIA_CHANNEL_EVENT"),
+        (1448, "MQIACF_AUTO_CLUSTER_TYPE"),
+        (2035, "MQCA_REPOSITORY_NAME"),
+        (2117, "MQCA_INSTALLATION_PATH"),
+        (3076, "MQCACF_Q_MGR_CPF"),
+        (3118, "MQCACF_SYSP_UNIT_VOLSER"),
+        (3510, "MQCACH_SEND_EXIT_NAME"),
+        (2135, "MQCA_LDAP_FIND_GROUP_FIELD"),
+        (3095, "MQCACF_CF_STRUC_NAMES"),
+        (196, "MQIA_NPM_DELIVERY"),
+        (3136, "MQCACF_APPL_ORIGIN_DATA"),
+        (2076, "MQCA_CHINIT_SERVICE_PARM"),
+        (1271, "MQIACF_PUBSUB_PROPERTIES"),
+        (25, "MQIA_TRIGGER_INTERVAL"),
+        (3135, "MQCACF_APPL_IDENTITY_DATA"),
+        (1598, "MQIACH_ACTIVE_CHL_RETRY"),
+        (3109, "MQCACF_DB2_NAME"),
+        (118, "MQIA_CHINIT_TRACE_TABLE_SIZE"),
+        (77, "MQIA_SHARED_Q_Q_MGR_NAME"),
+        (3511, "MQCACH_RCV_EXIT_NAME"),
+        (1410, "MQIACF_SYSP_MAX_ACE_POOL"),
+        (3053, "MQCACF_REG_SUB_NAME"),
+        (1153, "MQIACF_SECURITY_INTERVAL"),
+        (1460, "MQIACF_NHA_INSTANCE_ROLE"),
+        (1135, "MQIACF_CF_STATUS_TYPE"),
+        (1599, "MQIACH_LISTENER_STATUS"),
+        (174, "MQIA_AUTO_REORG_INTERVAL"),
+        (2029, "MQCA_CLUSTER_NAME"),
+        (1637, "MQIACH_MATCH"),
+        (258, "MQIA_CHECK_CLIENT_BINDING"),
+        (3050, "MQCACF_EVENT_APPL_NAME"),
+        (1196, "MQIACF_SYSP_ROUTING_CODE"),
+        (49, "MQIA_LOCAL_EVENT"),
+        (1455, "MQIACF_LOG_SECONDARIES"),
+        (1571, "MQIACH_ALLOC_FAST_TIMER"),
+        (122, "MQIA_MONITORING_CHANNEL"),
+        (3078, "MQCACF_USAGE_LOG_RBA"),
+        (1306, "MQIACF_CLEAR_SCOPE"),
+        (48, "MQIA_INHIBIT_EVENT"),
+        (1253, "MQIACF_PRIORITY"),
+        (115, "MQIA_TCP_KEEP_ALIVE"),
+        (3063, "MQCACF_UOW_LOG_START_TIME"),
+        (3161, "MQCACF_LAST_PUB_DATE"),
+        (1416, "MQIACF_ARCHIVE_LOG_SIZE"),
+        (1241, "MQIACF_BACKOUT_COUNT"),
+        (1101, "MQIACF_OPEN_INQUIRE"),
+        (1213, "MQIACF_SYSP_TIMESTAMP"),
+        (1251, "MQIACF_ORIGINAL_LENGTH"),
+        (1440, "MQIACF_BALANCING_OPTIONS"),
+        (121, "MQIA_SERVICE_TYPE"),
+        (18, "MQIA_OPEN_OUTPUT_COUNT"),
+        (1534, "MQIACH_MSGS"),
+        (101, "MQIA_CHINIT_ADAPTERS"),
+        (56, "MQIA_CHANNEL_AUTO_DEF_EVENT"),
+        (158, "MQIA_QMOPT_LOG_INFO_MSGS"),
+        (1005, "MQIACF_FORCE"),
+        (1572, "MQIACH_ALLOC_SLOW_TIMER"),
+        (3527, "MQCACH_MCA_USER_ID"),
+        (1100, "MQIACF_OPEN_SET"),
+        (1617, "MQIACH_CURRENT_SHARING_CONVS"),
+        (1012, "MQIACF_PARAMETER_ID"),
+        (2083, "MQCA_STDOUT_DESTINATION"),
+        (1408, "MQIACF_BUFFER_POOL_LOCATION"),
+        (2085, "MQCA_TPIPE_NAME"),
+        (1013, "MQIACF_ERROR_ID"),
+        (1466, "MQIACF_SYSP_ZHYPERLINK"),
+        (3031, "MQCACF_TOPIC"),
+        (3548, "MQCACH_REMOTE_APPL_TAG"),
+        (229, "MQIA_CF_OFFLDUSE"),
+        (3061, "MQCACF_UOW_START_TIME"),
+        (1511, "MQIACH_CHANNEL_TYPE"),
+        (1226, "MQIACF_Q_TIME_INDICATOR"),
+        (-1, "MQIASY_CODED_CHAR_SET_ID"),
+        (269, "MQIA_MEDIA_IMAGE_INTERVAL"),
+        (68, "MQIA_MSG_MARK_BROWSE_INTERVAL"),
+        (152, "MQIA_QMOPT_CONS_WARNING_MSGS"),
+        (104, "MQIA_ADOPTNEWMCA_INTERVAL"),
+        (2011, "MQCA_PROCESS_DESC"),
+        (1173, "MQIACF_CONFIGURATION_OBJECTS"),
+        (1152, "MQIACF_SECURITY_TIMEOUT"),
+        (1604, "MQIACH_XMITQ_TIME_INDICATOR"),
+        (108, "MQIA_LU62_CHANNELS"),
+        (2020, "MQCA_NAMES"),
+        (3086, "MQCACF_Q_MGR_UOW_ID"),
+        (1221, "MQIACF_SYSP_FULL_LOGS"),
+        (1525, "MQIACH_CHANNEL_ERROR_DATA"),
+    ],
+}, &[(1008,"MQIACF_MODE"),(1013,"MQIACF_ERROR_IDENTIFIER"),(1199,"MQIACF_SYSP_SMF_STAT_TIME_MINS"),(1529,"MQIACH_LAST_SEQUENCE_NUMBER"),(1532,"MQIACH_CURRENT_SEQUENCE_NUMBER"),(1536,"MQIACH_BYTES_RECEIVED"),(1539,"MQIACH_BUFFERS_RECEIVED"),(1634,"MQIACH_MSGS_RECEIVED"),(2002,"MQCA_BASE_Q_NAME"),]);
+/// Selectors for MQIA and MQCA combined
+pub const MQXA_FULL_CONST: PhfSource = ConstSource(&::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 233),
+        (1, 1),
+        (0, 3),
+        (0, 0),
+        (0, 0),
+        (0, 323),
+        (0, 17),
+        (0, 0),
+        (1, 308),
+        (0, 212),
+        (0, 26),
+        (0, 0),
+        (1, 1),
+        (0, 31),
+        (2, 36),
+        (1, 41),
+        (0, 18),
+        (0, 36),
+        (2, 289),
+        (5, 295),
+        (3, 82),
+        (0, 261),
+        (0, 7),
+        (0, 65),
+        (0, 1),
+        (0, 76),
+        (1, 302),
+        (0, 35),
+        (0, 160),
+        (0, 8),
+        (0, 14),
+        (0, 223),
+        (0, 7),
+        (0, 24),
+        (0, 5),
+        (6, 355),
+        (0, 202),
+        (1, 176),
+        (1, 210),
+        (0, 114),
+        (1, 66),
+        (4, 50),
+        (36, 317),
+        (0, 73),
+        (13, 286),
+        (0, 0),
+        (0, 122),
+        (0, 21),
+        (3, 119),
+        (0, 32),
+        (0, 110),
+        (0, 158),
+        (0, 90),
+        (0, 0),
+        (0, 269),
+        (2, 331),
+        (11, 140),
+        (0, 4),
+        (4, 41),
+        (0, 24),
+        (0, 0),
+        (11, 134),
+        (147, 160),
+        (0, 31),
+        (5, 300),
+        (0, 9),
+        (1, 210),
+        (0, 54),
+        (0, 125),
+        (0, 108),
+        (0, 4),
+        (0, 369),
+        (14, 290),
+        (0, 2),
+    ],
+    entries: &[
+        (229, "MQIA_CF_OFFLDUSE"),
+        (2006, "MQCA_DEAD_LETTER_Q_NAME"),
+        (2028, "MQCA_ALTERATION_TIME"),
+        (6, "MQIA_DEF_PRIORITY"),
+        (2098, "MQCA_RESUME_DATE"),
+        (77, "MQIA_SHARED_Q_Q_MGR_NAME"),
+        (2078, "MQCA_SERVICE_DESC"),
+        (167, "MQIA_QMOPT_TRACE_REORG"),
+        (63, "MQIA_QSG_DISP"),
+        (36, "MQIA_HIGH_Q_DEPTH"),
+        (95, "MQIA_CLWL_Q_RANK"),
+        (2024, "MQCA_XMIT_Q_NAME"),
+        (184, "MQIA_DEF_PUT_RESPONSE_TYPE"),
+        (240, "MQIA_ACTIVITY_TRACE"),
+        (2080, "MQCA_SERVICE_START_ARGS"),
+        (2123, "MQCA_XR_SSL_CIPHER_SUITES"),
+        (2021, "MQCA_USER_DATA"),
+        (188, "MQIA_DEF_READ_AHEAD"),
+        (2116, "MQCA_INSTALLATION_NAME"),
+        (2091, "MQCA_AUTO_REORG_CATALOG"),
+        (1, "MQIA_APPL_TYPE"),
+        (2052, "MQCA_CF_STRUC_DESC"),
+        (41, "MQIA_Q_DEPTH_LOW_LIMIT"),
+        (2097, "MQCA_MODEL_NON_DURABLE_Q"),
+        (2001, "MQCA_APPL_ID"),
+        (256, "MQIA_CLUSTER_OBJECT_STATE"),
+        (65, "MQIA_IGQ_PUT_AUTHORITY"),
+        (2036, "MQCA_REPOSITORY_NAMELIST"),
+        (253, "MQIA_TOPIC_NODE_COUNT"),
+        (2124, "MQCA_CLUS_CHL_NAME"),
+        (13, "MQIA_MAX_MSG_LENGTH"),
+        (2063, "MQCA_TRIGGER_TERM_ID"),
+        (246, "MQIA_CF_CFCONLOS"),
+        (172, "MQIA_MAX_CLIENTS"),
+        (119, "MQIA_CHINIT_CONTROL"),
+        (2125, "MQCA_CONN_AUTH"),
+        (133, "MQIA_ACCOUNTING_MQI"),
+        (215, "MQIA_PUB_COUNT"),
+        (70, "MQIA_CF_LEVEL"),
+        (38, "MQIA_MSG_DEQ_COUNT"),
+        (103, "MQIA_ADOPTNEWMCA_TYPE"),
+        (257, "MQIA_CHECK_LOCAL_BINDING"),
+        (48, "MQIA_INHIBIT_EVENT"),
+        (2038, "MQCA_CLUSTER_TIME"),
+        (2120, "MQCA_VERSION"),
+        (75, "MQIA_SSL_EVENT"),
+        (199, "MQIA_PROXY_SUB"),
+        (2020, "MQCA_NAMES"),
+        (2073, "MQCA_LU_NAME"),
+        (37, "MQIA_MSG_ENQ_COUNT"),
+        (60, "MQIA_ARCHIVE"),
+        (231, "MQIA_RESPONSE_RESTART_POINT"),
+        (2027, "MQCA_ALTERATION_DATE"),
+        (115, "MQIA_TCP_KEEP_ALIVE"),
+        (207, "MQIA_PUBSUB_SYNC_PT"),
+        (268, "MQIA_MEDIA_IMAGE_SCHEDULING"),
+        (166, "MQIA_QMOPT_TRACE_COMMS"),
+        (2009, "MQCA_NAMELIST_DESC"),
+        (2102, "MQCA_PARENT"),
+        (83, "MQIA_MAX_GLOBAL_LOCKS"),
+        (2129, "MQCA_LDAP_USER_ATTR_FIELD"),
+        (195, "MQIA_PM_DELIVERY"),
+        (31, "MQIA_COMMAND_LEVEL"),
+        (22, "MQIA_BACKOUT_THRESHOLD"),
+        (138, "MQIA_ACTIVITY_RECORDING"),
+        (2121, "MQCA_CERT_LABEL"),
+        (136, "MQIA_ACCOUNTING_CONN_OVERRIDE"),
+        (2017, "MQCA_REMOTE_Q_MGR_NAME"),
+        (88, "MQIA_CMD_SERVER_CONVERT_MSG"),
+        (193, "MQIA_BASE_TYPE"),
+        (2069, "MQCA_SSL_KEY_LIBRARY"),
+        (55, "MQIA_CHANNEL_AUTO_DEF"),
+        (3, "MQIA_CURRENT_Q_DEPTH"),
+        (2053, "MQCA_AUTH_INFO_CONN_NAME"),
+        (2108, "MQCA_TOPIC_STRING_FILTER"),
+        (26, "MQIA_TRIGGER_MSG_PRIORITY"),
+        (160, "MQIA_QMOPT_LOG_ERROR_MSGS"),
+        (25, "MQIA_TRIGGER_INTERVAL"),
+        (110, "MQIA_OUTBOUND_PORT_MIN"),
+        (27, "MQIA_CPI_LEVEL"),
+        (156, "MQIA_QMOPT_CONS_REORG_MSGS"),
+        (2076, "MQCA_CHINIT_SERVICE_PARM"),
+        (2096, "MQCA_MODEL_DURABLE_Q"),
+        (134, "MQIA_ACCOUNTING_Q"),
+        (187, "MQIA_PUBSUB_MODE"),
+        (82, "MQIA_Q_USERS"),
+        (190, "MQIA_PROPERTY_CONTROL"),
+        (2034, "MQCA_CLUSTER_WORKLOAD_DATA"),
+        (226, "MQIA_CF_OFFLOAD_THRESHOLD2"),
+        (76, "MQIA_SSL_RESET_COUNT"),
+        (218, "MQIA_SUB_SCOPE"),
+        (2004, "MQCA_CREATION_DATE"),
+        (94, "MQIA_LOGGER_EVENT"),
+        (2134, "MQCA_LDAP_GROUP_ATTR_FIELD"),
+        (2136, "MQCA_AMQP_VERSION"),
+        (44, "MQIA_Q_DEPTH_LOW_EVENT"),
+        (128, "MQIA_STATISTICS_Q"),
+        (112, "MQIA_RECEIVE_TIMEOUT_TYPE"),
+        (2019, "MQCA_BACKOUT_REQ_Q_NAME"),
+        (2079, "MQCA_SERVICE_START_COMMAND"),
+        (2015, "MQCA_Q_MGR_NAME"),
+        (2122, "MQCA_XR_VERSION"),
+        (196, "MQIA_NPM_DELIVERY"),
+        (272, "MQIA_MEDIA_IMAGE_RECOVER_Q"),
+        (2067, "MQCA_COMMAND_REPLY_Q_NAME"),
+        (2046, "MQCA_AUTH_INFO_DESC"),
+        (227, "MQIA_CF_OFFLOAD_THRESHOLD3"),
+        (108, "MQIA_LU62_CHANNELS"),
+        (2008, "MQCA_INITIATION_Q_NAME"),
+        (2135, "MQCA_LDAP_FIND_GROUP_FIELD"),
+        (33, "MQIA_MAX_UNCOMMITTED_MSGS"),
+        (255, "MQIA_CLUSTER_PUB_ROUTE"),
+        (18, "MQIA_OPEN_OUTPUT_COUNT"),
+        (40, "MQIA_Q_DEPTH_HIGH_LIMIT"),
+        (17, "MQIA_OPEN_INPUT_COUNT"),
+        (2031, "MQCA_CLUSTER_Q_MGR_NAME"),
+        (204, "MQIA_SUB_COUNT"),
+        (137, "MQIA_TRACE_ROUTE_RECORDING"),
+        (203, "MQIA_PUBSUB_NP_MSG"),
+        (46, "MQIA_Q_SERVICE_INTERVAL_EVENT"),
+        (233, "MQIA_MCAST_BRIDGE"),
+        (78, "MQIA_NPM_CLASS"),
+        (2032, "MQCA_Q_MGR_IDENTIFIER"),
+        (100, "MQIA_ACTIVE_CHANNELS"),
+        (80, "MQIA_MAX_OPEN_Q"),
+        (249, "MQIA_PUBSUB_CLUSTER"),
+        (235, "MQIA_TOLERATE_UNPROTECTED"),
+        (171, "MQIA_MAX_RECOVERY_TASKS"),
+        (232, "MQIA_COMM_EVENT"),
+        (2054, "MQCA_INITIAL_KEY"),
+        (2064, "MQCA_TRIGGER_CHANNEL_NAME"),
+        (4, "MQIA_DEF_INPUT_OPEN_OPTION"),
+        (237, "MQIA_ENCRYPTION_ALGORITHM"),
+        (252, "MQIA_CERT_VAL_POLICY"),
+        (45, "MQIA_SCOPE"),
+        (2109, "MQCA_AUTH_INFO_OCSP_URL"),
+        (2000, "MQIA_USER_LIST"),
+        (2071, "MQCA_DNS_GROUP"),
+        (250, "MQIA_DEF_CLUSTER_XMIT_Q_TYPE"),
+        (2042, "MQCA_STORAGE_CLASS_DESC"),
+        (121, "MQIA_SERVICE_TYPE"),
+        (39, "MQIA_EXPIRY_INTERVAL"),
+        (266, "MQIA_AUTHENTICATION_METHOD"),
+        (2066, "MQCA_MONITOR_Q_NAME"),
+        (113, "MQIA_RECEIVE_TIMEOUT_MIN"),
+        (99, "MQIA_COMMAND_EVENT"),
+        (153, "MQIA_QMOPT_CONS_ERROR_MSGS"),
+        (239, "MQIA_ACTIVITY_CONN_OVERRIDE"),
+        (242, "MQIA_SUB_CONFIGURATION_EVENT"),
+        (270, "MQIA_MEDIA_IMAGE_LOG_LENGTH"),
+        (118, "MQIA_CHINIT_TRACE_TABLE_SIZE"),
+        (2005, "MQCA_CREATION_TIME"),
+        (238, "MQIA_POLICY_VERSION"),
+        (2081, "MQCA_SERVICE_STOP_COMMAND"),
+        (271, "MQIA_MEDIA_IMAGE_RECOVER_OBJ"),
+        (174, "MQIA_AUTO_REORG_INTERVAL"),
+        (107, "MQIA_LISTENER_TIMER"),
+        (90, "MQIA_MAX_Q_TRIGGERS"),
+        (2010, "MQCA_NAMELIST_NAME"),
+        (102, "MQIA_ADOPTNEWMCA_CHECK"),
+        (140, "MQIA_OUTBOUND_PORT_MAX"),
+        (50, "MQIA_REMOTE_EVENT"),
+        (54, "MQIA_Q_SERVICE_INTERVAL"),
+        (150, "MQIA_QMOPT_CSMT_ON_ERROR"),
+        (221, "MQIA_GROUP_UR"),
+        (34, "MQIA_DIST_LISTS"),
+        (86, "MQIA_BATCH_INTERFACE_AUTO"),
+        (2085, "MQCA_TPIPE_NAME"),
+        (152, "MQIA_QMOPT_CONS_WARNING_MSGS"),
+        (2118, "MQCA_CHLAUTH_DESC"),
+        (181, "MQIA_INHIBIT_PUB"),
+        (2119, "MQCA_CUSTOM"),
+        (2133, "MQCA_LDAP_GROUP_OBJECT_CLASS"),
+        (2040, "MQCA_QSG_NAME"),
+        (30, "MQIA_SYNCPOINT"),
+        (8, "MQIA_HARDEN_GET_BACKOUT"),
+        (170, "MQIA_QMOPT_INTERNAL_DUMP"),
+        (66, "MQIA_AUTH_INFO_TYPE"),
+        (101, "MQIA_CHINIT_ADAPTERS"),
+        (122, "MQIA_MONITORING_CHANNEL"),
+        (56, "MQIA_CHANNEL_AUTO_DEF_EVENT"),
+        (2127, "MQCA_LDAP_SHORT_USER_FIELD"),
+        (182, "MQIA_INHIBIT_SUB"),
+        (92, "MQIA_SSL_FIPS_REQUIRED"),
+        (245, "MQIA_QMGR_CFCONLOS"),
+        (105, "MQIA_CHINIT_DISPATCHERS"),
+        (219, "MQIA_PUB_SCOPE"),
+        (269, "MQIA_MEDIA_IMAGE_INTERVAL"),
+        (135, "MQIA_ACCOUNTING_INTERVAL"),
+        (7, "MQIA_DEFINITION_TYPE"),
+        (185, "MQIA_TOPIC_DEF_PERSISTENCE"),
+        (192, "MQIA_MAX_PROPERTIES_LENGTH"),
+        (162, "MQIA_QMOPT_LOG_COMMS_MSGS"),
+        (165, "MQIA_QMOPT_TRACE_MQI_CALLS"),
+        (259, "MQIA_AUTHENTICATION_FAIL_DELAY"),
+        (2002, "MQCA_BASE_OBJECT_NAME"),
+        (2033, "MQCA_CLUSTER_WORKLOAD_EXIT"),
+        (2072, "MQCA_LU_GROUP_NAME"),
+        (2, "MQIA_CODED_CHAR_SET_ID"),
+        (2117, "MQCA_INSTALLATION_PATH"),
+        (2051, "MQCA_SSL_CRYPTO_HARDWARE"),
+        (186, "MQIA_MASTER_ADMIN"),
+        (114, "MQIA_TCP_CHANNELS"),
+        (97, "MQIA_CLWL_MRU_CHANNELS"),
+        (98, "MQIA_CLWL_USEQ"),
+        (2023, "MQCA_TRIGGER_DATA"),
+        (258, "MQIA_CHECK_CLIENT_BINDING"),
+        (9, "MQIA_INHIBIT_GET"),
+        (2013, "MQCA_Q_DESC"),
+        (183, "MQIA_TREE_LIFE_TIME"),
+        (2022, "MQCA_STORAGE_CLASS"),
+        (275, "MQIA_STREAM_QUEUE_QOS"),
+        (123, "MQIA_MONITORING_Q"),
+        (2039, "MQCA_CF_STRUC_NAME"),
+        (89, "MQIA_CMD_SERVER_DLQ_MSG"),
+        (14, "MQIA_MAX_PRIORITY"),
+        (87, "MQIA_CMD_SERVER_AUTO"),
+        (16, "MQIA_MSG_DELIVERY_SEQUENCE"),
+        (2043, "MQCA_XCF_GROUP_NAME"),
+        (2086, "MQCA_PASS_TICKET_APPL"),
+        (228, "MQIA_CF_SMDS_BUFFERS"),
+        (2101, "MQCA_CHILD"),
+        (104, "MQIA_ADOPTNEWMCA_INTERVAL"),
+        (157, "MQIA_QMOPT_CONS_SYSTEM_MSGS"),
+        (2011, "MQCA_PROCESS_DESC"),
+        (154, "MQIA_QMOPT_CONS_CRITICAL_MSGS"),
+        (216, "MQIA_WILDCARD_OPERATION"),
+        (53, "MQIA_PERFORMANCE_EVENT"),
+        (64, "MQIA_INTRA_GROUP_QUEUING"),
+        (2003, "MQCA_COMMAND_INPUT_Q_NAME"),
+        (2126, "MQCA_LDAP_BASE_DN_USERS"),
+        (2055, "MQCA_SSL_KEY_REPO_PASSWORD"),
+        (127, "MQIA_STATISTICS_MQI"),
+        (2093, "MQCA_TOPIC_DESC"),
+        (251, "MQIA_PROT_POLICY_CAPABILITY"),
+        (20, "MQIA_Q_TYPE"),
+        (164, "MQIA_QMOPT_LOG_SYSTEM_MSGS"),
+        (12, "MQIA_USAGE"),
+        (260, "MQIA_ADOPT_CONTEXT"),
+        (273, "MQIA_ADVANCED_CAPABILITY"),
+        (96, "MQIA_CLWL_Q_PRIORITY"),
+        (5, "MQIA_DEF_PERSISTENCE"),
+        (254, "MQIA_REVERSE_DNS_LOOKUP"),
+        (2016, "MQCA_Q_NAME"),
+        (2131, "MQCA_QSG_CERT_LABEL"),
+        (161, "MQIA_QMOPT_LOG_CRITICAL_MSGS"),
+        (81, "MQIA_MONITOR_INTERVAL"),
+        (47, "MQIA_AUTHORITY_EVENT"),
+        (169, "MQIA_QMOPT_TRACE_SYSTEM"),
+        (29, "MQIA_TRIGGER_DEPTH"),
+        (28, "MQIA_TRIGGER_TYPE"),
+        (2115, "MQCA_INSTALLATION_DESC"),
+        (74, "MQIA_BRIDGE_EVENT"),
+        (35, "MQIA_TIME_SINCE_RESET"),
+        (2130, "MQCA_SSL_CERT_ISSUER_NAME"),
+        (23, "MQIA_SHAREABILITY"),
+        (93, "MQIA_IP_ADDRESS_VERSION"),
+        (2132, "MQCA_LDAP_BASE_DN_GROUPS"),
+        (59, "MQIA_CLUSTER_Q_TYPE"),
+        (155, "MQIA_QMOPT_CONS_COMMS_MSGS"),
+        (2035, "MQCA_REPOSITORY_NAME"),
+        (57, "MQIA_INDEX_TYPE"),
+        (32, "MQIA_PLATFORM"),
+        (224, "MQIA_CF_OFFLOAD"),
+        (52, "MQIA_START_STOP_EVENT"),
+        (2030, "MQCA_CLUSTER_NAMELIST"),
+        (2047, "MQCA_LDAP_USER_NAME"),
+        (223, "MQIA_COMM_INFO_TYPE"),
+        (2090, "MQCA_AUTO_REORG_START_TIME"),
+        (267, "MQIA_KEY_REUSE_COUNT"),
+        (111, "MQIA_RECEIVE_TIMEOUT"),
+        (2074, "MQCA_LU62_ARM_SUFFIX"),
+        (42, "MQIA_Q_DEPTH_MAX_EVENT"),
+        (11, "MQIA_MAX_HANDLES"),
+        (69, "MQIA_SSL_TASKS"),
+        (2025, "MQCA_DEF_XMIT_Q_NAME"),
+        (73, "MQIA_CHANNEL_EVENT"),
+        (247, "MQIA_SUITE_B_STRENGTH"),
+        (151, "MQIA_QMOPT_CONS_INFO_MSGS"),
+        (243, "MQIA_XR_CAPABILITY"),
+        (2068, "MQCA_BATCH_INTERFACE_ID"),
+        (175, "MQIA_DURABLE_SUB"),
+        (159, "MQIA_QMOPT_LOG_WARNING_MSGS"),
+        (2014, "MQCA_Q_MGR_DESC"),
+        (2092, "MQCA_TOPIC_NAME"),
+        (139, "MQIA_SERVICE_CONTROL"),
+        (2077, "MQCA_SERVICE_NAME"),
+        (163, "MQIA_QMOPT_LOG_REORG_MSGS"),
+        (168, "MQIA_QMOPT_TRACE_CONVERSION"),
+        (206, "MQIA_PUBSUB_MAXMSG_RETRY_COUNT"),
+        (2082, "MQCA_SERVICE_STOP_ARGS"),
+        (222, "MQIA_UR_DISP"),
+        (2084, "MQCA_STDERR_DESTINATION"),
+        (2044, "MQCA_XCF_MEMBER_NAME"),
+        (58, "MQIA_CLUSTER_WORKLOAD_LENGTH"),
+        (109, "MQIA_MAX_CHANNELS"),
+        (2075, "MQCA_TCP_NAME"),
+        (124, "MQIA_MONITORING_AUTO_CLUSSDR"),
+        (131, "MQIA_STATISTICS_INTERVAL"),
+        (2061, "MQCA_TRIGGER_TRANS_ID"),
+        (2050, "MQCA_SSL_CRL_NAMELIST"),
+        (106, "MQIA_DNS_WLM"),
+        (2062, "MQCA_TRIGGER_PROGRAM_NAME"),
+        (230, "MQIA_MAX_RESPONSES"),
+        (2113, "MQCA_SIGNER_DN"),
+        (68, "MQIA_MSG_MARK_BROWSE_INTERVAL"),
+        (2048, "MQCA_LDAP_PASSWORD"),
+        (61, "MQIA_DEF_BIND"),
+        (2112, "MQCA_POLICY_NAME"),
+        (262, "MQIA_DISPLAY_TYPE"),
+        (2018, "MQCA_REMOTE_Q_NAME"),
+        (141, "MQIA_SECURITY_CASE"),
+        (263, "MQIA_LDAP_AUTHORMD"),
+        (189, "MQIA_READ_AHEAD"),
+        (225, "MQIA_CF_OFFLOAD_THRESHOLD1"),
+        (276, "MQIA_CAP_EXPIRY"),
+        (2114, "MQCA_RECIPIENT_DN"),
+        (274, "MQIA_MAX_Q_FILE_SIZE"),
+        (62, "MQIA_PAGESET_ID"),
+        (2128, "MQCA_LDAP_USER_OBJECT_CLASS"),
+        (84, "MQIA_MAX_LOCAL_LOCKS"),
+        (51, "MQIA_CONFIGURATION_EVENT"),
+        (2045, "MQCA_AUTH_INFO_NAME"),
+        (2041, "MQCA_IGQ_USER_ID"),
+        (236, "MQIA_SIGNATURE_ALGORITHM"),
+        (19, "MQIA_NAME_COUNT"),
+        (2007, "MQCA_ENV_DATA"),
+        (2138, "MQCA_STREAM_QUEUE_NAME"),
+        (117, "MQIA_CHINIT_TRACE_AUTO_START"),
+        (4000, "MQCA_USER_LIST"),
+        (2029, "MQCA_CLUSTER_NAME"),
+        (2065, "MQCA_SYSTEM_LOG_Q_NAME"),
+        (116, "MQIA_TCP_STACK_TYPE"),
+        (24, "MQIA_TRIGGER_CONTROL"),
+        (2060, "MQCA_CICS_FILE_NAME"),
+        (71, "MQIA_CF_RECOVER"),
+        (2083, "MQCA_STDOUT_DESTINATION"),
+        (2070, "MQCA_SSL_KEY_MEMBER"),
+        (2026, "MQCA_CHANNEL_AUTO_DEF_EXIT"),
+        (176, "MQIA_MULTICAST"),
+        (2094, "MQCA_TOPIC_STRING"),
+        (264, "MQIA_LDAP_NESTGRP"),
+        (2012, "MQCA_PROCESS_NAME"),
+        (173, "MQIA_AUTO_REORGANIZATION"),
+        (85, "MQIA_LISTENER_PORT_NUMBER"),
+        (2037, "MQCA_CLUSTER_DATE"),
+        (21, "MQIA_RETENTION_INTERVAL"),
+        (234, "MQIA_USE_DEAD_LETTER_Q"),
+        (2105, "MQCA_ADMIN_TOPIC_NAME"),
+        (265, "MQIA_AMQP_CAPABILITY"),
+        (120, "MQIA_CMD_SERVER_CONTROL"),
+        (72, "MQIA_NAMELIST_TYPE"),
+        (205, "MQIA_PUBSUB_NP_RESP"),
+        (43, "MQIA_Q_DEPTH_HIGH_EVENT"),
+        (129, "MQIA_STATISTICS_CHANNEL"),
+        (248, "MQIA_CHLAUTH_RECORDS"),
+        (2110, "MQCA_COMM_INFO_NAME"),
+        (10, "MQIA_INHIBIT_PUT"),
+        (244, "MQIA_CF_RECAUTO"),
+        (49, "MQIA_LOCAL_EVENT"),
+        (2137, "MQCA_AMQP_SSL_CIPHER_SUITES"),
+        (91, "MQIA_TRIGGER_RESTART"),
+        (130, "MQIA_STATISTICS_AUTO_CLUSSDR"),
+        (2049, "MQCA_SSL_KEY_REPOSITORY"),
+        (15, "MQIA_MAX_Q_DEPTH"),
+        (2111, "MQCA_COMM_INFO_DESC"),
+        (261, "MQIA_LDAP_SECURE_COMM"),
+        (158, "MQIA_QMOPT_LOG_INFO_MSGS"),
+        (208, "MQIA_TOPIC_TYPE"),
+        (2099, "MQCA_RESUME_TIME"),
+    ],
+}, &[(2002,"MQCA_BASE_Q_NAME"),]);
+/// Combined MQRC and MQRCCF range
+pub const MQRC_FULL_CONST: PhfSource = ConstSource(&::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 273),
+        (0, 416),
+        (0, 13),
+        (0, 4),
+        (0, 168),
+        (0, 96),
+        (0, 169),
+        (0, 579),
+        (0, 2),
+        (1, 306),
+        (0, 516),
+        (0, 12),
+        (0, 88),
+        (0, 108),
+        (0, 23),
+        (0, 182),
+        (0, 863),
+        (0, 66),
+        (0, 201),
+        (0, 41),
+        (0, 2),
+        (0, 12),
+        (0, 56),
+        (0, 0),
+        (0, 80),
+        (0, 667),
+        (0, 7),
+        (0, 49),
+        (0, 14),
+        (0, 2),
+        (0, 266),
+        (0, 771),
+        (1, 502),
+        (0, 78),
+        (0, 507),
+        (0, 196),
+        (0, 54),
+        (0, 25),
+        (0, 313),
+        (0, 18),
+        (0, 84),
+        (0, 11),
+        (0, 0),
+        (0, 139),
+        (0, 365),
+        (0, 638),
+        (0, 204),
+        (2, 317),
+        (0, 28),
+        (0, 2),
+        (0, 152),
+        (0, 3),
+        (0, 509),
+        (0, 0),
+        (2, 563),
+        (0, 361),
+        (0, 157),
+        (0, 4),
+        (0, 0),
+        (0, 6),
+        (0, 467),
+        (0, 9),
+        (0, 201),
+        (0, 238),
+        (0, 0),
+        (0, 40),
+        (0, 6),
+        (0, 20),
+        (0, 14),
+        (1, 203),
+        (0, 43),
+        (3, 350),
+        (0, 346),
+        (0, 117),
+        (1, 71),
+        (6, 671),
+        (0, 1),
+        (0, 367),
+        (0, 0),
+        (0, 6),
+        (1, 155),
+        (0, 114),
+        (0, 39),
+        (0, 19),
+        (0, 3),
+        (0, 1),
+        (0, 84),
+        (0, 170),
+        (0, 34),
+        (2, 180),
+        (1, 153),
+        (0, 1),
+        (0, 1),
+        (0, 66),
+        (0, 6),
+        (0, 342),
+        (0, 568),
+        (0, 0),
+        (0, 4),
+        (0, 35),
+        (1, 529),
+        (0, 109),
+        (0, 0),
+        (0, 381),
+        (4, 240),
+        (0, 81),
+        (0, 10),
+        (0, 368),
+        (0, 26),
+        (0, 104),
+        (14, 552),
+        (0, 21),
+        (0, 20),
+        (0, 3),
+        (0, 15),
+        (0, 294),
+        (0, 285),
+        (0, 31),
+        (0, 503),
+        (4, 518),
+        (2, 786),
+        (0, 7),
+        (0, 10),
+        (0, 1),
+        (0, 812),
+        (0, 83),
+        (0, 64),
+        (0, 205),
+        (4, 182),
+        (0, 77),
+        (0, 23),
+        (5, 338),
+        (0, 6),
+        (0, 7),
+        (0, 57),
+        (0, 331),
+        (0, 0),
+        (0, 5),
+        (3, 801),
+        (0, 6),
+        (0, 0),
+        (0, 36),
+        (2, 395),
+        (0, 567),
+        (0, 371),
+        (3, 433),
+        (0, 41),
+        (4, 403),
+        (5, 735),
+        (0, 10),
+        (0, 0),
+        (0, 434),
+        (0, 40),
+        (0, 0),
+        (3, 369),
+        (0, 44),
+        (12, 491),
+        (9, 546),
+        (0, 0),
+        (0, 27),
+        (3, 552),
+        (0, 264),
+        (5, 867),
+        (4, 739),
+        (0, 342),
+        (67, 345),
+        (0, 44),
+        (3, 677),
+        (2, 87),
+        (0, 0),
+        (2, 792),
+        (6, 653),
+        (0, 0),
+        (0, 302),
+        (0, 8),
+        (51, 411),
+        (0, 1),
+        (0, 455),
+        (0, 303),
+        (0, 3),
+        (0, 3),
+        (0, 0),
+        (0, 14),
+        (23, 413),
+        (8, 170),
+        (5, 577),
+    ],
+    entries: &[
+        (2048, "MQRC_PERSISTENT_NOT_ALLOWED"),
+        (2552, "MQRC_CHANNEL_SSL_WARNING"),
+        (4054, "MQRCCF_DISC_INT_WRONG_TYPE"),
+        (3054, "MQRCCF_ESCAPE_TYPE_ERROR"),
+        (2251, "MQRC_OFFSET_ERROR"),
+        (3211, "MQRCCF_DISPOSITION_CONFLICT"),
+        (3235, "MQRCCF_CHANNEL_ERROR"),
+        (3174, "MQRCCF_CONNECTION_ID_ERROR"),
+        (2097, "MQRC_CONTEXT_HANDLE_ERROR"),
+        (2120, "MQRC_CONVERTED_MSG_TOO_BIG"),
+        (2077, "MQRC_TRIGGER_MSG_PRIORITY_ERR"),
+        (3368, "MQRCCF_CLUSTER_TOPIC_CONFLICT"),
+        (2116, "MQRC_TARGET_INTEGER_ENC_ERROR"),
+        (3083, "MQRCCF_REG_OPTIONS_ERROR"),
+        (6111, "MQRC_BINARY_DATA_LENGTH_ERROR"),
+        (2011, "MQRC_DYNAMIC_Q_NAME_ERROR"),
+        (2385, "MQRC_AIR_ERROR"),
+        (3252, "MQRCCF_SERV_STATUS_NOT_FOUND"),
+        (3337, "MQRCCF_CHLAUTH_ALREADY_EXISTS"),
+        (2363, "MQRC_MSG_NOT_MATCHED"),
+        (4089, "MQRCCF_NET_PRIORITY_WRONG_TYPE"),
+        (4081, "MQRCCF_CHAD_EVENT_ERROR"),
+        (2160, "MQRC_CONN_ID_IN_USE"),
+        (2472, "MQRC_PROP_NUMBER_FORMAT_ERROR"),
+        (2553, "MQRC_OCSP_URL_ERROR"),
+        (29440, "MQRC_SUB_JOIN_NOT_ALTERABLE"),
+        (2366, "MQRC_WRONG_CF_LEVEL"),
+        (3218, "MQRCCF_CHANNEL_INITIATOR_ERROR"),
+        (2262, "MQRC_SRC_NAME_ERROR"),
+        (2435, "MQRC_ALTER_SUB_ERROR"),
+        (2047, "MQRC_PERSISTENCE_ERROR"),
+        (2362, "MQRC_BACKOUT_THRESHOLD_REACHED"),
+        (3026, "MQRCCF_CFIL_DUPLICATE_VALUE"),
+        (2270, "MQRC_NO_DESTINATIONS_AVAILABLE"),
+        (2147, "MQRC_INCOMPLETE_TRANSACTION"),
+        (3303, "MQRCCF_SECURITY_CASE_CONFLICT"),
+        (4026, "MQRCCF_MQCONN_FAILED"),
+        (2588, "MQRC_EXIT_PROPS_NOT_SUPPORTED"),
+        (3333, "MQRCCF_RECIPIENT_DN_MISSING"),
+        (3370, "MQRCCF_CHLAUTH_CHKCLI_ERROR"),
+        (3212, "MQRCCF_Q_MGR_NOT_IN_QSG"),
+        (3172, "MQRCCF_AUTH_VALUE_MISSING"),
+        (3024, "MQRCCF_CFSL_LENGTH_ERROR"),
+        (3076, "MQRCCF_Q_NAME_ERROR"),
+        (3053, "MQRCCF_INDOUBT_VALUE_ERROR"),
+        (2497, "MQRC_MODULE_ENTRY_NOT_FOUND"),
+        (2352, "MQRC_LOCAL_UOW_CONFLICT"),
+        (2282, "MQRC_CHANNEL_STARTED"),
+        (6107, "MQRC_STRUC_ID_ERROR"),
+        (3002, "MQRCCF_CFH_LENGTH_ERROR"),
+        (6121, "MQRC_CONTEXT_OBJECT_NOT_VALID"),
+        (2319, "MQRC_ITEM_VALUE_ERROR"),
+        (4059, "MQRCCF_PUT_AUTH_WRONG_TYPE"),
+        (4085, "MQRCCF_SUPPRESSED_BY_EXIT"),
+        (3338, "MQRCCF_CHLAUTH_NOT_FOUND"),
+        (2245, "MQRC_INCONSISTENT_UOW"),
+        (2131, "MQRC_ADAPTER_DEFS_ERROR"),
+        (2441, "MQRC_OBJECT_STRING_ERROR"),
+        (2494, "MQRC_GET_ENABLED"),
+        (3011, "MQRCCF_CFST_STRING_LENGTH_ERR"),
+        (3384, "MQRCCF_STREAMQ_DEST_NOT_SUPP"),
+        (2049, "MQRC_PRIORITY_EXCEEDS_MAXIMUM"),
+        (6124, "MQRC_NOT_CONNECTED"),
+        (2333, "MQRC_WIH_ERROR"),
+        (2242, "MQRC_INCOMPLETE_MSG"),
+        (3305, "MQRCCF_TOPIC_TYPE_ERROR"),
+        (4067, "MQRCCF_DYNAMIC_Q_SCOPE_ERROR"),
+        (2126, "MQRC_BRIDGE_STOPPED"),
+        (2391, "MQRC_SSL_ALREADY_INITIALIZED"),
+        (2484, "MQRC_CBD_OPTIONS_ERROR"),
+        (2163, "MQRC_DUPLICATE_RECOV_COORD"),
+        (6119, "MQRC_INCONSISTENT_FORMAT"),
+        (4099, "MQRCCF_Q_MGR_STATUS_NOT_FOUND"),
+        (3160, "MQRCCF_OBJECT_IN_USE"),
+        (2532, "MQRC_MSG_HANDLE_COPY_FAILURE"),
+        (2241, "MQRC_INCOMPLETE_GROUP"),
+        (2369, "MQRC_CONFIG_DELETE_OBJECT"),
+        (6101, "MQRC_REOPEN_INQUIRE_ERROR"),
+        (2591, "MQRC_CIPHER_SPEC_NOT_SUITE_B"),
+        (3321, "MQRCCF_USE_CLIENT_ID_ERROR"),
+        (3246, "MQRCCF_CFSF_OPERATOR_ERROR"),
+        (2544, "MQRC_RECONNECTING"),
+        (2434, "MQRC_IDENTITY_MISMATCH"),
+        (3169, "MQRCCF_ENTITY_NAME_MISSING"),
+        (2244, "MQRC_INCONSISTENT_ENCODINGS"),
+        (2409, "MQRC_SSL_KEY_RESET_ERROR"),
+        (3020, "MQRCCF_PARM_COUNT_TOO_BIG"),
+        (2216, "MQRC_FILE_NOT_AUDITED"),
+        (2329, "MQRC_SYSTEM_ITEM_NOT_DELETABLE"),
+        (3240, "MQRCCF_CFGR_PARM_ID_ERROR"),
+        (3170, "MQRCCF_PROFILE_NAME_ERROR"),
+        (3315, "MQRCCF_HOBJ_ERROR"),
+        (2367, "MQRC_CONFIG_CREATE_OBJECT"),
+        (2045, "MQRC_OPTION_NOT_VALID_FOR_TYPE"),
+        (2146, "MQRC_TARGET_BUFFER_ERROR"),
+        (2421, "MQRC_RFH_FORMAT_ERROR"),
+        (2376, "MQRC_API_EXIT_TERM_ERROR"),
+        (4048, "MQRCCF_SEND_EXIT_NAME_ERROR"),
+        (2480, "MQRC_ALIAS_TARGTYPE_CHANGED"),
+        (2070, "MQRC_SIGNAL_REQUEST_ACCEPTED"),
+        (4049, "MQRCCF_SEC_EXIT_NAME_ERROR"),
+        (6118, "MQRC_NEGATIVE_OFFSET"),
+        (2080, "MQRC_TRUNCATED_MSG_FAILED"),
+        (3066, "MQRCCF_CFSL_DUPLICATE_PARM"),
+        (2579, "MQRC_SUBSCRIPTION_CREATE"),
+        (6100, "MQRC_REOPEN_EXCL_INPUT_ERROR"),
+        (3082, "MQRCCF_UNKNOWN_STREAM"),
+        (3084, "MQRCCF_PUB_OPTIONS_ERROR"),
+        (2004, "MQRC_BUFFER_ERROR"),
+        (2309, "MQRC_SELECTOR_NOT_PRESENT"),
+        (2041, "MQRC_OBJECT_CHANGED"),
+        (3215, "MQRCCF_NAMELIST_ERROR"),
+        (2256, "MQRC_WRONG_GMO_VERSION"),
+        (2443, "MQRC_SEGMENTATION_NOT_ALLOWED"),
+        (2121, "MQRC_NO_EXTERNAL_PARTICIPANTS"),
+        (2087, "MQRC_UNKNOWN_REMOTE_Q_MGR"),
+        (2582, "MQRC_SUBSCRIPTION_REFRESH"),
+        (2486, "MQRC_CALLBACK_ROUTINE_ERROR"),
+        (2353, "MQRC_HANDLE_IN_USE_FOR_UOW"),
+        (6117, "MQRC_NEGATIVE_LENGTH"),
+        (2546, "MQRC_RECONNECT_QMID_MISMATCH"),
+        (2599, "MQRC_AMQP_NOT_AVAILABLE"),
+        (2396, "MQRC_SSL_NOT_ALLOWED"),
+        (2112, "MQRC_SOURCE_INTEGER_ENC_ERROR"),
+        (2158, "MQRC_PMO_RECORD_FLAGS_ERROR"),
+        (3067, "MQRCCF_CFSL_TOTAL_LENGTH_ERROR"),
+        (3001, "MQRCCF_CFH_TYPE_ERROR"),
+        (3312, "MQRCCF_UNKNOWN_OBJECT_NAME"),
+        (4040, "MQRCCF_COMMIT_FAILED"),
+        (2483, "MQRC_CALLBACK_TYPE_ERROR"),
+        (2602, "MQRC_BNO_ERROR"),
+        (2284, "MQRC_CHANNEL_CONV_ERROR"),
+        (2526, "MQRC_RETAINED_NOT_DELIVERED"),
+        (3356, "MQRCCF_BACKLOG_OUT_OF_RANGE"),
+        (2057, "MQRC_Q_TYPE_ERROR"),
+        (2281, "MQRC_FUNCTION_ERROR"),
+        (2186, "MQRC_GMO_ERROR"),
+        (4080, "MQRCCF_CHAD_WRONG_TYPE"),
+        (2072, "MQRC_SYNCPOINT_NOT_AVAILABLE"),
+        (2157, "MQRC_ASID_MISMATCH"),
+        (6120, "MQRC_INCONSISTENT_OBJECT_STATE"),
+        (2064, "MQRC_TOKEN_TIMESTAMP_NOT_VALID"),
+        (2225, "MQRC_Q_DEPTH_LOW"),
+        (3055, "MQRCCF_REPOS_VALUE_ERROR"),
+        (3094, "MQRCCF_BROKER_COMMAND_FAILED"),
+        (4090, "MQRCCF_CHANNEL_CLOSED"),
+        (3031, "MQRCCF_PING_DATA_COUNT_ERROR"),
+        (3047, "MQRCCF_CFIL_PARM_ID_ERROR"),
+        (2580, "MQRC_SUBSCRIPTION_DELETE"),
+        (2094, "MQRC_NOT_OPEN_FOR_PASS_IDENT"),
+        (2485, "MQRC_MAX_MSG_LENGTH_ERROR"),
+        (4058, "MQRCCF_LONG_TIMER_WRONG_TYPE"),
+        (2575, "MQRC_PRECONN_EXIT_ERROR"),
+        (2135, "MQRC_DH_ERROR"),
+        (2086, "MQRC_UNKNOWN_OBJECT_Q_MGR"),
+        (2574, "MQRC_PRECONN_EXIT_NOT_FOUND"),
+        (2516, "MQRC_SELECTOR_INVALID_FOR_TYPE"),
+        (2357, "MQRC_CURRENT_RECORD_ERROR"),
+        (2137, "MQRC_OPEN_FAILED"),
+        (3350, "MQRCCF_CHLAUTH_RUNCHECK_ERROR"),
+        (2061, "MQRC_REPORT_OPTIONS_ERROR"),
+        (2311, "MQRC_STRING_TRUNCATED"),
+        (2029, "MQRC_MSG_TYPE_ERROR"),
+        (2411, "MQRC_LOGGER_STATUS"),
+        (3300, "MQRCCF_TOPICSTR_ALREADY_EXISTS"),
+        (2299, "MQRC_SELECTOR_TYPE_ERROR"),
+        (4038, "MQRCCF_CHANNEL_DISABLED"),
+        (6109, "MQRC_NO_CONNECTION_REFERENCE"),
+        (4034, "MQRCCF_REMOTE_QM_UNAVAILABLE"),
+        (2012, "MQRC_ENVIRONMENT_ERROR"),
+        (2007, "MQRC_CHAR_ATTRS_ERROR"),
+        (2191, "MQRC_TMC_ERROR"),
+        (2578, "MQRC_CHANNEL_BLOCKED_WARNING"),
+        (2332, "MQRC_MISSING_WIH"),
+        (4060, "MQRCCF_KEEP_ALIVE_INT_ERROR"),
+        (3361, "MQRCCF_SSL_CIPHER_SUITE_ERROR"),
+        (2136, "MQRC_MULTIPLE_REASONS"),
+        (3332, "MQRCCF_POLICY_VERSION_ERROR"),
+        (3341, "MQRCCF_CHLAUTH_WARN_ERROR"),
+        (3065, "MQRCCF_CHL_STATUS_NOT_FOUND"),
+        (2514, "MQRC_DUPLICATE_GROUP_SUB"),
+        (3261, "MQRCCF_SERVICE_REQUEST_PENDING"),
+        (2428, "MQRC_NO_SUBSCRIPTION"),
+        (2322, "MQRC_CMD_SERVER_NOT_AVAILABLE"),
+        (2350, "MQRC_CONN_TAG_NOT_USABLE"),
+        (4041, "MQRCCF_WRONG_CHANNEL_TYPE"),
+        (4064, "MQRCCF_CHANNEL_NOT_ACTIVE"),
+        (2563, "MQRC_GROUP_ADDRESS_ERROR"),
+        (2236, "MQRC_CFIL_ERROR"),
+        (3239, "MQRCCF_NO_XCF_PARTNER"),
+        (2577, "MQRC_CHANNEL_BLOCKED"),
+        (6102, "MQRC_REOPEN_SAVED_CONTEXT_ERR"),
+        (3073, "MQRCCF_NOT_REGISTERED"),
+        (2386, "MQRC_AUTH_INFO_TYPE_ERROR"),
+        (2133, "MQRC_ADAPTER_CONV_LOAD_ERROR"),
+        (4068, "MQRCCF_CELL_DIR_NOT_AVAILABLE"),
+        (2303, "MQRC_BAG_CONVERSION_ERROR"),
+        (2464, "MQRC_IMPO_ERROR"),
+        (2220, "MQRC_RMH_ERROR"),
+        (3027, "MQRCCF_CFIL_COUNT_ERROR"),
+        (2199, "MQRC_DEF_XMIT_Q_USAGE_ERROR"),
+        (3249, "MQRCCF_LISTENER_RUNNING"),
+        (2250, "MQRC_MSG_SEQ_NUMBER_ERROR"),
+        (2419, "MQRC_NESTED_SELECTOR_ERROR"),
+        (2134, "MQRC_BO_ERROR"),
+        (2375, "MQRC_API_EXIT_INIT_ERROR"),
+        (3040, "MQRCCF_SHORT_TIMER_ERROR"),
+        (2042, "MQRC_OBJECT_IN_USE"),
+        (2389, "MQRC_LDAP_USER_NAME_LENGTH_ERR"),
+        (2079, "MQRC_TRUNCATED_MSG_ACCEPTED"),
+        (2379, "MQRC_NO_DATA_AVAILABLE"),
+        (4065, "MQRCCF_TERMINATED_BY_SEC_EXIT"),
+        (3165, "MQRCCF_ALLOC_SLOW_TIMER_ERROR"),
+        (3250, "MQRCCF_LSTR_STATUS_NOT_FOUND"),
+        (2261, "MQRC_SRC_ENV_ERROR"),
+        (2268, "MQRC_CLUSTER_PUT_INHIBITED"),
+        (4017, "MQRCCF_CONNECTION_CLOSED"),
+        (3324, "MQRCCF_PORT_IN_USE"),
+        (3357, "MQRCCF_CHLAUTH_DISABLED"),
+        (2418, "MQRC_FILTER_OPERATOR_ERROR"),
+        (2308, "MQRC_ENCODING_NOT_SUPPORTED"),
+        (2334, "MQRC_RFH_ERROR"),
+        (2522, "MQRC_INVALID_DESTINATION"),
+        (3009, "MQRCCF_CFIN_LENGTH_ERROR"),
+        (3207, "MQRCCF_STORAGE_CLASS_IN_USE"),
+        (2586, "MQRC_PROPERTIES_DISABLED"),
+        (2540, "MQRC_UNKNOWN_CHANNEL_NAME"),
+        (2510, "MQRC_TOPIC_NOT_ALTERABLE"),
+        (2587, "MQRC_HMSG_NOT_AVAILABLE"),
+        (3301, "MQRCCF_SHARING_CONVS_ERROR"),
+        (2252, "MQRC_ORIGINAL_LENGTH_ERROR"),
+        (2234, "MQRC_CHANNEL_AUTO_DEF_ERROR"),
+        (2246, "MQRC_INVALID_MSG_UNDER_CURSOR"),
+        (2071, "MQRC_STORAGE_NOT_AVAILABLE"),
+        (2198, "MQRC_DEF_XMIT_Q_TYPE_ERROR"),
+        (2104, "MQRC_UNKNOWN_REPORT_OPTION"),
+        (2394, "MQRC_Q_INDEX_TYPE_ERROR"),
+        (2302, "MQRC_SYSTEM_ITEM_NOT_ALTERABLE"),
+        (2557, "MQRC_PUBLISH_EXIT_ERROR"),
+        (2253, "MQRC_SEGMENT_LENGTH_ZERO"),
+        (3339, "MQRCCF_WRONG_CHLAUTH_ACTION"),
+        (2173, "MQRC_PMO_ERROR"),
+        (2044, "MQRC_OD_ERROR"),
+        (3175, "MQRCCF_LOG_TYPE_ERROR"),
+        (2567, "MQRC_MULTICAST_INTERNAL_ERROR"),
+        (4005, "MQRCCF_ATTR_VALUE_ERROR"),
+        (3363, "MQRCCF_CLUS_XMIT_Q_USAGE_ERROR"),
+        (2568, "MQRC_CONNECTION_NOT_AVAILABLE"),
+        (3087, "MQRCCF_DEL_OPTIONS_ERROR"),
+        (2496, "MQRC_MODULE_INVALID"),
+        (3311, "MQRCCF_SUB_ALREADY_EXISTS"),
+        (3151, "MQRCCF_WRONG_USER"),
+        (2043, "MQRC_OBJECT_TYPE_ERROR"),
+        (6129, "MQRC_REFERENCE_ERROR"),
+        (3328, "MQRCCF_POLICY_NOT_FOUND"),
+        (4033, "MQRCCF_UNKNOWN_REMOTE_CHANNEL"),
+        (3385, "MQRCCF_STREAMQ_DEST_CONFLICT"),
+        (4050, "MQRCCF_MSG_EXIT_NAME_ERROR"),
+        (4097, "MQRCCF_APPL_STATUS_NOT_FOUND"),
+        (2323, "MQRC_STRING_LENGTH_ERROR"),
+        (3156, "MQRCCF_SUBSCRIPTION_LOCKED"),
+        (2103, "MQRC_ANOTHER_Q_MGR_CONNECTED"),
+        (2339, "MQRC_RFH_PARM_MISSING"),
+        (2482, "MQRC_PD_ERROR"),
+        (2210, "MQRC_SOAP_DOTNET_ERROR"),
+        (2402, "MQRC_SSL_CERT_STORE_ERROR"),
+        (2031, "MQRC_MSG_TOO_BIG_FOR_Q_MGR"),
+        (2038, "MQRC_NOT_OPEN_FOR_INQUIRE"),
+        (4006, "MQRCCF_UNKNOWN_Q_MGR"),
+        (4018, "MQRCCF_NO_STORAGE"),
+        (3267, "MQRCCF_CFBF_FILTER_VAL_LEN_ERR"),
+        (3025, "MQRCCF_REPLACE_VALUE_ERROR"),
+        (2016, "MQRC_GET_INHIBITED"),
+        (3251, "MQRCCF_SERVICE_RUNNING"),
+        (2184, "MQRC_REMOTE_Q_NAME_ERROR"),
+        (2005, "MQRC_BUFFER_LENGTH_ERROR"),
+        (3150, "MQRCCF_FILTER_ERROR"),
+        (2188, "MQRC_STOPPED_BY_CLUSTER_EXIT"),
+        (3168, "MQRCCF_CHL_SYSTEM_NOT_ACTIVE"),
+        (3229, "MQRCCF_PARM_VALUE_ERROR"),
+        (3319, "MQRCCF_GROUPUR_CHECKS_FAILED"),
+        (3079, "MQRCCF_INCORRECT_Q"),
+        (3097, "MQRCCF_PARM_SYNTAX_ERROR"),
+        (2566, "MQRC_MULTICAST_SEND_ERROR"),
+        (2295, "MQRC_CHANNEL_ACTIVATED"),
+        (2069, "MQRC_SIGNAL_OUTSTANDING"),
+        (2297, "MQRC_UOW_CANCELED"),
+        (3354, "MQRCCF_CHANNEL_NOT_STARTED"),
+        (2122, "MQRC_PARTICIPANT_NOT_AVAILABLE"),
+        (2437, "MQRC_NO_RETAINED_MSG"),
+        (2155, "MQRC_OBJECT_RECORDS_ERROR"),
+        (2066, "MQRC_SELECTOR_LIMIT_EXCEEDED"),
+        (2438, "MQRC_SRO_ERROR"),
+        (3256, "MQRCCF_CFBS_PARM_ID_ERROR"),
+        (2337, "MQRC_RFH_PARM_ERROR"),
+        (2153, "MQRC_OBJECT_Q_MGR_NAME_ERROR"),
+        (3348, "MQRCCF_CHLAUTH_CLNTUSER_ERROR"),
+        (3242, "MQRCCF_CFIF_OPERATOR_ERROR"),
+        (2182, "MQRC_API_EXIT_NOT_FOUND"),
+        (6114, "MQRC_INSUFFICIENT_DATA"),
+        (3203, "MQRCCF_PARM_CONFLICT"),
+        (2554, "MQRC_CONTENT_ERROR"),
+        (3022, "MQRCCF_Q_TYPE_ERROR"),
+        (4055, "MQRCCF_SHORT_RETRY_WRONG_TYPE"),
+        (2156, "MQRC_RESPONSE_RECORDS_ERROR"),
+        (2562, "MQRC_GROUPING_NOT_ALLOWED"),
+        (4031, "MQRCCF_CHANNEL_IN_USE"),
+        (3309, "MQRCCF_SUBSCRIPTION_POINT_ERR"),
+        (3155, "MQRCCF_SUBSCRIPTION_IN_USE"),
+        (3230, "MQRCCF_COMMAND_LENGTH_ERROR"),
+        (3164, "MQRCCF_ALLOC_RETRY_ERROR"),
+        (3263, "MQRCCF_NO_STOP_CMD"),
+        (2100, "MQRC_OBJECT_ALREADY_EXISTS"),
+        (3018, "MQRCCF_CFST_DUPLICATE_PARM"),
+        (2224, "MQRC_Q_DEPTH_HIGH"),
+        (2340, "MQRC_CHAR_CONVERSION_ERROR"),
+        (3372, "MQRCCF_Q_MGR_ATTR_CONFLICT"),
+        (3088, "MQRCCF_CLUSTER_NAME_CONFLICT"),
+        (2291, "MQRC_USER_ID_NOT_AVAILABLE"),
+        (3063, "MQRCCF_MCA_TYPE_ERROR"),
+        (4091, "MQRCCF_Q_STATUS_NOT_FOUND"),
+        (3232, "MQRCCF_LISTENER_CONFLICT"),
+        (2442, "MQRC_PROPERTY_NAME_ERROR"),
+        (3096, "MQRCCF_PATH_NOT_VALID"),
+        (2062, "MQRC_SECOND_MARK_NOT_ALLOWED"),
+        (4043, "MQRCCF_DATA_TOO_LARGE"),
+        (3004, "MQRCCF_CFH_MSG_SEQ_NUMBER_ERR"),
+        (2065, "MQRC_SELECTOR_COUNT_ERROR"),
+        (2417, "MQRC_MSG_NOT_ALLOWED_IN_GROUP"),
+        (2018, "MQRC_HCONN_ERROR"),
+        (4014, "MQRCCF_SEND_FAILED"),
+        (2217, "MQRC_CONNECTION_NOT_AUTHORIZED"),
+        (3029, "MQRCCF_QUIESCE_VALUE_ERROR"),
+        (2003, "MQRC_BACKED_OUT"),
+        (6113, "MQRC_INSUFFICIENT_BUFFER"),
+        (2384, "MQRC_AUTH_INFO_REC_ERROR"),
+        (2019, "MQRC_HOBJ_ERROR"),
+        (3021, "MQRCCF_Q_ALREADY_IN_CELL"),
+        (3314, "MQRCCF_DURABILITY_NOT_ALLOWED"),
+        (2600, "MQRC_CCDT_URL_ERROR"),
+        (3262, "MQRCCF_NO_START_CMD"),
+        (2331, "MQRC_MSG_TOKEN_ERROR"),
+        (2218, "MQRC_MSG_TOO_BIG_FOR_CHANNEL"),
+        (2406, "MQRC_CLIENT_EXIT_LOAD_ERROR"),
+        (2422, "MQRC_CFBF_ERROR"),
+        (3367, "MQRCCF_CLROUTE_NOT_ALTERABLE"),
+        (4039, "MQRCCF_USER_EXIT_NOT_AVAILABLE"),
+        (2358, "MQRC_NEXT_OFFSET_ERROR"),
+        (3371, "MQRCCF_CERT_LABEL_NOT_ALLOWED"),
+        (4072, "MQRCCF_MR_EXIT_NAME_WRONG_TYPE"),
+        (6115, "MQRC_DATA_TRUNCATED"),
+        (2305, "MQRC_SELECTOR_NOT_UNIQUE"),
+        (2324, "MQRC_INQUIRY_COMMAND_ERROR"),
+        (2010, "MQRC_DATA_LENGTH_ERROR"),
+        (3016, "MQRCCF_MSG_LENGTH_ERROR"),
+        (4032, "MQRCCF_CHANNEL_NOT_FOUND"),
+        (3381, "MQRCCF_LOG_EXTENT_ERROR"),
+        (4024, "MQRCCF_BIND_FAILED"),
+        (2509, "MQRC_DURABILITY_NOT_ALTERABLE"),
+        (2013, "MQRC_EXPIRY_ERROR"),
+        (4073, "MQRCCF_MR_INTERVAL_ERROR"),
+        (3049, "MQRCCF_CCSID_ERROR"),
+        (2313, "MQRC_INCONSISTENT_ITEM_TYPE"),
+        (2478, "MQRC_PROPERTIES_TOO_BIG"),
+        (4004, "MQRCCF_OBJECT_OPEN"),
+        (3380, "MQRCCF_LOG_NOT_REDUCED"),
+        (3364, "MQRCCF_CERT_VAL_POLICY_ERROR"),
+        (2292, "MQRC_UNKNOWN_ENTITY"),
+        (2033, "MQRC_NO_MSG_AVAILABLE"),
+        (3359, "MQRCCF_PSCLUS_DISABLED_TOPDEF"),
+        (2021, "MQRC_INT_ATTR_COUNT_ERROR"),
+        (2381, "MQRC_KEY_REPOSITORY_ERROR"),
+        (2203, "MQRC_CONNECTION_STOPPING"),
+        (2572, "MQRC_MCAST_SUB_STATUS"),
+        (2154, "MQRC_RECS_PRESENT_ERROR"),
+        (3259, "MQRCCF_CFGR_PARM_COUNT_ERROR"),
+        (3042, "MQRCCF_LONG_TIMER_ERROR"),
+        (2589, "MQRC_INSTALLATION_MISSING"),
+        (2001, "MQRC_ALIAS_BASE_Q_TYPE_ERROR"),
+        (3033, "MQRCCF_CFSL_PARM_ID_ERROR"),
+        (2326, "MQRC_BAG_WRONG_TYPE"),
+        (4074, "MQRCCF_MR_INTERVAL_WRONG_TYPE"),
+        (2108, "MQRC_XWAIT_ERROR"),
+        (2420, "MQRC_EPH_ERROR"),
+        (2399, "MQRC_SSL_PEER_NAME_ERROR"),
+        (6104, "MQRC_ATTRIBUTE_LOCKED"),
+        (4011, "MQRCCF_CONFIGURATION_ERROR"),
+        (3166, "MQRCCF_ALLOC_FAST_TIMER_ERROR"),
+        (2469, "MQRC_PROPERTY_VALUE_TOO_BIG"),
+        (3177, "MQRCCF_PROGRAM_AUTH_FAILED"),
+        (2408, "MQRC_UOW_COMMITTED"),
+        (3345, "MQRCCF_ADDRESS_ERROR"),
+        (2111, "MQRC_SOURCE_CCSID_ERROR"),
+        (2569, "MQRC_SYNCPOINT_NOT_ALLOWED"),
+        (2487, "MQRC_CALLBACK_LINK_ERROR"),
+        (2132, "MQRC_ADAPTER_DEFS_LOAD_ERROR"),
+        (2503, "MQRC_SUB_INHIBITED"),
+        (2237, "MQRC_CFIN_ERROR"),
+        (3265, "MQRCCF_CFBF_PARM_ID_ERROR"),
+        (6112, "MQRC_BUFFER_NOT_AUTOMATIC"),
+        (2356, "MQRC_WXP_ERROR"),
+        (2099, "MQRC_SIGNAL1_ERROR"),
+        (4047, "MQRCCF_MCA_NAME_ERROR"),
+        (2598, "MQRC_ADMIN_TOPIC_STRING_ERROR"),
+        (4063, "MQRCCF_MQSET_FAILED"),
+        (3238, "MQRCCF_UNEXPECTED_ERROR"),
+        (2024, "MQRC_SYNCPOINT_LIMIT_REACHED"),
+        (2037, "MQRC_NOT_OPEN_FOR_INPUT"),
+        (2046, "MQRC_OPTIONS_ERROR"),
+        (2189, "MQRC_CLUSTER_RESOLUTION_ERROR"),
+        (3051, "MQRCCF_QUEUES_VALUE_ERROR"),
+        (2304, "MQRC_SELECTOR_OUT_OF_RANGE"),
+        (4070, "MQRCCF_MR_COUNT_WRONG_TYPE"),
+        (2014, "MQRC_FEEDBACK_ERROR"),
+        (2561, "MQRC_DATA_SET_NOT_AVAILABLE"),
+        (3366, "MQRCCF_REVDNS_DISABLED"),
+        (2296, "MQRC_CHANNEL_NOT_ACTIVATED"),
+        (2581, "MQRC_SUBSCRIPTION_CHANGE"),
+        (3331, "MQRCCF_TOLERATION_POL_ERROR"),
+        (3035, "MQRCCF_PARM_SEQUENCE_ERROR"),
+        (2059, "MQRC_Q_MGR_NOT_AVAILABLE"),
+        (2355, "MQRC_UOW_MIX_NOT_SUPPORTED"),
+        (2423, "MQRC_CLIENT_CHANNEL_CONFLICT"),
+        (2502, "MQRC_PUBLICATION_FAILURE"),
+        (2541, "MQRC_LOOPING_PUBLICATION"),
+        (2499, "MQRC_MSG_HANDLE_IN_USE"),
+        (3228, "MQRCCF_PARM_MISSING"),
+        (2470, "MQRC_PROP_CONV_NOT_SUPPORTED"),
+        (2229, "MQRC_RAS_PROPERTY_ERROR"),
+        (2519, "MQRC_SELECTION_STRING_ERROR"),
+        (3045, "MQRCCF_PUT_AUTH_ERROR"),
+        (3342, "MQRCCF_WRONG_CHLAUTH_MATCH"),
+        (4076, "MQRCCF_NPM_SPEED_WRONG_TYPE"),
+        (3074, "MQRCCF_Q_MGR_NAME_ERROR"),
+        (2436, "MQRC_DURABILITY_NOT_ALLOWED"),
+        (2481, "MQRC_DMPO_ERROR"),
+        (2314, "MQRC_INDEX_ERROR"),
+        (3013, "MQRCCF_STRUCTURE_TYPE_ERROR"),
+        (3336, "MQRCCF_WRONG_CHLAUTH_TYPE"),
+        (3269, "MQRCCF_DEF_XMIT_Q_CLUS_ERROR"),
+        (4077, "MQRCCF_HB_INTERVAL_ERROR"),
+        (2102, "MQRC_RESOURCE_PROBLEM"),
+        (3222, "MQRCCF_COMMAND_LEVEL_CONFLICT"),
+        (2415, "MQRC_CFSF_ERROR"),
+        (2264, "MQRC_DEST_NAME_ERROR"),
+        (2093, "MQRC_NOT_OPEN_FOR_PASS_ALL"),
+        (3173, "MQRCCF_OBJECT_TYPE_MISSING"),
+        (4082, "MQRCCF_CHAD_EVENT_WRONG_TYPE"),
+        (3374, "MQRCCF_CLWL_EXIT_NAME_ERROR"),
+        (2603, "MQRC_OUTBOUND_SNI_NOT_VALID"),
+        (2204, "MQRC_ADAPTER_NOT_AVAILABLE"),
+        (2318, "MQRC_SELECTOR_NOT_SUPPORTED"),
+        (3375, "MQRCCF_SERVICE_NAME_ERROR"),
+        (2558, "MQRC_COMMINFO_ERROR"),
+        (3362, "MQRCCF_SOCKET_ERROR"),
+        (2109, "MQRC_SUPPRESSED_BY_EXIT"),
+        (3034, "MQRCCF_CHANNEL_TYPE_ERROR"),
+        (3161, "MQRCCF_UNKNOWN_FILE_NAME"),
+        (2556, "MQRC_RECONNECT_TIMED_OUT"),
+        (4009, "MQRCCF_ALLOCATE_FAILED"),
+        (2212, "MQRC_SOAP_URL_ERROR"),
+        (3224, "MQRCCF_EVENTS_DISABLED"),
+        (2145, "MQRC_SOURCE_BUFFER_ERROR"),
+        (3322, "MQRCCF_CLIENT_ID_NOT_FOUND"),
+        (2306, "MQRC_INDEX_NOT_PRESENT"),
+        (2238, "MQRC_CFSL_ERROR"),
+        (4019, "MQRCCF_NO_COMMS_MANAGER"),
+        (2344, "MQRC_CONN_TAG_NOT_RELEASED"),
+        (2209, "MQRC_NO_MSG_LOCKED"),
+        (3254, "MQRCCF_CFBS_DUPLICATE_PARM"),
+        (4010, "MQRCCF_HOST_NOT_AVAILABLE"),
+        (3383, "MQRCCF_PS_REQUIRED_MQUC"),
+        (2393, "MQRC_SSL_INITIALIZATION_ERROR"),
+        (2211, "MQRC_SOAP_AXIS_ERROR"),
+        (2559, "MQRC_DEF_SYNCPOINT_INHIBITED"),
+        (2055, "MQRC_Q_NOT_EMPTY"),
+        (3069, "MQRCCF_CFSL_STRING_LENGTH_ERR"),
+        (2293, "MQRC_UNKNOWN_AUTH_ENTITY"),
+        (2330, "MQRC_CODED_CHAR_SET_ID_ERROR"),
+        (2249, "MQRC_MSG_FLAGS_ERROR"),
+        (3041, "MQRCCF_LONG_RETRY_ERROR"),
+        (2529, "MQRC_ASYNC_UOW_CONFLICT"),
+        (2364, "MQRC_JMS_FORMAT_ERROR"),
+        (3007, "MQRCCF_CFH_COMMAND_ERROR"),
+        (2374, "MQRC_API_EXIT_ERROR"),
+        (2273, "MQRC_CONNECTION_ERROR"),
+        (2092, "MQRC_XMIT_Q_USAGE_ERROR"),
+        (2378, "MQRC_RESERVED_VALUE_ERROR"),
+        (2208, "MQRC_FILE_SYSTEM_ERROR"),
+        (2197, "MQRC_UNKNOWN_DEF_XMIT_Q"),
+        (2590, "MQRC_FASTPATH_NOT_AVAILABLE"),
+        (2370, "MQRC_CONFIG_REFRESH_OBJECT"),
+        (2321, "MQRC_PARAMETER_MISSING"),
+        (2387, "MQRC_AUTH_INFO_CONN_NAME_ERROR"),
+        (2067, "MQRC_SELECTOR_ERROR"),
+        (2320, "MQRC_HBAG_ERROR"),
+        (2444, "MQRC_CBD_ERROR"),
+        (3030, "MQRCCF_MSG_SEQ_NUMBER_ERROR"),
+        (3330, "MQRCCF_SIGNATURE_ALG_ERROR"),
+        (2360, "MQRC_OBJECT_LEVEL_INCOMPATIBLE"),
+        (2538, "MQRC_HOST_NOT_AVAILABLE"),
+        (3085, "MQRCCF_UNKNOWN_BROKER"),
+        (2351, "MQRC_GLOBAL_UOW_CONFLICT"),
+        (2531, "MQRC_PUBSUB_INHIBITED"),
+        (3091, "MQRCCF_ACTION_VALUE_ERROR"),
+        (3355, "MQRCCF_CUSTOM_ERROR"),
+        (2118, "MQRC_TARGET_FLOAT_ENC_ERROR"),
+        (2117, "MQRC_TARGET_DECIMAL_ENC_ERROR"),
+        (2119, "MQRC_NOT_CONVERTED"),
+        (4015, "MQRCCF_RECEIVED_DATA_ERROR"),
+        (2341, "MQRC_UCS2_CONVERSION_ERROR"),
+        (4087, "MQRCCF_BATCH_INT_WRONG_TYPE"),
+        (2501, "MQRC_MHBO_ERROR"),
+        (2518, "MQRC_HOBJ_QUIESCED_NO_MSGS"),
+        (2053, "MQRC_Q_FULL"),
+        (2050, "MQRC_PRIORITY_ERROR"),
+        (3162, "MQRCCF_FILE_NOT_AVAILABLE"),
+        (2398, "MQRC_SSL_PEER_NAME_MISMATCH"),
+        (2058, "MQRC_Q_MGR_NAME_ERROR"),
+        (3062, "MQRCCF_CHANNEL_TABLE_ERROR"),
+        (3257, "MQRCCF_CFBS_STRING_LENGTH_ERR"),
+        (2513, "MQRC_PROPERTY_NAME_LENGTH_ERR"),
+        (2228, "MQRC_RFH_HEADER_FIELD_ERROR"),
+        (2128, "MQRC_UOW_IN_PROGRESS"),
+        (4094, "MQRCCF_SSL_CLIENT_AUTH_ERROR"),
+        (3226, "MQRCCF_COMMAND_REPLY_ERROR"),
+        (2463, "MQRC_SMPO_ERROR"),
+        (2400, "MQRC_UNSUPPORTED_CIPHER_SUITE"),
+        (2373, "MQRC_CF_STRUC_FAILED"),
+        (2307, "MQRC_STRING_ERROR"),
+        (2076, "MQRC_TRIGGER_DEPTH_ERROR"),
+        (2278, "MQRC_CLIENT_CONN_ERROR"),
+        (2576, "MQRC_CD_ARRAY_ERROR"),
+        (6103, "MQRC_REOPEN_TEMPORARY_Q_ERROR"),
+        (6116, "MQRC_ZERO_LENGTH"),
+        (2432, "MQRC_SUB_ALREADY_EXISTS"),
+        (2260, "MQRC_XQH_ERROR"),
+        (2294, "MQRC_UNKNOWN_REF_OBJECT"),
+        (2413, "MQRC_COMMAND_PCF"),
+        (2206, "MQRC_MSG_ID_ERROR"),
+        (2445, "MQRC_CTLO_ERROR"),
+        (2372, "MQRC_PARTICIPANT_NOT_DEFINED"),
+        (2207, "MQRC_CORREL_ID_ERROR"),
+        (2025, "MQRC_MAX_CONNS_LIMIT_REACHED"),
+        (2584, "MQRC_NOT_PRIVILEGED"),
+        (3302, "MQRCCF_SHARING_CONVS_TYPE"),
+        (3204, "MQRCCF_COMMAND_INHIBITED"),
+        (3387, "MQRCCF_STREAMQ_CONFLICT"),
+        (2349, "MQRC_CF_STRUC_ERROR"),
+        (2196, "MQRC_UNKNOWN_XMIT_Q"),
+        (2467, "MQRC_PROP_TYPE_NOT_SUPPORTED"),
+        (2006, "MQRC_CHAR_ATTR_LENGTH_ERROR"),
+        (3090, "MQRCCF_CLUSTER_Q_USAGE_ERROR"),
+        (3317, "MQRCCF_INVALID_DESTINATION"),
+        (3329, "MQRCCF_ENCRYPTION_ALG_ERROR"),
+        (2017, "MQRC_HANDLE_NOT_AVAILABLE"),
+        (2195, "MQRC_UNEXPECTED_ERROR"),
+        (2573, "MQRC_PRECONN_EXIT_LOAD_ERROR"),
+        (2564, "MQRC_MULTICAST_CONFIG_ERROR"),
+        (2138, "MQRC_ADAPTER_DISC_LOAD_ERROR"),
+        (3266, "MQRCCF_CFBF_OPERATOR_ERROR"),
+        (2096, "MQRC_NOT_OPEN_FOR_SET_IDENT"),
+        (2026, "MQRC_MD_ERROR"),
+        (2101, "MQRC_OBJECT_DAMAGED"),
+        (2555, "MQRC_RECONNECT_Q_MGR_REQD"),
+        (2345, "MQRC_CF_NOT_AVAILABLE"),
+        (2426, "MQRC_STS_ERROR"),
+        (2141, "MQRC_DLH_ERROR"),
+        (3247, "MQRCCF_CFSF_PARM_ID_ERROR"),
+        (2283, "MQRC_CHANNEL_STOPPED"),
+        (2473, "MQRC_PROPERTY_TYPE_ERROR"),
+        (3176, "MQRCCF_PROGRAM_NOT_AVAILABLE"),
+        (2490, "MQRC_UNSUPPORTED_PROPERTY"),
+        (3369, "MQRCCF_DEFCLXQ_MODEL_Q_ERROR"),
+        (2068, "MQRC_SELECTOR_NOT_FOR_TYPE"),
+        (2535, "MQRC_ACTION_ERROR"),
+        (2201, "MQRC_NAME_IN_USE"),
+        (2243, "MQRC_INCONSISTENT_CCSIDS"),
+        (2193, "MQRC_PAGESET_ERROR"),
+        (3313, "MQRCCF_REMOTE_Q_NAME_ERROR"),
+        (2027, "MQRC_MISSING_REPLY_TO_Q"),
+        (2222, "MQRC_Q_MGR_ACTIVE"),
+        (4027, "MQRCCF_MQOPEN_FAILED"),
+        (4083, "MQRCCF_CHAD_EXIT_ERROR"),
+        (3028, "MQRCCF_CFIL_LENGTH_ERROR"),
+        (3343, "MQRCCF_IPADDR_RANGE_CONFLICT"),
+        (4075, "MQRCCF_NPM_SPEED_ERROR"),
+        (2361, "MQRC_NEXT_RECORD_ERROR"),
+        (3200, "MQRCCF_NONE_FOUND"),
+        (3205, "MQRCCF_OBJECT_BEING_DELETED"),
+        (4071, "MQRCCF_MR_EXIT_NAME_ERROR"),
+        (3349, "MQRCCF_CHLAUTH_NAME_ERROR"),
+        (3255, "MQRCCF_CFBS_LENGTH_ERROR"),
+        (3012, "MQRCCF_FORCE_VALUE_ERROR"),
+        (2460, "MQRC_HMSG_ERROR"),
+        (2159, "MQRC_PUT_MSG_RECORDS_ERROR"),
+        (3089, "MQRCCF_REPOS_NAME_CONFLICT"),
+        (4095, "MQRCCF_RETAINED_NOT_SUPPORTED"),
+        (4016, "MQRCCF_RECEIVE_FAILED"),
+        (2226, "MQRC_Q_SERVICE_INTERVAL_HIGH"),
+        (3078, "MQRCCF_DUPLICATE_IDENTITY"),
+        (2327, "MQRC_ITEM_TYPE_ERROR"),
+        (3253, "MQRCCF_SERVICE_STOPPED"),
+        (4036, "MQRCCF_MQINQ_FAILED"),
+        (2263, "MQRC_DEST_ENV_ERROR"),
+        (4057, "MQRCCF_LONG_RETRY_WRONG_TYPE"),
+        (3248, "MQRCCF_TOO_MANY_FILTERS"),
+        (2440, "MQRC_SUB_NAME_ERROR"),
+        (0, "MQRC_NONE"),
+        (3014, "MQRCCF_CFIN_PARM_ID_ERROR"),
+        (2023, "MQRC_INT_ATTRS_ARRAY_ERROR"),
+        (3010, "MQRCCF_CFST_LENGTH_ERROR"),
+        (2533, "MQRC_DEST_CLASS_NOT_ALTERABLE"),
+        (4030, "MQRCCF_PING_ERROR"),
+        (2547, "MQRC_RECONNECT_INCOMPATIBLE"),
+        (4061, "MQRCCF_MISSING_CONN_NAME"),
+        (2371, "MQRC_CHANNEL_SSL_ERROR"),
+        (2298, "MQRC_FUNCTION_NOT_SUPPORTED"),
+        (2416, "MQRC_CFGR_ERROR"),
+        (3070, "MQRCCF_BROKER_DELETED"),
+        (2534, "MQRC_OPERATION_NOT_ALLOWED"),
+        (2549, "MQRC_CALL_INTERRUPTED"),
+        (2239, "MQRC_CFST_ERROR"),
+        (2127, "MQRC_ADAPTER_STORAGE_SHORTAGE"),
+        (2035, "MQRC_NOT_AUTHORIZED"),
+        (2380, "MQRC_SCO_ERROR"),
+        (4025, "MQRCCF_CHANNEL_INDOUBT"),
+        (3382, "MQRCCF_ACCESS_BLOCKED"),
+        (2002, "MQRC_ALREADY_CONNECTED"),
+        (2106, "MQRC_COD_NOT_VALID_FOR_XCF_Q"),
+        (2530, "MQRC_ASYNC_XA_CONFLICT"),
+        (2338, "MQRC_RFH_DUPLICATE_PARM"),
+        (2498, "MQRC_MIXED_CONTENT_NOT_ALLOWED"),
+        (2287, "MQRC_TERMINATION_FAILED"),
+        (2395, "MQRC_CFBS_ERROR"),
+        (2255, "MQRC_UOW_NOT_AVAILABLE"),
+        (3093, "MQRCCF_NETBIOS_NAME_ERROR"),
+        (2495, "MQRC_MODULE_NOT_FOUND"),
+        (3245, "MQRCCF_CFSF_LENGTH_ERROR"),
+        (4086, "MQRCCF_BATCH_INT_ERROR"),
+        (3340, "MQRCCF_WRONG_CHLAUTH_USERSRC"),
+        (2343, "MQRC_OBJECT_NOT_UNIQUE"),
+        (2301, "MQRC_MULTIPLE_INSTANCE_ERROR"),
+        (3163, "MQRCCF_DISC_RETRY_ERROR"),
+        (3377, "MQRCCF_TOPIC_RESTRICTED"),
+        (4013, "MQRCCF_ENTRY_ERROR"),
+        (3023, "MQRCCF_MD_FORMAT_ERROR"),
+        (6128, "MQRC_WRONG_VERSION"),
+        (2105, "MQRC_STORAGE_CLASS_ERROR"),
+        (2594, "MQRC_PASSWORD_PROTECTION_ERROR"),
+        (3017, "MQRCCF_CFIN_DUPLICATE_PARM"),
+        (2401, "MQRC_SSL_CERTIFICATE_REVOKED"),
+        (4069, "MQRCCF_MR_COUNT_ERROR"),
+        (3234, "MQRCCF_LISTENER_STOPPED"),
+        (4084, "MQRCCF_CHAD_EXIT_WRONG_TYPE"),
+        (3154, "MQRCCF_SUB_IDENTITY_ERROR"),
+        (2489, "MQRC_BMHO_ERROR"),
+        (4056, "MQRCCF_SHORT_TIMER_WRONG_TYPE"),
+        (4092, "MQRCCF_SSL_CIPHER_SPEC_ERROR"),
+        (3346, "MQRCCF_IPADDR_RANGE_ERROR"),
+        (6110, "MQRC_NO_BUFFER"),
+        (4079, "MQRCCF_CHAD_ERROR"),
+        (2465, "MQRC_PROPERTY_NAME_TOO_BIG"),
+        (2310, "MQRC_OUT_SELECTOR_ERROR"),
+        (2595, "MQRC_CSP_ERROR"),
+        (6108, "MQRC_NULL_POINTER"),
+        (3260, "MQRCCF_CONN_NOT_STOPPED"),
+        (3036, "MQRCCF_XMIT_PROTOCOL_TYPE_ERR"),
+        (2277, "MQRC_CD_ERROR"),
+        (3003, "MQRCCF_CFH_VERSION_ERROR"),
+        (2161, "MQRC_Q_MGR_QUIESCING"),
+        (3072, "MQRCCF_TOPIC_ERROR"),
+        (2565, "MQRC_MULTICAST_INTERFACE_ERROR"),
+        (6125, "MQRC_NOT_OPEN"),
+        (2219, "MQRC_CALL_IN_PROGRESS"),
+        (3098, "MQRCCF_PWD_LENGTH_ERROR"),
+        (2479, "MQRC_PUT_NOT_RETAINED"),
+        (2091, "MQRC_XMIT_Q_TYPE_ERROR"),
+        (2471, "MQRC_PROPERTY_NOT_AVAILABLE"),
+        (2410, "MQRC_UNKNOWN_COMPONENT_NAME"),
+        (3360, "MQRCCF_PSCLUS_TOPIC_EXISTS"),
+        (4078, "MQRCCF_HB_INTERVAL_WRONG_TYPE"),
+        (2288, "MQRC_UNKNOWN_Q_NAME"),
+        (2424, "MQRC_SD_ERROR"),
+        (2407, "MQRC_CLIENT_EXIT_ERROR"),
+        (6122, "MQRC_CONTEXT_OPEN_ERROR"),
+        (3378, "MQRCCF_CURRENT_LOG_EXTENT"),
+        (2459, "MQRC_SELECTOR_SYNTAX_ERROR"),
+        (2377, "MQRC_EXIT_REASON_ERROR"),
+        (2425, "MQRC_TOPIC_STRING_ERROR"),
+        (2140, "MQRC_CICS_WAIT_FAILED"),
+        (2488, "MQRC_OPERATION_ERROR"),
+        (2429, "MQRC_SUBSCRIPTION_IN_USE"),
+        (3044, "MQRCCF_MAX_MSG_LENGTH_ERROR"),
+        (3071, "MQRCCF_STREAM_ERROR"),
+        (2548, "MQRC_RECONNECT_FAILED"),
+        (3389, "MQRCCF_INCOMPATIBLE_QM_IN_QSG"),
+        (3068, "MQRCCF_CFSL_COUNT_ERROR"),
+        (2149, "MQRC_PCF_ERROR"),
+        (4042, "MQRCCF_CHANNEL_ALREADY_EXISTS"),
+        (2560, "MQRC_MULTICAST_ONLY"),
+        (4020, "MQRCCF_LISTENER_NOT_STARTED"),
+        (4088, "MQRCCF_NET_PRIORITY_ERROR"),
+        (2342, "MQRC_DB2_NOT_AVAILABLE"),
+        (4029, "MQRCCF_MQPUT_FAILED"),
+        (2593, "MQRC_CERT_VAL_POLICY_ERROR"),
+        (2458, "MQRC_READ_AHEAD_MSGS"),
+        (2248, "MQRC_MDE_ERROR"),
+        (4053, "MQRCCF_MCA_NAME_WRONG_TYPE"),
+        (3308, "MQRCCF_TOPIC_STRING_NOT_FOUND"),
+        (3075, "MQRCCF_INCORRECT_STREAM"),
+        (4096, "MQRCCF_KWD_VALUE_WRONG_TYPE"),
+        (2202, "MQRC_CONNECTION_QUIESCING"),
+        (4012, "MQRCCF_CONNECTION_REFUSED"),
+        (2040, "MQRC_NOT_OPEN_FOR_SET"),
+        (3038, "MQRCCF_DISC_INT_ERROR"),
+        (2539, "MQRC_CHANNEL_CONFIG_ERROR"),
+        (2448, "MQRC_CALLBACK_NOT_REGISTERED"),
+        (2009, "MQRC_CONNECTION_BROKEN"),
+        (2187, "MQRC_CICS_BRIDGE_RESTRICTION"),
+        (2036, "MQRC_NOT_OPEN_FOR_BROWSE"),
+        (2583, "MQRC_INSTALLATION_MISMATCH"),
+        (2266, "MQRC_CLUSTER_EXIT_ERROR"),
+        (2034, "MQRC_NO_MSG_UNDER_CURSOR"),
+        (2090, "MQRC_WAIT_INTERVAL_ERROR"),
+        (2123, "MQRC_OUTCOME_MIXED"),
+        (3347, "MQRCCF_PROFILE_NAME_MISSING"),
+        (2392, "MQRC_SSL_CONFIG_ERROR"),
+        (3080, "MQRCCF_CORREL_ID_ERROR"),
+        (3005, "MQRCCF_CFH_CONTROL_ERROR"),
+        (3217, "MQRCCF_NO_CHANNEL_INITIATOR"),
+        (3243, "MQRCCF_CFIF_PARM_ID_ERROR"),
+        (2354, "MQRC_UOW_ENLISTMENT_ERROR"),
+        (3244, "MQRCCF_CFSF_FILTER_VAL_LEN_ERR"),
+        (2520, "MQRC_RES_OBJECT_STRING_ERROR"),
+        (2233, "MQRC_CHANNEL_AUTO_DEF_OK"),
+        (2039, "MQRC_NOT_OPEN_FOR_OUTPUT"),
+        (2272, "MQRC_PARTIALLY_CONVERTED"),
+        (2148, "MQRC_IIH_ERROR"),
+        (3157, "MQRCCF_ALREADY_JOINED"),
+        (2078, "MQRC_TRIGGER_TYPE_ERROR"),
+        (3344, "MQRCCF_CHLAUTH_MAX_EXCEEDED"),
+        (3046, "MQRCCF_PURGE_VALUE_ERROR"),
+        (2152, "MQRC_OBJECT_NAME_ERROR"),
+        (3032, "MQRCCF_PING_DATA_COMPARE_ERROR"),
+        (3225, "MQRCCF_COMMAND_SCOPE_ERROR"),
+        (6105, "MQRC_CURSOR_NOT_VALID"),
+        (2527, "MQRC_RFH_RESTRICTED_FORMAT_ERR"),
+        (2316, "MQRC_ITEM_COUNT_ERROR"),
+        (2543, "MQRC_STANDBY_Q_MGR"),
+        (2461, "MQRC_CMHO_ERROR"),
+        (3316, "MQRCCF_DEST_NAME_ERROR"),
+        (3227, "MQRCCF_FUNCTION_RESTRICTED"),
+        (3352, "MQRCCF_CFCONLOS_CHECKS_FAILED"),
+        (2139, "MQRC_CNO_ERROR"),
+        (3376, "MQRCCF_REMOTE_CHL_TYPE_ERROR"),
+        (2095, "MQRC_NOT_OPEN_FOR_SET_ALL"),
+        (2257, "MQRC_WRONG_MD_VERSION"),
+        (2183, "MQRC_API_EXIT_LOAD_ERROR"),
+        (2382, "MQRC_CRYPTO_HARDWARE_ERROR"),
+        (2347, "MQRC_CF_STRUC_LIST_HDR_IN_USE"),
+        (2258, "MQRC_GROUP_ID_ERROR"),
+        (2537, "MQRC_CHANNEL_NOT_AVAILABLE"),
+        (2524, "MQRC_SELECTOR_NOT_ALTERABLE"),
+        (3015, "MQRCCF_CFST_PARM_ID_ERROR"),
+        (2124, "MQRC_OUTCOME_PENDING"),
+        (2267, "MQRC_CLUSTER_EXIT_LOAD_ERROR"),
+        (2194, "MQRC_NAME_NOT_VALID_FOR_TYPE"),
+        (3365, "MQRCCF_INVALID_PROTOCOL"),
+        (2125, "MQRC_BRIDGE_STARTED"),
+        (2290, "MQRC_Q_ALREADY_EXISTS"),
+        (2142, "MQRC_HEADER_ERROR"),
+        (3039, "MQRCCF_SHORT_RETRY_ERROR"),
+        (2085, "MQRC_UNKNOWN_OBJECT_NAME"),
+        (4003, "MQRCCF_LIKE_OBJECT_WRONG_TYPE"),
+        (2269, "MQRC_CLUSTER_RESOURCE_ERROR"),
+        (2431, "MQRC_SUB_USER_DATA_ERROR"),
+        (2150, "MQRC_DBCS_ERROR"),
+        (2335, "MQRC_RFH_STRING_ERROR"),
+        (6126, "MQRC_DISTRIBUTION_LIST_EMPTY"),
+        (3050, "MQRCCF_ENCODING_ERROR"),
+        (3353, "MQRCCF_SUITE_B_ERROR"),
+        (4044, "MQRCCF_CHANNEL_NAME_ERROR"),
+        (2596, "MQRC_CERT_LABEL_NOT_ALLOWED"),
+        (2051, "MQRC_PUT_INHIBITED"),
+        (3043, "MQRCCF_SEQ_NUMBER_WRAP_ERROR"),
+        (2098, "MQRC_CONTEXT_NOT_AVAILABLE"),
+        (2223, "MQRC_Q_MGR_NOT_ACTIVE"),
+        (2359, "MQRC_NO_RECORD_AVAILABLE"),
+        (3210, "MQRCCF_OBJECT_OPEN_FORCE"),
+        (2523, "MQRC_INVALID_SUBSCRIPTION"),
+        (2525, "MQRC_RETAINED_MSG_Q_ERROR"),
+        (2130, "MQRC_ADAPTER_SERV_LOAD_ERROR"),
+        (2328, "MQRC_SYSTEM_BAG_NOT_DELETABLE"),
+        (2457, "MQRC_OPTIONS_CHANGED"),
+        (2115, "MQRC_TARGET_CCSID_ERROR"),
+        (2507, "MQRC_XEPO_ERROR"),
+        (2592, "MQRC_SUITE_B_ERROR"),
+        (3208, "MQRCCF_OBJECT_NAME_RESTRICTED"),
+        (2286, "MQRC_INITIALIZATION_FAILED"),
+        (4062, "MQRCCF_CONN_NAME_ERROR"),
+        (3048, "MQRCCF_MSG_TRUNCATED"),
+        (2200, "MQRC_MSG_MARKED_BROWSE_CO_OP"),
+        (2082, "MQRC_UNKNOWN_ALIAS_BASE_Q"),
+        (3351, "MQRCCF_CF_STRUC_ALREADY_FAILED"),
+        (2550, "MQRC_NO_SUBS_MATCHED"),
+        (2162, "MQRC_Q_MGR_STOPPING"),
+        (2114, "MQRC_SOURCE_FLOAT_ENC_ERROR"),
+        (3202, "MQRCCF_SECURITY_REFRESH_FAILED"),
+        (3320, "MQRCCF_COMM_INFO_TYPE_ERROR"),
+        (2285, "MQRC_SERVICE_NOT_AVAILABLE"),
+        (2365, "MQRC_SEGMENTS_NOT_SUPPORTED"),
+        (3064, "MQRCCF_CHL_INST_TYPE_ERROR"),
+        (2528, "MQRC_CONNECTION_STOPPED"),
+        (2056, "MQRC_Q_SPACE_NOT_AVAILABLE"),
+        (3006, "MQRCCF_CFH_PARM_COUNT_ERROR"),
+        (2185, "MQRC_INCONSISTENT_PERSISTENCE"),
+        (2551, "MQRC_SELECTION_NOT_AVAILABLE"),
+        (3052, "MQRCCF_DATA_CONV_VALUE_ERROR"),
+        (2052, "MQRC_Q_DELETED"),
+        (2312, "MQRC_SELECTOR_WRONG_TYPE"),
+        (3171, "MQRCCF_AUTH_VALUE_ERROR"),
+        (2383, "MQRC_AUTH_INFO_REC_COUNT_ERROR"),
+        (4028, "MQRCCF_MQGET_FAILED"),
+        (2113, "MQRC_SOURCE_DECIMAL_ENC_ERROR"),
+        (2063, "MQRC_SECURITY_ERROR"),
+        (4037, "MQRCCF_NOT_XMIT_Q"),
+        (3386, "MQRCCF_STREAMQ_NOT_SUPPORTED"),
+        (2144, "MQRC_TARGET_LENGTH_ERROR"),
+        (2325, "MQRC_NESTED_BAG_NOT_SUPPORTED"),
+        (2190, "MQRC_CONVERTED_STRING_TOO_BIG"),
+        (2521, "MQRC_CONNECTION_SUSPENDED"),
+        (2280, "MQRC_HCONFIG_ERROR"),
+        (2336, "MQRC_RFH_COMMAND_ERROR"),
+        (3264, "MQRCCF_CFBF_LENGTH_ERROR"),
+        (3325, "MQRCCF_SSL_ALT_PROVIDER_REQD"),
+        (3167, "MQRCCF_PORT_NUMBER_ERROR"),
+        (2192, "MQRC_STORAGE_MEDIUM_FULL"),
+        (4002, "MQRCCF_OBJECT_WRONG_TYPE"),
+        (2500, "MQRC_HCONN_ASYNC_ACTIVE"),
+        (3081, "MQRCCF_NOT_AUTHORIZED"),
+        (2232, "MQRC_UNIT_OF_WORK_NOT_STARTED"),
+        (2274, "MQRC_OPTION_ENVIRONMENT_ERROR"),
+        (6130, "MQRC_XR_NOT_AVAILABLE"),
+        (2542, "MQRC_ALREADY_JOINED"),
+        (3019, "MQRCCF_PARM_COUNT_TOO_SMALL"),
+        (2515, "MQRC_GROUPING_NOT_ALTERABLE"),
+        (2492, "MQRC_PROP_NAME_NOT_CONVERTED"),
+        (3233, "MQRCCF_LISTENER_STARTED"),
+        (2030, "MQRC_MSG_TOO_BIG_FOR_Q"),
+        (3268, "MQRCCF_LISTENER_STILL_ACTIVE"),
+        (2446, "MQRC_NO_CALLBACKS_ACTIVE"),
+        (2571, "MQRC_MCAST_PUB_STATUS"),
+        (3092, "MQRCCF_COMMS_LIBRARY_ERROR"),
+        (3307, "MQRCCF_MAX_INSTS_PER_CLNT_ERR"),
+        (2300, "MQRC_COMMAND_TYPE_ERROR"),
+        (3335, "MQRCCF_CHLAUTH_USERSRC_ERROR"),
+        (2265, "MQRC_TM_ERROR"),
+        (3201, "MQRCCF_SECURITY_SWITCH_OFF"),
+        (3086, "MQRCCF_Q_MGR_CCSID_ERROR"),
+        (2368, "MQRC_CONFIG_CHANGE_OBJECT"),
+        (3241, "MQRCCF_CFIF_LENGTH_ERROR"),
+        (2129, "MQRC_ADAPTER_CONN_LOAD_ERROR"),
+        (4008, "MQRCCF_OBJECT_NAME_ERROR"),
+        (4035, "MQRCCF_REMOTE_QM_TERMINATING"),
+        (3231, "MQRCCF_COMMAND_ORIGIN_ERROR"),
+        (2517, "MQRC_HOBJ_QUIESCED"),
+        (2466, "MQRC_PROP_VALUE_NOT_CONVERTED"),
+        (4098, "MQRCCF_NHA_NOT_AVAILABLE"),
+        (3318, "MQRCCF_PUBSUB_INHIBITED"),
+        (2390, "MQRC_LDAP_PASSWORD_ERROR"),
+        (2020, "MQRC_INHIBIT_VALUE_ERROR"),
+        (2570, "MQRC_SSL_ALT_PROVIDER_REQUIRED"),
+        (3323, "MQRCCF_CLIENT_ID_ERROR"),
+        (4001, "MQRCCF_OBJECT_ALREADY_EXISTS"),
+        (3258, "MQRCCF_CFGR_LENGTH_ERROR"),
+        (2110, "MQRC_FORMAT_ERROR"),
+        (3077, "MQRCCF_NO_RETAINED_MSG"),
+        (2346, "MQRC_CF_STRUC_IN_USE"),
+        (3008, "MQRCCF_COMMAND_FAILED"),
+        (3379, "MQRCCF_LOG_EXTENT_NOT_FOUND"),
+        (2430, "MQRC_STAT_TYPE_ERROR"),
+        (3095, "MQRCCF_CFST_CONFLICTING_PARM"),
+        (2462, "MQRC_DMHO_ERROR"),
+        (2397, "MQRC_JSSE_ERROR"),
+        (2107, "MQRC_XWAIT_CANCELED"),
+        (2143, "MQRC_SOURCE_LENGTH_ERROR"),
+        (2412, "MQRC_COMMAND_MQSC"),
+        (2348, "MQRC_CF_STRUC_AUTH_FAILED"),
+        (2075, "MQRC_TRIGGER_CONTROL_ERROR"),
+        (4052, "MQRCCF_XMIT_Q_NAME_WRONG_TYPE"),
+        (2545, "MQRC_RECONNECTED"),
+        (2227, "MQRC_Q_SERVICE_INTERVAL_OK"),
+        (3152, "MQRCCF_DUPLICATE_SUBSCRIPTION"),
+        (4007, "MQRCCF_Q_WRONG_TYPE"),
+        (3326, "MQRCCF_CHLAUTH_TYPE_ERROR"),
+        (2259, "MQRC_INCONSISTENT_BROWSE"),
+        (4045, "MQRCCF_XMIT_Q_NAME_ERROR"),
+        (2512, "MQRC_SUBLEVEL_NOT_ALTERABLE"),
+        (4093, "MQRCCF_SSL_PEER_NAME_ERROR"),
+        (2279, "MQRC_CHANNEL_STOPPED_BY_USER"),
+        (3237, "MQRCCF_UNKNOWN_USER_ID"),
+        (3327, "MQRCCF_CHLAUTH_ACTION_ERROR"),
+        (3358, "MQRCCF_SMDS_REQUIRES_DSGROUP"),
+        (2289, "MQRC_SERVICE_ERROR"),
+        (3306, "MQRCCF_MAX_INSTANCES_ERROR"),
+        (2601, "MQRC_Q_MGR_RECONNECT_REQUESTED"),
+        (2504, "MQRC_SELECTOR_ALWAYS_FALSE"),
+        (4051, "MQRCCF_RCV_EXIT_NAME_ERROR"),
+        (2022, "MQRC_INT_ATTR_COUNT_TOO_SMALL"),
+        (3209, "MQRCCF_OBJECT_LIMIT_EXCEEDED"),
+        (2235, "MQRC_CFH_ERROR"),
+        (6123, "MQRC_STRUC_LENGTH_ERROR"),
+        (3223, "MQRCCF_Q_ATTR_CONFLICT"),
+        (3213, "MQRCCF_ATTR_VALUE_FIXED"),
+        (3236, "MQRCCF_CF_STRUC_ERROR"),
+        (6106, "MQRC_ENCODING_ERROR"),
+        (6127, "MQRC_INCONSISTENT_OPEN_OPTIONS"),
+        (2008, "MQRC_CHAR_ATTRS_TOO_SHORT"),
+        (2247, "MQRC_MATCH_OPTIONS_ERROR"),
+        (3037, "MQRCCF_BATCH_SIZE_ERROR"),
+        (2414, "MQRC_CFIF_ERROR"),
+        (3334, "MQRCCF_POLICY_NAME_MISSING"),
+        (3153, "MQRCCF_SUB_NAME_ERROR"),
+        (3373, "MQRCCF_ENTITY_TYPE_MISSING"),
+        (2317, "MQRC_FORMAT_NOT_SUPPORTED"),
+        (2388, "MQRC_LDAP_USER_NAME_ERROR"),
+        (2315, "MQRC_SYSTEM_BAG_NOT_ALTERABLE"),
+        (2271, "MQRC_CONN_TAG_IN_USE"),
+    ],
+}, &[(3029,"MQRCCF_MODE_VALUE_ERROR"),(3345,"MQRCCF_IPADDR_ERROR"),]);
 pub(crate) const MQI_BY_STRING: ::phf::Map<&'static str, ::libmqm_sys::lib::MQLONG> = ::phf::Map {
     key: 12913932095322966823,
     disps: &[
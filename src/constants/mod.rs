@@ -140,7 +140,7 @@ impl<T: AsRef<sys::MQLONG>> MqConstant for T {
 mod tests {
     use crate::ConstLookup;
 
-    use super::{ConstSource, LinearSource};
+    use super::{mapping, ConstSource, LinearSource};
 
     const ZERO: LinearSource = ConstSource(&[], &[]);
     const ONE: LinearSource = ConstSource(&[(1, "ONE")], &[]);
@@ -157,4 +157,18 @@ mod tests {
         assert_eq!(ONEB.by_value(1).collect::<Vec<_>>(), &["ONE", "ONEB"]);
         assert_eq!(ONEB.by_value(0).collect::<Vec<_>>(), Vec::<&str>::new());
     }
+
+    #[test]
+    fn combined_source() {
+        for (value, ..) in mapping::MQXA_FULL_CONST.all() {
+            let chained = mapping::MQIA_CONST.by_value(value).chain(mapping::MQCA_CONST.by_value(value));
+            assert!(mapping::MQXA_FULL_CONST.by_value(value).eq(chained));
+        }
+        for (value, ..) in mapping::MQRC_FULL_CONST.all() {
+            let chained = mapping::MQRC_CONST
+                .by_value(value)
+                .chain(mapping::MQRCCF_CONST.by_value(value));
+            assert!(mapping::MQRC_FULL_CONST.by_value(value).eq(chained));
+        }
+    }
 }
@@ -4,7 +4,7 @@ mod mqi_verbs;
 mod outcome;
 pub(crate) mod values;
 
-#[cfg(feature = "mqai")]
+#[cfg(any(feature = "mqai", feature = "pcf"))]
 pub mod mqai;
 
 pub use handles::*;
@@ -4,7 +4,7 @@ use crate::sys;
 
 use super::values::MQCFOP;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Filter<T> {
     pub operator: MQCFOP,
     pub value: T,
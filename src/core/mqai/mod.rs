@@ -1,7 +1,10 @@
 mod filter;
+#[cfg(feature = "mqai")]
 mod handles;
 pub(crate) mod values;
+#[cfg(feature = "mqai")]
 mod verbs;
 
 pub use filter::*;
+#[cfg(feature = "mqai")]
 pub use handles::*;
@@ -7,7 +7,7 @@ use crate::core::{Library, MqFunctions, MqiOutcome, MqiOutcomeVoid};
 use crate::{core, MQMD};
 use crate::{sys, ResultComp};
 
-use crate::values::{MqaiSelector, CCSID, MQCBO, MQCFOP, MQCMD, MQIND};
+use crate::values::{MqaiSelector, CCSID, MQCBO, MQCFOP, MQCMD, MQIND, MQITEM};
 use super::{BagHandle, Filter};
 
 #[cfg(feature = "tracing")]
@@ -619,6 +619,31 @@ impl<L: Library<MQ: Mqai>> MqFunctions<L> {
         outcome.into()
     }
 
+    /// Returns the selector and type of the specified item in the bag
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self)))]
+    pub fn mq_inquire_item_info(
+        &self,
+        bag: &BagHandle,
+        selector: MqaiSelector,
+        index: MQIND,
+    ) -> ResultComp<(MqaiSelector, MQITEM)> {
+        let mut outcome = MqiOutcome::new("mqInquireItemInfo", (MqaiSelector(0), MQITEM(0)));
+        unsafe {
+            self.0.lib().mqInquireItemInfo(
+                bag.raw_handle(),
+                selector.0,
+                index.0,
+                &mut outcome.value.0 .0,
+                &mut outcome.value.1 .0,
+                &mut outcome.cc.0,
+                &mut outcome.rc.0,
+            );
+        }
+        #[cfg(feature = "tracing")]
+        tracing_outcome(&outcome);
+        outcome.into()
+    }
+
     #[expect(clippy::too_many_arguments)]
     #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self)))]
     pub fn mq_execute(
@@ -731,6 +756,54 @@ impl<L: Library<MQ: Mqai>> MqFunctions<L> {
         tracing_outcome(&outcome);
         outcome.into()
     }
+
+    /// Converts the bag into PCF format in the supplied buffer, returning the length of the PCF data
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self, buffer)))]
+    pub fn mq_bag_to_buffer<T: ?Sized>(
+        &self,
+        options: Option<&BagHandle>,
+        bag: &BagHandle,
+        buffer: &mut T,
+    ) -> ResultComp<sys::MQLONG> {
+        let mut outcome = MqiOutcome::new("mqBagToBuffer", -1);
+        unsafe {
+            self.0.lib().mqBagToBuffer(
+                options.map_or(sys::MQHB_NONE, |h| h.raw_handle()),
+                bag.raw_handle(),
+                size_of_val(buffer)
+                    .try_into()
+                    .expect("buffer length should not exceed maximum positive MQLONG"),
+                ptr::from_mut(buffer).cast(),
+                &mut outcome.value,
+                &mut outcome.cc.0,
+                &mut outcome.rc.0,
+            );
+        }
+        #[cfg(feature = "tracing")]
+        tracing_outcome(&outcome);
+        outcome.into()
+    }
+
+    /// Converts the PCF data in the buffer into items of the bag
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self, buffer)))]
+    pub fn mq_buffer_to_bag<T: ?Sized>(&self, options: Option<&BagHandle>, buffer: &T, bag: &BagHandle) -> ResultComp<()> {
+        let mut outcome = MqiOutcomeVoid::with_verb("mqBufferToBag");
+        unsafe {
+            self.0.lib().mqBufferToBag(
+                options.map_or(sys::MQHB_NONE, |h| h.raw_handle()),
+                size_of_val(buffer)
+                    .try_into()
+                    .expect("buffer length should not exceed maximum positive MQLONG"),
+                ptr::from_ref(buffer).cast_mut().cast(),
+                bag.raw_handle(),
+                &mut outcome.cc.0,
+                &mut outcome.rc.0,
+            );
+        }
+        #[cfg(feature = "tracing")]
+        tracing_outcome(&outcome);
+        outcome.into()
+    }
 }
 
 #[cfg(test)]
@@ -798,5 +871,8 @@ mod tests {
         assert_eq!(format!("{}", MqaiSelector(sys::MQCA_ALTERATION_TIME)), "MQCA_ALTERATION_TIME");
         assert_eq!(format!("{}", MqaiSelector(sys::MQIACF_INQUIRY)), "MQIACF_INQUIRY");
         assert_eq!(format!("{}", MqaiSelector(sys::MQSEL_ANY_SELECTOR)), "-30001");
+        assert_eq!(format!("{}", MqaiSelector(sys::MQIASY_COMP_CODE)), "MQIASY_COMP_CODE");
+        assert_eq!(format!("{}", MqaiSelector(sys::MQHA_BAG_HANDLE)), "MQHA_BAG_HANDLE");
+        assert_eq!("MQCACH_CHANNEL_NAME".parse(), Ok(MqaiSelector(sys::MQCACH_CHANNEL_NAME)));
     }
 }
@@ -36,15 +36,39 @@ impl_default_mqvalue!(MQIMPO, sys::MQIMPO_NONE);
 define_mqvalue!(pub MQPD, mapping::MQPD_CONST, "Property descriptor, support and context");
 define_mqmask!(pub MQCOPY, mapping::MQCOPY_CONST, "Property copy options mask");
 define_mqvalue!(pub MQRC, mapping::MQRC_FULL_CONST, "Reason Code from an MQ function call");
+define_mqvalue!(pub MQRCCF, mapping::MQRCCF_CONST, "Reason Code from a PCF command");
 define_mqvalue!(pub MQCC, mapping::MQCC_CONST, "Completion Code from an MQ function call");
 define_mqmask!(pub MQDCC, mapping::MQDCC_CONST, "Options mask that control the action of `MQXCNVC`");
 impl_default_mqvalue!(MQDCC, sys::MQDCC_NONE);
 
 define_mqmask!(pub MQCNO, mapping::MQCNO_CONST, "Options mask that control the action of `MQCONNX`");
 define_mqvalue!(pub MQXPT, mapping::MQXPT_CONST, "Transport Types");
+define_mqvalue!(pub MQCOMPRESS, mapping::MQCOMPRESS_CONST, "Channel header and message compression techniques");
+define_mqmask!(pub MQBNOBALTYPE, mapping::MQBNO_BALTYPE_CONST, "Application type mask for connection balancing");
+define_mqvalue!(pub MQBNOTIMEOUT, mapping::MQBNO_TIMEOUT_CONST, "Timeout in seconds for connection balancing");
+define_mqmask!(pub MQBNOOPTIONS, mapping::MQBNO_OPTIONS_CONST, "Options mask for connection balancing");
 
 define_mqvalue!(pub MQOT, mapping::MQOT_CONST, "Object Types and Extended Object Types");
 
+define_mqvalue!(pub MQMT, mapping::MQMT_CONST, "Message Types");
+define_mqmask!(pub MQRO, mapping::MQRO_CONST, "Report options mask of the message descriptor");
+impl_default_mqvalue!(MQRO, sys::MQRO_NONE);
+define_mqvalue!(pub MQPER, mapping::MQPER_CONST, "Message persistence of the message descriptor");
+impl_default_mqvalue!(MQPER, sys::MQPER_PERSISTENCE_AS_Q_DEF);
+define_mqvalue!(pub MQPRI, mapping::MQPRI_CONST, "Message priority, including the special priority values");
+define_mqvalue!(pub MQFB, mapping::MQFB_CONST, "Feedback codes of the message descriptor");
+impl_default_mqvalue!(MQFB, sys::MQFB_NONE);
+define_mqmask!(pub MQMF, mapping::MQMF_CONST, "Message flags mask of the message descriptor");
+impl_default_mqvalue!(MQMF, sys::MQMF_NONE);
+define_mqvalue!(pub MQEI, mapping::MQEI_CONST, "Message expiry interval in tenths of a second");
+impl_default_mqvalue!(MQEI, sys::MQEI_UNLIMITED);
+define_mqvalue!(pub MQBND, mapping::MQBND_CONST, "Binding of a cluster queue handle");
+define_mqmask!(pub MQMO, mapping::MQMO_CONST, "Match options mask of the get message options");
+impl_default_mqvalue!(MQMO, sys::MQMO_NONE);
+define_mqvalue!(pub MQGS, mapping::MQGS_CONST, "Group status of the get message options");
+define_mqvalue!(pub MQSS, mapping::MQSS_CONST, "Segment status of the get message options");
+define_mqvalue!(pub MQPL, mapping::MQPL_CONST, "Platform of the queue manager");
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, derive_more::From)]
 pub struct CCSID(pub sys::MQLONG);
 
@@ -92,7 +116,8 @@ impl PartialEq<sys::MQLONG> for CCSID {
 mod test {
     use std::convert::identity;
 
-    use crate::values::CCSID;
+    use crate::sys;
+    use crate::values::{CCSID, MQFB, MQGS, MQMF, MQPER, MQSS};
 
     #[test]
     fn ccsid() {
@@ -105,4 +130,17 @@ mod test {
         assert_eq!(format!("{}", CCSID(999)), "999");
         assert_eq!(format!("{:?}", CCSID(999)), "CCSID(999)");
     }
+
+    #[test]
+    fn message_descriptor_values() {
+        assert_eq!(MQPER(sys::MQPER_PERSISTENT).to_string(), "MQPER_PERSISTENT");
+        assert_eq!(MQFB(sys::MQFB_COA).to_string(), "MQFB_COA");
+        assert_eq!(
+            MQMF(sys::MQMF_MSG_IN_GROUP | sys::MQMF_LAST_MSG_IN_GROUP).to_string(),
+            "MQMF_MSG_IN_GROUP|MQMF_LAST_MSG_IN_GROUP"
+        );
+        assert_eq!("MQFB_EXPIRATION".parse(), Ok(MQFB(sys::MQFB_EXPIRATION)));
+        assert_eq!(MQGS(b'L'.into()).to_string(), "MQGS_LAST_MSG_IN_GROUP");
+        assert_eq!(MQSS(b'S'.into()).to_string(), "MQSS_SEGMENT");
+    }
 }
@@ -24,6 +24,9 @@ pub use mq::*;
 #[cfg(feature = "mqai")]
 pub mod admin;
 
+#[cfg(feature = "pcf")]
+pub mod pcf;
+
 pub mod sys {
     pub use libmqm_sys::lib::*; // Re-export mq sys library
 }
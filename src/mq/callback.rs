@@ -1,12 +1,57 @@
+use std::time::Duration;
+
 use libmqm_sys::function;
 
 use crate::{
     core::{values::MQCBDO, Library, MqFunctions},
-    sys, Error, MqStruct,
+    sys,
+    values::MQRC,
+    Error, MqStruct,
 };
 
 use super::{values::MQOP, Conn as _, Connection, ConnectionRef};
 
+/// Connection lifecycle event delivered to the handler registered with [`Connection::register_event_handler`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// The connection is broken and the client will attempt to reconnect after the delay
+    Reconnecting { delay: Duration },
+    /// The client has reconnected to a queue manager
+    Reconnected,
+    /// The client could not reconnect within the reconnect timeout
+    ReconnectFailed,
+    /// The queue manager is quiescing
+    QmgrQuiescing,
+    /// The connection to the queue manager is broken
+    ConnectionBroken,
+    /// The event handler is being deregistered
+    Deregistered,
+    /// Any other event, identified by the reason code
+    Other(MQRC),
+}
+
+impl ConnectionEvent {
+    /// The event described by the callback context of an event handler
+    #[must_use]
+    pub fn from_context(context: &MqStruct<sys::MQCBC>) -> Self {
+        if context.CallType == sys::MQCBCT_DEREGISTER_CALL {
+            return Self::Deregistered;
+        }
+        match context.Reason {
+            sys::MQRC_RECONNECTING => Self::Reconnecting {
+                delay: u64::try_from(context.ReconnectDelay)
+                    .map(Duration::from_millis)
+                    .unwrap_or_default(),
+            },
+            sys::MQRC_RECONNECTED => Self::Reconnected,
+            sys::MQRC_RECONNECT_FAILED => Self::ReconnectFailed,
+            sys::MQRC_Q_MGR_QUIESCING => Self::QmgrQuiescing,
+            sys::MQRC_CONNECTION_BROKEN => Self::ConnectionBroken,
+            reason => Self::Other(MQRC(reason)),
+        }
+    }
+}
+
 struct CallbackData<F, L> {
     options: MQCBDO,
     closure: F,
@@ -16,7 +61,7 @@ struct CallbackData<F, L> {
 fn event_callback<L, H, F>(hconn: sys::MQHCONN, _: sys::PMQVOID, _: sys::PMQVOID, _: sys::PMQVOID, cbc: *const sys::MQCBC)
 where
     L: Library<MQ: function::Mqi> + Clone,
-    F: FnMut(ConnectionRef<L, H>, &MqStruct<sys::MQCBC>),
+    F: FnMut(ConnectionRef<L, H>, ConnectionEvent, &MqStruct<sys::MQCBC>),
 {
     unsafe {
         if let Some(context) = cbc.cast::<MqStruct<sys::MQCBC>>().as_ref() {
@@ -25,7 +70,11 @@ where
             }) = context.CallbackArea.cast::<CallbackData<F, L>>().as_mut()
             {
                 if (context.CallType != sys::MQCBCT_DEREGISTER_CALL) || (*options & sys::MQCBDO_DEREGISTER_CALL) != 0 {
-                    closure(ConnectionRef::from_parts(hconn.into(), mq.clone()), context);
+                    closure(
+                        ConnectionRef::from_parts(hconn.into(), mq.clone()),
+                        ConnectionEvent::from_context(context),
+                        context,
+                    );
                 }
                 if context.CallType == sys::MQCBCT_DEREGISTER_CALL {
                     // Recreate the box so it deallocates / drops
@@ -40,9 +89,11 @@ impl<L, H> Connection<L, H>
 where
     L: Library<MQ: function::Mqi> + Clone,
 {
+    /// Register a closure that is called with the [`ConnectionEvent`] of each event on the connection,
+    /// such as the progress of a client reconnect
     pub fn register_event_handler<F>(&mut self, options: MQCBDO, closure: F) -> Result<(), Error>
     where
-        F: FnMut(ConnectionRef<L, H>, &MqStruct<sys::MQCBC>),
+        F: FnMut(ConnectionRef<L, H>, ConnectionEvent, &MqStruct<sys::MQCBC>),
     {
         let cb_data: *mut CallbackData<F, L> = Box::into_raw(Box::from(CallbackData {
             options,
@@ -61,3 +112,38 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{sys, values::MQRC, MqStruct};
+
+    use super::ConnectionEvent;
+
+    #[test]
+    fn connection_event() {
+        let mut context = MqStruct::<sys::MQCBC>::default();
+        context.CallType = sys::MQCBCT_EVENT_CALL;
+        context.Reason = sys::MQRC_RECONNECTING;
+        context.ReconnectDelay = 1500;
+        assert_eq!(
+            ConnectionEvent::from_context(&context),
+            ConnectionEvent::Reconnecting {
+                delay: Duration::from_millis(1500)
+            }
+        );
+
+        context.Reason = sys::MQRC_RECONNECT_FAILED;
+        assert_eq!(ConnectionEvent::from_context(&context), ConnectionEvent::ReconnectFailed);
+
+        context.Reason = sys::MQRC_Q_MGR_STOPPING;
+        assert_eq!(
+            ConnectionEvent::from_context(&context),
+            ConnectionEvent::Other(MQRC(sys::MQRC_Q_MGR_STOPPING))
+        );
+
+        context.CallType = sys::MQCBCT_DEREGISTER_CALL;
+        assert_eq!(ConnectionEvent::from_context(&context), ConnectionEvent::Deregistered);
+    }
+}
@@ -0,0 +1,242 @@
+use std::{borrow::Cow, marker::PhantomData};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    get::{GetState, GetValue},
+    headers::{fmt, TextEnc},
+    put::PutMessage,
+    sys,
+    types::{Fmt, MessageFormat, Warning},
+    values::{CCSID, MQENC},
+    Buffer, Error, MqiValue, ResultComp, ResultCompErr,
+};
+
+pub type CodecError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Serialization format of a message body
+pub trait Codec {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// The message format used when putting a message
+    fn format() -> MessageFormat;
+
+    /// Whether a retrieved message with the `format` can be decoded
+    #[must_use]
+    fn accepts(format: &MessageFormat) -> bool {
+        let expected = Self::format();
+        format.fmt == expected.fmt && format.ccsid == expected.ccsid
+    }
+
+    fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, Self::Error>;
+    fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, Self::Error>;
+}
+
+/// JSON codec using `MQFMT_STRING` and UTF-8 (CCSID 1208)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct JsonCodec;
+
+impl Codec for JsonCodec {
+    type Error = serde_json::Error;
+
+    fn format() -> MessageFormat {
+        MessageFormat {
+            ccsid: CCSID(1208),
+            encoding: MQENC(sys::MQENC_NATIVE),
+            fmt: TextEnc::Ascii(fmt::MQFMT_STRING),
+        }
+    }
+
+    fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(value)
+    }
+
+    fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, Self::Error> {
+        serde_json::from_slice(data)
+    }
+}
+
+/// Message body that is serialized and deserialized using the codec `C`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Deref, derive_more::DerefMut)]
+pub struct Serialized<T, C> {
+    #[deref]
+    #[deref_mut]
+    value: T,
+    _codec: PhantomData<C>,
+}
+
+/// Message body serialized as JSON
+pub type Json<T> = Serialized<T, JsonCodec>;
+
+impl<T, C> Serialized<T, C> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _codec: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, C> From<T> for Serialized<T, C> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Serialize, C: Codec> Serialized<T, C> {
+    /// Serialize the message body so it can be put
+    pub fn encode(&self) -> Result<Encoded<C>, C::Error> {
+        Encoded::new(&self.value)
+    }
+}
+
+/// Message body serialized using the codec `C`, ready to be put
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Encoded<C> {
+    data: Vec<u8>,
+    _codec: PhantomData<C>,
+}
+
+impl<C: Codec> Encoded<C> {
+    /// Serialize the value, returning any serialization error
+    pub fn new<T: Serialize + ?Sized>(value: &T) -> Result<Self, C::Error> {
+        C::encode(value).map(|data| Self {
+            data,
+            _codec: PhantomData,
+        })
+    }
+
+    /// The serialized message body
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl<C: Codec> PutMessage for Encoded<C> {
+    type Data = [u8];
+
+    fn render(&self) -> Cow<[u8]> {
+        Cow::Borrowed(&self.data)
+    }
+
+    fn format(&self) -> MessageFormat {
+        C::format()
+    }
+}
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum CodecGetError {
+    #[display("Unexpected format or CCSID. Message format = '{_0}', CCSID = {_1}")]
+    UnexpectedFormat(TextEnc<Fmt>, CCSID, Option<Warning>),
+    #[display("Message deserialization error: {_0}")]
+    Deserialize(CodecError, Option<Warning>),
+    #[from]
+    MQ(Error),
+}
+
+impl<'a, T: DeserializeOwned, C: Codec, B: Buffer<'a>> GetValue<B> for Serialized<T, C> {}
+
+impl<'a, P, T, C, B> MqiValue<P, GetState<B>> for Serialized<T, C>
+where
+    T: DeserializeOwned,
+    C: Codec,
+    B: Buffer<'a>,
+{
+    type Error = CodecGetError;
+
+    fn consume<F>(param: &mut P, get: F) -> ResultCompErr<Self, Self::Error>
+    where
+        F: FnOnce(&mut P) -> ResultComp<GetState<B>>,
+    {
+        let state = get(param)?;
+        let warning = state.warning();
+        if !C::accepts(&state.format) {
+            return Err(CodecGetError::UnexpectedFormat(state.format.fmt, state.format.ccsid, warning));
+        }
+
+        let data = &state.buffer.as_ref()[..state.data_length];
+        let value = C::decode(data).map_err(|e| CodecGetError::Deserialize(e.into(), warning))?;
+        Ok(state.map(|_| Self::new(value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        get::GetState,
+        headers::{fmt, TextEnc},
+        put::PutMessage,
+        types::MessageFormat,
+        Completion, MqiValue,
+    };
+
+    use std::collections::HashMap;
+
+    use super::{Codec, CodecGetError, Encoded, Json, JsonCodec};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u32,
+        item: String,
+    }
+
+    fn get_json(data: &[u8], format: MessageFormat) -> Result<Json<Order>, CodecGetError> {
+        let mut buffer = data.to_vec();
+        Json::<Order>::consume(&mut (), |()| {
+            Ok(Completion::new(GetState {
+                buffer: buffer.as_mut_slice(),
+                data_length: data.len(),
+                message_length: data.len(),
+                format,
+            }))
+        })
+        .map(|Completion(value, ..)| value)
+    }
+
+    #[test]
+    fn json_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let order = Json::new(Order {
+            id: 1,
+            item: "widget".to_string(),
+        });
+        let encoded = order.encode()?;
+        let data = encoded.render();
+        assert_eq!(&*data, br#"{"id":1,"item":"widget"}"#);
+
+        let result = get_json(&data, encoded.format())?;
+        assert_eq!(*result, *order);
+        Ok(())
+    }
+
+    #[test]
+    fn json_encode_error() {
+        // JSON objects only have string keys
+        let map = HashMap::from([((1, 2), "value")]);
+        assert!(Encoded::<JsonCodec>::new(&map).is_err());
+    }
+
+    #[test]
+    fn json_errors() {
+        assert!(matches!(
+            get_json(
+                b"{}",
+                MessageFormat {
+                    fmt: TextEnc::Ascii(fmt::MQFMT_NONE),
+                    ..JsonCodec::format()
+                }
+            ),
+            Err(CodecGetError::UnexpectedFormat(..))
+        ));
+        assert!(matches!(
+            get_json(b"{\"id\":", JsonCodec::format()),
+            Err(CodecGetError::Deserialize(..))
+        ));
+    }
+}
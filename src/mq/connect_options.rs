@@ -1,7 +1,7 @@
 #![expect(clippy::allow_attributes, reason = "Macro include 'allow' for generation purposes")]
 #![allow(non_snake_case)]
 
-use std::{any, ptr};
+use std::{any, ptr, time::Duration};
 
 use crate::{
     macros::{all_multi_tuples, reverse_ident},
@@ -12,7 +12,8 @@ use crate::{
 use super::{
     impl_mqstruct_min_version,
     types::{
-        impl_from_str, CertificateLabel, ChannelName, CipherSpec, ConnectionName, CryptoHardware, KeyRepo, QueueManagerName,
+        impl_from_str, CertificateLabel, ChannelName, CipherSpec, ConnectionName, ConnectionNameError, CryptoHardware, ExitData,
+        ExitName, KeyRepo, LocalAddress, QueueManagerName,
     },
     ConnTag, ConnectParam, ConnectionId, MqStruct,
 };
@@ -122,7 +123,7 @@ pub struct Ccdt<'url>(pub &'url str);
 #[derive(Debug, Clone, Copy)]
 pub struct MqServer<'m> {
     channel_name: &'m str,
-    connection_name: &'m str,
+    connection_name: ConnectionName,
     transport: values::MQXPT,
 }
 
@@ -134,17 +135,15 @@ impl<'m> TryFrom<&'m str> for MqServer<'m> {
         let server_pattern = regex_lite::Regex::new(r"^(.{1,20}?)/(.+?)/(.{1,264}?)$").unwrap();
 
         if let Some((_, [channel, transport, connection_name])) = server_pattern.captures(server).map(|v| v.extract()) {
-            Ok(Self {
+            let mut server = Self {
                 channel_name: if channel.len() <= 20 {
                     Ok(channel)
                 } else {
                     Err(MqServerSyntaxError::ChannelFormat(channel.to_string()))
                 }?,
-                connection_name: if connection_name.len() <= 264 {
-                    Ok(connection_name)
-                } else {
-                    Err(MqServerSyntaxError::ConnectionNameFormat(connection_name.to_string()))
-                }?,
+                connection_name: connection_name
+                    .parse()
+                    .map_err(|_| MqServerSyntaxError::ConnectionNameFormat(connection_name.to_string()))?,
                 transport: match transport {
                     "TCP" => Ok(values::MQXPT(sys::MQXPT_TCP)),
                     "LU62" => Ok(values::MQXPT(sys::MQXPT_LU62)),
@@ -152,7 +151,15 @@ impl<'m> TryFrom<&'m str> for MqServer<'m> {
                     "SPX" => Ok(values::MQXPT(sys::MQXPT_SPX)),
                     other => Err(MqServerSyntaxError::UnrecognizedTransport(other.to_string())),
                 }?,
-            })
+            };
+            if server.transport == values::MQXPT(sys::MQXPT_TCP) {
+                server.connection_name = server
+                    .connection_name
+                    .addresses()
+                    .and_then(|addresses| ConnectionName::from_addresses(&addresses))
+                    .map_err(MqServerSyntaxError::ConnectionNameList)?;
+            }
+            Ok(server)
         } else {
             Err(MqServerSyntaxError::InvalidFormat)
         }
@@ -166,9 +173,7 @@ impl<'m> ConnectOption<'m> for MqServer<'m> {
     {
         cd.ChannelName = [32; 20];
         cd.ChannelName[..self.channel_name.len()].copy_from_slice(unsafe { &*(ptr::from_ref(self.channel_name) as *const [i8]) });
-        cd.ConnectionName = [32; 264];
-        cd.ConnectionName[..self.connection_name.len()]
-            .copy_from_slice(unsafe { &*(ptr::from_ref(self.connection_name) as *const [i8]) });
+        self.connection_name.copy_into_mqchar(&mut cd.ConnectionName);
         cd.TransportType = self.transport.value();
         cno.Options &= !sys::MQCNO_LOCAL_BINDING;
         cno.Options |= sys::MQCNO_CLIENT_BINDING;
@@ -177,7 +182,8 @@ impl<'m> ConnectOption<'m> for MqServer<'m> {
 }
 
 /// Connection binding mode connection option. Represents the `MQCNO_*_BINDING` constants.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(rename_all = "lowercase"))]
 pub enum Binding {
     #[default]
     /// MQI default binding
@@ -203,6 +209,37 @@ impl ConnectOption<'_> for Binding {
     }
 }
 
+/// Client reconnect connection option. Represents the `MQCNO_RECONNECT*` constants.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Reconnect {
+    #[default]
+    /// Reconnect as defined by the `DefReconnect` of the channel or the client configuration (`MQCNO_RECONNECT_AS_DEF`)
+    AsDefined,
+    /// Reconnect to any queue manager of the connection name list or queue manager group (`MQCNO_RECONNECT`)
+    Enabled,
+    /// Reconnect only to the same queue manager (`MQCNO_RECONNECT_Q_MGR`)
+    QueueManager,
+    /// Do not reconnect (`MQCNO_RECONNECT_DISABLED`)
+    Disabled,
+}
+
+impl ConnectOption<'_> for Reconnect {
+    fn apply_param<'ptr>(self, structs: &mut ConnectStructs<'ptr>) -> i32
+    where
+        'static: 'ptr,
+    {
+        structs.cno.Options &= !(sys::MQCNO_RECONNECT | sys::MQCNO_RECONNECT_Q_MGR | sys::MQCNO_RECONNECT_DISABLED);
+        structs.cno.Options |= match self {
+            Self::AsDefined => sys::MQCNO_RECONNECT_AS_DEF,
+            Self::Enabled => sys::MQCNO_RECONNECT,
+            Self::QueueManager => sys::MQCNO_RECONNECT_Q_MGR,
+            Self::Disabled => sys::MQCNO_RECONNECT_DISABLED,
+        };
+        HAS_CNO
+    }
+}
+
 impl ConnectOption<'_> for QueueManagerName {
     fn queue_manager_name(&self) -> Option<&QueueManagerName> {
         Some(self)
@@ -335,6 +372,214 @@ impl<'pw> Tls<'pw> {
     }
 }
 
+/// TCP keepalive interval of a client channel
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum KeepAlive {
+    /// Interval derived from the negotiated heartbeat interval (`MQKAI_AUTO`)
+    #[default]
+    Auto,
+    Interval(Duration),
+}
+
+/// Maximum length in bytes of a message sent on a client channel, in the range of 0 to 100 MB
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub struct MaxMsgLength(sys::MQLONG);
+
+impl MaxMsgLength {
+    pub const MAX: Self = Self(104_857_600);
+
+    /// Create a maximum message length. Returns `None` when the length is outside the range of 0 to 100 MB.
+    #[must_use]
+    pub const fn new(length: sys::MQLONG) -> Option<Self> {
+        if length >= 0 && length <= Self::MAX.0 {
+            Some(Self(length))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn value(self) -> sys::MQLONG {
+        self.0
+    }
+}
+
+/// Maximum number of conversations that can share a client channel instance, in the range of 0 to 999 999 999
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub struct SharingConversations(sys::MQLONG);
+
+impl SharingConversations {
+    pub const MAX: Self = Self(999_999_999);
+
+    /// Create a sharing conversations limit. Returns `None` when the limit is outside the range of 0 to 999 999 999.
+    #[must_use]
+    pub const fn new(conversations: sys::MQLONG) -> Option<Self> {
+        if conversations >= 0 && conversations <= Self::MAX.0 {
+            Some(Self(conversations))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn value(self) -> sys::MQLONG {
+        self.0
+    }
+}
+
+/// Interval of a client channel that exceeds the maximum supported by MQ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Error, derive_more::Display)]
+#[display("{field} of {}s exceeds the maximum of {max}s", interval.as_secs())]
+pub struct IntervalRangeError {
+    pub field: &'static str,
+    pub interval: Duration,
+    pub max: sys::MQLONG,
+}
+
+/// Client connection channel definition connection option. Sets the connection as `MQCNO_CLIENT_BINDING`.
+///
+/// The `MQCD` version is raised to the minimum version supporting the fields that have been set.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ClientChannel<'peer>(MqStruct<'peer, sys::MQCD>);
+
+/// Whole seconds of the interval, when it does not exceed `max` seconds
+fn duration_secs(field: &'static str, interval: Duration, max: sys::MQLONG) -> Result<sys::MQLONG, IntervalRangeError> {
+    interval
+        .as_secs()
+        .try_into()
+        .ok()
+        .filter(|&secs| secs <= max)
+        .ok_or(IntervalRangeError { field, interval, max })
+}
+
+/// Compression list padded with `MQCOMPRESS_NOT_AVAILABLE`. Lists longer than the field fail to compile.
+fn compression_list<const M: usize, const N: usize>(list: [values::MQCOMPRESS; M]) -> [sys::MQLONG; N] {
+    const { assert!(M <= N, "Compression list has more entries than the MQCD field") };
+    let mut result = [sys::MQCOMPRESS_NOT_AVAILABLE; N];
+    for (target, compress) in result.iter_mut().zip(list) {
+        *target = compress.value();
+    }
+    result
+}
+
+impl<'peer> ClientChannel<'peer> {
+    /// TCP client channel to the connection name
+    pub fn new(channel: &ChannelName, connection_name: &ConnectionName) -> Self {
+        let mut cd = MqStruct::new(sys::MQCD::client_conn_default());
+        channel.copy_into_mqchar(&mut cd.ChannelName);
+        connection_name.copy_into_mqchar(&mut cd.ConnectionName);
+        cd.set_min_version(sys::MQCD_VERSION_2);
+        cd.TransportType = sys::MQXPT_TCP;
+        Self(cd)
+    }
+
+    pub fn transport(&mut self, transport: values::MQXPT) -> &mut Self {
+        self.0.TransportType = transport.value();
+        self
+    }
+
+    pub fn max_msg_length(&mut self, length: MaxMsgLength) -> &mut Self {
+        self.0.MaxMsgLength = length.value();
+        self
+    }
+
+    /// Heartbeat interval in whole seconds, up to 999 999 seconds
+    pub fn heartbeat_interval(&mut self, interval: Duration) -> Result<&mut Self, IntervalRangeError> {
+        self.0.HeartbeatInterval = duration_secs("Heartbeat interval", interval, 999_999)?;
+        self.0.set_min_version(sys::MQCD_VERSION_4);
+        Ok(self)
+    }
+
+    /// TCP keepalive interval in whole seconds, up to 99 999 seconds
+    pub fn keepalive(&mut self, keepalive: KeepAlive) -> Result<&mut Self, IntervalRangeError> {
+        self.0.KeepAliveInterval = match keepalive {
+            KeepAlive::Auto => sys::MQKAI_AUTO,
+            KeepAlive::Interval(interval) => duration_secs("Keepalive interval", interval, 99_999)?,
+        };
+        self.0.set_min_version(sys::MQCD_VERSION_7);
+        Ok(self)
+    }
+
+    pub fn sharing_conversations(&mut self, conversations: SharingConversations) -> &mut Self {
+        self.0.set_min_version(sys::MQCD_VERSION_9);
+        self.0.SharingConversations = conversations.value();
+        self
+    }
+
+    /// Header compression techniques in order of preference, at most 2
+    pub fn header_compression<const M: usize>(&mut self, list: [values::MQCOMPRESS; M]) -> &mut Self {
+        self.0.set_min_version(sys::MQCD_VERSION_8);
+        self.0.HdrCompList = compression_list(list);
+        self
+    }
+
+    /// Message compression techniques in order of preference, at most 16
+    pub fn message_compression<const M: usize>(&mut self, list: [values::MQCOMPRESS; M]) -> &mut Self {
+        self.0.set_min_version(sys::MQCD_VERSION_8);
+        self.0.MsgCompList = compression_list(list);
+        self
+    }
+
+    pub fn cipher_spec(&mut self, cipher: &CipherSpec) -> &mut Self {
+        self.0.set_min_version(sys::MQCD_VERSION_7);
+        cipher.copy_into_mqchar(&mut self.0.SSLCipherSpec);
+        self
+    }
+
+    pub fn certificate_label(&mut self, label: Option<&CertificateLabel>) -> &mut Self {
+        self.0.set_min_version(sys::MQCD_VERSION_11);
+        match label {
+            Some(cl) => cl.copy_into_mqchar(&mut self.0.CertificateLabel),
+            None => CertificateLabel::default().copy_into_mqchar(&mut self.0.CertificateLabel),
+        }
+        self
+    }
+
+    /// Distinguished name pattern the queue manager certificate must match
+    pub fn ssl_peer_name(&mut self, peer_name: &'peer str) -> &mut Self {
+        self.0.attach_ssl_peer_name(peer_name);
+        self
+    }
+
+    pub fn local_address(&mut self, address: &LocalAddress) -> &mut Self {
+        self.0.set_min_version(sys::MQCD_VERSION_7);
+        address.copy_into_mqchar(&mut self.0.LocalAddress);
+        self
+    }
+
+    pub fn security_exit(&mut self, name: &ExitName, data: Option<&ExitData>) -> &mut Self {
+        name.copy_into_mqchar(&mut self.0.SecurityExit);
+        data.copied()
+            .unwrap_or_default()
+            .copy_into_mqchar(&mut self.0.SecurityUserData);
+        self
+    }
+
+    pub fn send_exit(&mut self, name: &ExitName, data: Option<&ExitData>) -> &mut Self {
+        name.copy_into_mqchar(&mut self.0.SendExit);
+        data.copied().unwrap_or_default().copy_into_mqchar(&mut self.0.SendUserData);
+        self
+    }
+
+    pub fn receive_exit(&mut self, name: &ExitName, data: Option<&ExitData>) -> &mut Self {
+        name.copy_into_mqchar(&mut self.0.ReceiveExit);
+        data.copied()
+            .unwrap_or_default()
+            .copy_into_mqchar(&mut self.0.ReceiveUserData);
+        self
+    }
+}
+
+impl<'peer> ConnectOption<'peer> for ClientChannel<'peer> {
+    fn apply_param<'ptr>(self, structs: &mut ConnectStructs<'ptr>) -> i32
+    where
+        'peer: 'ptr,
+    {
+        self.0.apply_param(structs)
+    }
+}
+
 impl ConnectOption<'_> for CipherSpec {
     fn apply_param<'ptr>(self, structs: &mut ConnectStructs<'ptr>) -> i32
     where
@@ -584,6 +829,15 @@ pub fn mqserver(server: &str) -> Result<(ChannelName, ConnectionName, values::MQ
             "SPX" => Ok(values::MQXPT(sys::MQXPT_SPX)),
             other => Err(MqServerSyntaxError::UnrecognizedTransport(other.to_string())),
         }?;
+        // TCP connection names are normalised from the validated list of addresses
+        let connection_name = if transport == values::MQXPT(sys::MQXPT_TCP) {
+            connection_name
+                .addresses()
+                .and_then(|addresses| ConnectionName::from_addresses(&addresses))
+                .map_err(MqServerSyntaxError::ConnectionNameList)?
+        } else {
+            connection_name
+        };
         Ok((channel, connection_name, transport))
     } else {
         Err(MqServerSyntaxError::InvalidFormat)
@@ -603,11 +857,20 @@ pub enum MqServerSyntaxError {
     #[display("Transport \"{_0}\" not recognized")]
     #[error(ignore)]
     UnrecognizedTransport(String),
+    #[display("Connection Name list invalid: {_0}")]
+    ConnectionNameList(ConnectionNameError),
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{MqServer, MqServerSyntaxError, ProtectedSecret, Secret as _};
+    use std::{error::Error, time::Duration};
+
+    use crate::{sys, types::ConnectionNameError, values::MQCOMPRESS};
+
+    use super::{
+        ClientChannel, ConnectOption as _, ConnectStructs, IntervalRangeError, KeepAlive, MaxMsgLength, MqServer,
+        MqServerSyntaxError, ProtectedSecret, Reconnect, Secret as _, SharingConversations,
+    };
 
     #[test]
     fn secret() {
@@ -620,6 +883,81 @@ mod tests {
         let mqserver = MqServer::try_from("a/TCP/c")?;
         assert!(mqserver.channel_name.len() == 1);
 
+        let (.., connection_name, _) = super::mqserver("DEV.APP.SVRCONN/TCP/host1(1414),[::1](1415)")?;
+        assert_eq!(connection_name.to_string(), "host1(1414),::1(1415)");
+
+        assert!(matches!(
+            MqServer::try_from("DEV.APP.SVRCONN/TCP/host1(1414),,host2(1414)"),
+            Err(MqServerSyntaxError::ConnectionNameList(ConnectionNameError::Empty(1)))
+        ));
+        assert!(matches!(
+            super::mqserver("DEV.APP.SVRCONN/TCP/host1(99999)"),
+            Err(MqServerSyntaxError::ConnectionNameList(ConnectionNameError::Port(_)))
+        ));
+        assert!(MqServer::try_from("DEV.APP.SVRCONN/LU62/partner(lu)").is_ok());
+
         Ok(())
     }
+
+    #[test]
+    fn client_channel() -> Result<(), Box<dyn Error>> {
+        let mut channel = ClientChannel::new(&"DEV.APP.SVRCONN".parse()?, &"localhost(1414)".parse()?);
+        assert_eq!(
+            channel.0.Version,
+            sys::MQCD_VERSION_2.max(sys::MQCD::client_conn_default().Version)
+        );
+
+        channel
+            .heartbeat_interval(Duration::from_secs(30))?
+            .keepalive(KeepAlive::Auto)?
+            .header_compression([MQCOMPRESS(sys::MQCOMPRESS_SYSTEM)])
+            .sharing_conversations(SharingConversations::new(1).ok_or("sharing conversations should be valid")?)
+            .ssl_peer_name("CN=QM1");
+        assert_eq!(
+            channel.0.Version,
+            sys::MQCD_VERSION_9.max(sys::MQCD::client_conn_default().Version)
+        );
+        assert_eq!(channel.0.HeartbeatInterval, 30);
+        assert_eq!(channel.0.KeepAliveInterval, sys::MQKAI_AUTO);
+        assert_eq!(channel.0.HdrCompList, [sys::MQCOMPRESS_SYSTEM, sys::MQCOMPRESS_NOT_AVAILABLE]);
+        assert_eq!(channel.0.SSLPeerNameLength, 7);
+
+        assert_eq!(
+            channel.keepalive(KeepAlive::Interval(Duration::from_secs(100_000))).err(),
+            Some(IntervalRangeError {
+                field: "Keepalive interval",
+                interval: Duration::from_secs(100_000),
+                max: 99_999
+            })
+        );
+        assert_eq!(channel.0.KeepAliveInterval, sys::MQKAI_AUTO);
+        assert_eq!(MaxMsgLength::new(-1), None);
+        assert_eq!(SharingConversations::new(1_000_000_000), None);
+
+        channel.certificate_label(Some(&"ibmwebspheremqapp".parse()?));
+        assert_eq!(
+            channel.0.Version,
+            sys::MQCD_VERSION_11.max(sys::MQCD::client_conn_default().Version)
+        );
+
+        let mut structs = ConnectStructs::default();
+        channel.apply_param(&mut structs);
+        assert_eq!(structs.cno.Options & sys::MQCNO_CLIENT_BINDING, sys::MQCNO_CLIENT_BINDING);
+        assert_eq!(structs.cd.SharingConversations, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reconnect() {
+        let mut structs = ConnectStructs::default();
+        (Reconnect::QueueManager, Reconnect::Disabled).apply_param(&mut structs);
+        assert_eq!(
+            structs.cno.Options & (sys::MQCNO_RECONNECT | sys::MQCNO_RECONNECT_Q_MGR | sys::MQCNO_RECONNECT_DISABLED),
+            sys::MQCNO_RECONNECT_Q_MGR
+        );
+
+        Reconnect::AsDefined.apply_param(&mut structs);
+        assert_eq!(structs.cno.Options & sys::MQCNO_RECONNECT_Q_MGR, 0);
+    }
 }
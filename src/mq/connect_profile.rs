@@ -0,0 +1,454 @@
+use std::{env, fmt, fs, io, path::PathBuf, str::FromStr};
+
+use crate::{
+    sys,
+    types::{CertificateLabel, ChannelName, CipherSpec, ConnectionName, ConnectionNameError, KeyRepo, QueueManagerName},
+    values::{self, MQBNOBALTYPE, MQBNOOPTIONS, MQBNOTIMEOUT},
+    MqStrError, MqStruct,
+};
+
+use super::connect_options::{
+    self, ApplName, Binding, Ccdt, ClientChannel, ConnectOption, Credentials, MqServerSyntaxError, Reconnect, Tls,
+};
+
+/// Connection settings loaded from a configuration file and the environment, producing a [`ConnectOption`].
+///
+/// With the `serde` feature the profile can be deserialized from any serde format, such as TOML or JSON.
+/// [`ConnectionProfile::with_env`] overlays the standard MQ environment variables.
+/// The [`Debug`](fmt::Debug) output redacts the password.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default, deny_unknown_fields))]
+pub struct ConnectionProfile {
+    pub queue_manager: Option<String>,
+    /// Client connection in the `MQSERVER` format of `CHANNEL/TRANSPORT/CONNAME`
+    pub mqserver: Option<String>,
+    pub channel: Option<String>,
+    /// Connection names of the client channel, such as `host1(1414)`
+    pub connection_names: Vec<String>,
+    pub ccdt_url: Option<String>,
+    pub binding: Binding,
+    pub tls: Option<TlsProfile>,
+    pub user: Option<String>,
+    pub password: Option<String>,
+    /// File holding the password, read by [`ConnectionProfile::resolve_password`]
+    pub password_file: Option<PathBuf>,
+    /// Environment variable holding the password, read by [`ConnectionProfile::resolve_password`]
+    pub password_env: Option<String>,
+    pub application_name: Option<String>,
+    pub reconnect: Option<Reconnect>,
+    /// Additional `MQCNO` options
+    pub options: Option<values::MQCNO>,
+    pub balance: Option<BalanceProfile>,
+}
+
+/// TLS settings of a [`ConnectionProfile`]. The [`Debug`](fmt::Debug) output redacts the key repository password.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default, deny_unknown_fields))]
+pub struct TlsProfile {
+    pub key_repository: Option<String>,
+    pub key_repository_password: Option<String>,
+    pub certificate_label: Option<String>,
+    pub cipher_spec: Option<String>,
+    pub fips_required: bool,
+}
+
+/// Debug representation of an optional secret
+fn redacted(secret: Option<&String>) -> Option<&'static str> {
+    secret.map(|_| "<REDACTED>")
+}
+
+impl fmt::Debug for ConnectionProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionProfile")
+            .field("queue_manager", &self.queue_manager)
+            .field("mqserver", &self.mqserver)
+            .field("channel", &self.channel)
+            .field("connection_names", &self.connection_names)
+            .field("ccdt_url", &self.ccdt_url)
+            .field("binding", &self.binding)
+            .field("tls", &self.tls)
+            .field("user", &self.user)
+            .field("password", &redacted(self.password.as_ref()))
+            .field("password_file", &self.password_file)
+            .field("password_env", &self.password_env)
+            .field("application_name", &self.application_name)
+            .field("reconnect", &self.reconnect)
+            .field("options", &self.options)
+            .field("balance", &self.balance)
+            .finish()
+    }
+}
+
+impl fmt::Debug for TlsProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TlsProfile")
+            .field("key_repository", &self.key_repository)
+            .field("key_repository_password", &redacted(self.key_repository_password.as_ref()))
+            .field("certificate_label", &self.certificate_label)
+            .field("cipher_spec", &self.cipher_spec)
+            .field("fips_required", &self.fips_required)
+            .finish()
+    }
+}
+
+/// Uniform cluster connection balancing parameters of the `MQBNO`
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default, deny_unknown_fields))]
+pub struct BalanceProfile {
+    pub application_type: Option<MQBNOBALTYPE>,
+    pub timeout: Option<MQBNOTIMEOUT>,
+    pub options: Option<MQBNOOPTIONS>,
+}
+
+/// Invalid field of a [`ConnectionProfile`]
+#[derive(Debug, derive_more::Error, derive_more::Display)]
+#[display("Connection profile field `{field}` is invalid: {reason}")]
+pub struct ProfileError {
+    pub field: &'static str,
+    #[error(source)]
+    pub reason: ProfileErrorReason,
+}
+
+#[derive(Debug, derive_more::Error, derive_more::Display, derive_more::From)]
+pub enum ProfileErrorReason {
+    #[display("a value is required")]
+    Required,
+    #[display("it conflicts with `{_0}`")]
+    #[error(ignore)]
+    Conflict(&'static str),
+    #[display("environment variable {_0} is not set")]
+    #[error(ignore)]
+    MissingVariable(String),
+    #[display("the password has not been resolved with `resolve_password`")]
+    Unresolved,
+    #[from]
+    Length(MqStrError),
+    #[from]
+    MqServer(MqServerSyntaxError),
+    #[from]
+    ConnectionName(ConnectionNameError),
+    #[from]
+    Io(io::Error),
+}
+
+impl ProfileError {
+    fn new(field: &'static str, reason: impl Into<ProfileErrorReason>) -> Self {
+        Self {
+            field,
+            reason: reason.into(),
+        }
+    }
+}
+
+fn parse<T: FromStr<Err = MqStrError>>(field: &'static str, value: &str) -> Result<T, ProfileError> {
+    value.parse().map_err(|e| ProfileError::new(field, e))
+}
+
+impl ConnectionProfile {
+    /// Profile using only the MQ environment variables
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::default().with_env()
+    }
+
+    /// Overlay the `MQSERVER`, `MQCCDTURL`, `MQSSLKEYR`, `MQCERTLABL` and `MQSAMP_USER_ID` environment
+    /// variables onto the profile. Variables that are set take precedence over the profile.
+    #[must_use]
+    pub fn with_env(self) -> Self {
+        self.with_vars(|name| env::var(name).ok())
+    }
+
+    fn with_vars(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(server) = var("MQSERVER") {
+            self.mqserver = Some(server);
+            self.channel = None;
+            self.connection_names.clear();
+        }
+        if let Some(url) = var("MQCCDTURL") {
+            self.ccdt_url = Some(url);
+        }
+        if let Some(repo) = var("MQSSLKEYR") {
+            self.tls.get_or_insert_with(TlsProfile::default).key_repository = Some(repo);
+        }
+        if let Some(label) = var("MQCERTLABL") {
+            self.tls.get_or_insert_with(TlsProfile::default).certificate_label = Some(label);
+        }
+        if let Some(user) = var("MQSAMP_USER_ID") {
+            self.user = Some(user);
+        }
+        self
+    }
+
+    /// Read the password from the `password_file` or `password_env` source into the `password` field
+    pub fn resolve_password(self) -> Result<Self, ProfileError> {
+        self.resolve_password_vars(|name| env::var(name).ok())
+    }
+
+    fn resolve_password_vars(mut self, var: impl Fn(&str) -> Option<String>) -> Result<Self, ProfileError> {
+        match (self.password.is_some(), self.password_file.take(), self.password_env.take()) {
+            (_, Some(_), Some(_)) => {
+                return Err(ProfileError::new(
+                    "password_env",
+                    ProfileErrorReason::Conflict("password_file"),
+                ))
+            }
+            (true, Some(_), None) => return Err(ProfileError::new("password_file", ProfileErrorReason::Conflict("password"))),
+            (true, None, Some(_)) => return Err(ProfileError::new("password_env", ProfileErrorReason::Conflict("password"))),
+            (false, Some(file), None) => {
+                let password = fs::read_to_string(file).map_err(|e| ProfileError::new("password_file", e))?;
+                self.password = Some(password.trim_end_matches(['\r', '\n']).to_string());
+            }
+            (false, None, Some(name)) => {
+                self.password =
+                    Some(var(&name).ok_or_else(|| ProfileError::new("password_env", ProfileErrorReason::MissingVariable(name)))?);
+            }
+            (_, None, None) => (),
+        }
+        Ok(self)
+    }
+
+    /// Validate the profile and produce the [`ConnectOption`] for connecting to the queue manager
+    pub fn connect_option(&self) -> Result<impl ConnectOption<'_>, ProfileError> {
+        if self.password_file.is_some() {
+            return Err(ProfileError::new("password_file", ProfileErrorReason::Unresolved));
+        }
+        if self.password_env.is_some() {
+            return Err(ProfileError::new("password_env", ProfileErrorReason::Unresolved));
+        }
+
+        let queue_manager = self
+            .queue_manager
+            .as_deref()
+            .map(|name| parse::<QueueManagerName>("queue_manager", name))
+            .transpose()?;
+        let cd = self.client_channel()?;
+        let ccdt = self.ccdt_url.as_deref().map(Ccdt);
+        match (cd.is_some(), ccdt.is_some(), self.binding) {
+            (true, true, _) => Err(ProfileError::new("ccdt_url", ProfileErrorReason::Conflict("channel"))),
+            (true, _, Binding::Local) => Err(ProfileError::new("binding", ProfileErrorReason::Conflict("channel"))),
+            (_, true, Binding::Local) => Err(ProfileError::new("binding", ProfileErrorReason::Conflict("ccdt_url"))),
+            _ => Ok(()),
+        }?;
+
+        let credentials = match (&self.user, &self.password) {
+            (Some(user), password) => Some(Credentials::<&str>::user(user, password.as_deref().unwrap_or(""))),
+            (None, Some(_)) => return Err(ProfileError::new("user", ProfileErrorReason::Required)),
+            (None, None) => None,
+        };
+        let application_name = self
+            .application_name
+            .as_deref()
+            .map(|name| parse::<ApplName>("application_name", name))
+            .transpose()?;
+        let tls = self.tls.as_ref().map(TlsProfile::tls).transpose()?;
+        let balance = self.balance.as_ref().map(|balance| {
+            let mut bno = MqStruct::<sys::MQBNO>::default();
+            if let Some(application_type) = balance.application_type {
+                bno.ApplType = application_type.value();
+            }
+            if let Some(timeout) = balance.timeout {
+                bno.Timeout = timeout.value();
+            }
+            if let Some(options) = balance.options {
+                bno.Options = options.value();
+            }
+            bno
+        });
+
+        // Options are applied right to left, so the binding is applied before the client channel
+        Ok((
+            (queue_manager, tls, credentials, application_name),
+            (balance, self.reconnect, self.options, ccdt, cd, self.binding),
+        ))
+    }
+
+    fn client_channel(&self) -> Result<Option<ClientChannel<'static>>, ProfileError> {
+        let (channel, connection_name, transport) = match (&self.mqserver, &self.channel, self.connection_names.is_empty()) {
+            (None, None, true) => return Ok(None),
+            (Some(_), Some(_), _) => return Err(ProfileError::new("mqserver", ProfileErrorReason::Conflict("channel"))),
+            (Some(_), None, false) => {
+                return Err(ProfileError::new(
+                    "mqserver",
+                    ProfileErrorReason::Conflict("connection_names"),
+                ))
+            }
+            (None, Some(_), true) => return Err(ProfileError::new("connection_names", ProfileErrorReason::Required)),
+            (None, None, false) => return Err(ProfileError::new("channel", ProfileErrorReason::Required)),
+            (Some(server), None, true) => connect_options::mqserver(server).map_err(|e| ProfileError::new("mqserver", e))?,
+            (None, Some(channel), false) => (
+                parse::<ChannelName>("channel", channel)?,
+                ConnectionName::from_addresses_str(&self.connection_names.join(","))
+                    .map_err(|e| ProfileError::new("connection_names", e))?,
+                values::MQXPT(sys::MQXPT_TCP),
+            ),
+        };
+
+        let mut cd = ClientChannel::new(&channel, &connection_name);
+        cd.transport(transport);
+        Ok(Some(cd))
+    }
+}
+
+impl TlsProfile {
+    fn tls(&self) -> Result<Tls<'_>, ProfileError> {
+        let repo = self
+            .key_repository
+            .as_deref()
+            .ok_or_else(|| ProfileError::new("tls.key_repository", ProfileErrorReason::Required))
+            .and_then(|repo| parse::<KeyRepo>("tls.key_repository", repo))?;
+        let label = self
+            .certificate_label
+            .as_deref()
+            .map(|label| parse::<CertificateLabel>("tls.certificate_label", label))
+            .transpose()?;
+        let cipher = self
+            .cipher_spec
+            .as_deref()
+            .map_or_else(|| Ok(CipherSpec::default()), |cipher| parse("tls.cipher_spec", cipher))?;
+
+        let mut tls = Tls::new(&repo, self.key_repository_password.as_deref(), label.as_ref(), &cipher);
+        if self.fips_required {
+            tls.fips_required(true);
+        }
+        Ok(tls)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::connect_options::{Binding, ConnectOption as _, ConnectStructs};
+    use crate::sys;
+
+    use super::{ConnectionProfile, ProfileErrorReason, TlsProfile};
+
+    fn vars(name: &str) -> Option<String> {
+        match name {
+            "MQSERVER" => Some("DEV.APP.SVRCONN/TCP/localhost(1414)".to_string()),
+            "MQSSLKEYR" => Some("/var/mqm/key".to_string()),
+            "MQSAMP_USER_ID" => Some("app".to_string()),
+            "APP_PASSWORD" => Some("passw0rd".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn env() -> Result<(), Box<dyn Error>> {
+        let profile = ConnectionProfile {
+            channel: Some("OTHER.SVRCONN".to_string()),
+            connection_names: vec!["otherhost(1414)".to_string()],
+            password_env: Some("APP_PASSWORD".to_string()),
+            ..ConnectionProfile::default()
+        }
+        .with_vars(vars)
+        .resolve_password_vars(vars)?;
+
+        assert_eq!(profile.mqserver.as_deref(), Some("DEV.APP.SVRCONN/TCP/localhost(1414)"));
+        assert_eq!(profile.channel, None);
+        assert_eq!(profile.user.as_deref(), Some("app"));
+        assert_eq!(profile.password.as_deref(), Some("passw0rd"));
+
+        let mut structs = ConnectStructs::default();
+        profile.connect_option()?.apply_param(&mut structs);
+        assert_eq!(structs.cno.Options & sys::MQCNO_CLIENT_BINDING, sys::MQCNO_CLIENT_BINDING);
+        assert_eq!(structs.cd.TransportType, sys::MQXPT_TCP);
+        assert_eq!(structs.csp.AuthenticationType, sys::MQCSP_AUTH_USER_ID_AND_PWD);
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_redacts_secrets() {
+        let profile = ConnectionProfile {
+            password: Some("passw0rd".to_string()),
+            tls: Some(TlsProfile {
+                key_repository_password: Some("keyp4ss".to_string()),
+                ..TlsProfile::default()
+            }),
+            ..ConnectionProfile::default()
+        };
+        let debug = format!("{profile:?}");
+        assert!(!debug.contains("passw0rd"));
+        assert!(!debug.contains("keyp4ss"));
+        assert!(debug.contains(r#"password: Some("<REDACTED>")"#));
+        assert!(format!("{:?}", ConnectionProfile::default()).contains("password: None"));
+    }
+
+    #[test]
+    fn validation() {
+        let error = ConnectionProfile {
+            channel: Some("DEV.APP.SVRCONN".to_string()),
+            ..ConnectionProfile::default()
+        }
+        .connect_option()
+        .err();
+        assert!(matches!(
+            error,
+            Some(ref e) if e.field == "connection_names" && matches!(e.reason, ProfileErrorReason::Required)
+        ));
+
+        let error = ConnectionProfile {
+            channel: Some("A.CHANNEL.NAME.LONGER.THAN.20".to_string()),
+            connection_names: vec!["localhost".to_string()],
+            ..ConnectionProfile::default()
+        }
+        .connect_option()
+        .err();
+        assert_eq!(error.map(|e| e.field), Some("channel"));
+
+        let error = ConnectionProfile {
+            channel: Some("DEV.APP.SVRCONN".to_string()),
+            connection_names: vec!["host1(1414)".to_string(), "host2(port)".to_string()],
+            ..ConnectionProfile::default()
+        }
+        .connect_option()
+        .err();
+        assert_eq!(error.map(|e| e.field), Some("connection_names"));
+
+        let error = ConnectionProfile {
+            ccdt_url: Some("file:///var/mqm/ccdt.json".to_string()),
+            binding: Binding::Local,
+            ..ConnectionProfile::default()
+        }
+        .connect_option()
+        .err();
+        assert_eq!(
+            error.map(|e| e.to_string()).as_deref(),
+            Some("Connection profile field `binding` is invalid: it conflicts with `ccdt_url`")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() -> Result<(), Box<dyn Error>> {
+        let profile: ConnectionProfile = serde_json::from_str(
+            r#"{
+                "queue_manager": "QM1",
+                "channel": "DEV.APP.SVRCONN",
+                "connection_names": ["host1(1414)", "host2(1414)"],
+                "binding": "client",
+                "application_name": "orders",
+                "reconnect": "queue_manager",
+                "options": "SHARED_BINDING",
+                "balance": { "application_type": "REQREP", "timeout": 10 },
+                "tls": { "key_repository": "/var/mqm/key", "cipher_spec": "ANY_TLS13" }
+            }"#,
+        )?;
+
+        let option = profile.connect_option()?;
+        assert_eq!(option.queue_manager_name(), Some(&"QM1".parse()?));
+
+        let mut structs = ConnectStructs::default();
+        option.apply_param(&mut structs);
+        assert_eq!(
+            structs.cno.Options & (sys::MQCNO_RECONNECT_Q_MGR | sys::MQCNO_SHARED_BINDING),
+            sys::MQCNO_RECONNECT_Q_MGR | sys::MQCNO_SHARED_BINDING
+        );
+        assert_eq!(structs.bno.ApplType, sys::MQBNO_BALTYPE_REQREP);
+        assert_eq!(structs.bno.Timeout, 10);
+
+        assert!(serde_json::from_str::<ConnectionProfile>(r#"{ "chanel": "DEV.APP.SVRCONN" }"#).is_err());
+        Ok(())
+    }
+}
@@ -8,7 +8,8 @@ use crate::{
     sys,
     types::{self, Fmt, MessageFormat, MessageId},
     values::{self, CCSID},
-    Buffer, Completion, Conn, Error, MqStruct, MqiAttr, MqiValue, Object, ResultComp, ResultCompErr, StrCcsidCow,
+    Buffer, Completion, Conn, Error, MqStruct, MqiAttr, MqiValue, Object, Persistence, Priority, PutTimestamp, ResultComp,
+    ResultCompErr, StrCcsidCow,
 };
 
 #[derive(Clone, Debug)]
@@ -284,6 +285,71 @@ impl<S> MqiAttr<GetParam, S> for MessageId {
     }
 }
 
+/// Implement a get attribute that is derived from the returned `MQMD2`
+macro_rules! impl_get_md_attr {
+    ($ty:ty, |$md:ident| $value:expr) => {
+        impl<S> MqiAttr<GetParam, S> for $ty {
+            fn extract<F>(param: &mut GetParam, get: F) -> ResultComp<(Self, S)>
+            where
+                F: FnOnce(&mut GetParam) -> ResultComp<S>,
+            {
+                get(param).map_completion(|state| {
+                    let $md = &param.md;
+                    ($value, state)
+                })
+            }
+        }
+    };
+}
+
+impl_get_md_attr!(types::CorrelationId, |md| Self(md.CorrelId.into()));
+impl_get_md_attr!(types::GroupId, |md| Self(md.GroupId.into()));
+impl_get_md_attr!(types::MsgSeqNumber, |md| Self(md.MsgSeqNumber));
+impl_get_md_attr!(types::BackoutCount, |md| Self(md.BackoutCount));
+impl_get_md_attr!(types::PutApplName, |md| Self(md.PutApplName.into()));
+impl_get_md_attr!(types::ReplyTo, |md| Self::new(md.ReplyToQ, md.ReplyToQMgr));
+impl_get_md_attr!(Option<types::UserIdentifier>, |md| types::UserIdentifier::new(
+    md.UserIdentifier
+));
+impl_get_md_attr!(Option<PutTimestamp>, |md| PutTimestamp::from_mqmd(
+    &md.PutDate.into(),
+    &md.PutTime.into()
+));
+// The queue manager only returns persistent or non-persistent
+impl_get_md_attr!(Persistence, |md| if md.Persistence == sys::MQPER_PERSISTENT {
+    Self::Persistent
+} else {
+    Self::NotPersistent
+});
+impl_get_md_attr!(Priority, |md| Self::saturating_from(md.Priority));
+impl_get_md_attr!(values::MQPER, |md| Self(md.Persistence));
+impl_get_md_attr!(values::MQPRI, |md| Self(md.Priority));
+impl_get_md_attr!(values::MQEI, |md| Self(md.Expiry));
+impl_get_md_attr!(values::MQFB, |md| Self(md.Feedback));
+impl_get_md_attr!(values::MQMF, |md| Self(md.MsgFlags));
+impl_get_md_attr!(values::MQMT, |md| Self(md.MsgType));
+impl_get_md_attr!(values::MQRO, |md| Self(md.Report));
+
+/// Implement a get attribute that is derived from the returned `MQGMO`
+macro_rules! impl_get_gmo_attr {
+    ($ty:ty, |$gmo:ident| $value:expr) => {
+        impl<S> MqiAttr<GetParam, S> for $ty {
+            fn extract<F>(param: &mut GetParam, get: F) -> ResultComp<(Self, S)>
+            where
+                F: FnOnce(&mut GetParam) -> ResultComp<S>,
+            {
+                get(param).map_completion(|state| {
+                    let $gmo = &param.gmo;
+                    ($value, state)
+                })
+            }
+        }
+    };
+}
+
+impl_get_gmo_attr!(values::MQGS, |gmo| Self(gmo.GroupStatus.into()));
+impl_get_gmo_attr!(values::MQSS, |gmo| Self(gmo.SegmentStatus.into()));
+
 /// A trait that manipulates the parameters to the [`mqget`](`crate::core::MqFunctions::mqget`) function
 #[diagnostic::on_unimplemented(message = "{Self} does not implement `GetOption` so it can't be used as an argument for MQI get")]
 pub trait GetOption {
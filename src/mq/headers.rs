@@ -30,12 +30,18 @@ const fn cstr_array<const N: usize>(mqi: &CStr) -> [u8; N] {
 #[expect(clippy::cast_possible_wrap)]
 const INTEGER_NATIVE_MASK: sys::MQLONG = sys::MQENC_NATIVE & (sys::MQENC_INTEGER_MASK as sys::MQLONG);
 
+pub mod rfh2;
+
 pub mod fmt {
     use crate::{sys, types::Fmt};
     use super::cstr_array;
 
     pub const MQFMT_NONE: Fmt = cstr_array(sys::MQFMT_NONE);
     pub const MQFMT_STRING: Fmt = cstr_array(sys::MQFMT_STRING);
+    pub const MQFMT_ADMIN: Fmt = cstr_array(sys::MQFMT_ADMIN);
+    pub const MQFMT_EVENT: Fmt = cstr_array(sys::MQFMT_EVENT);
+    pub const MQFMT_PCF: Fmt = cstr_array(sys::MQFMT_PCF);
+    pub const MQFMT_EMBEDDED_PCF: Fmt = cstr_array(sys::MQFMT_EMBEDDED_PCF);
 }
 
 #[derive(derive_more::Error, derive_more::Display, Debug, Clone)]
@@ -0,0 +1,564 @@
+use std::{borrow::Cow, collections::BTreeMap, fmt::Write as _};
+
+use crate::{
+    prelude::*,
+    properties_options::{Name, Value, INQUIRE_ALL},
+    sys,
+    values::{CCSID, MQIMPO, MQSMPO},
+    Completion, Conn, Error, FromStringCcsidError, Properties, ResultComp, StrCcsid, StrCcsidOwned, StringCcsid,
+};
+
+use super::EncodedHeader;
+
+/// RFH2 folders that can be represented as message properties
+pub const PROPERTY_FOLDERS: &[&str] = &["usr", "jms"];
+
+/// Reserved property folders that are not mapped to the `usr` folder
+const RESERVED_FOLDERS: &[&str] = &["jms", "mcd", "mqext", "mqps", "mqpse", "psc", "pscr", "sib", "usr"];
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum Rfh2Error {
+    #[display("Malformed NameValueData at offset {_0}: {_1}")]
+    #[error(ignore)]
+    Malformed(usize, &'static str),
+    #[display("Unsupported data type '{_1}' for property {_0}")]
+    #[error(ignore)]
+    UnsupportedType(String, String),
+    #[display("Invalid {_1} value '{_2}' for property {_0}")]
+    #[error(ignore)]
+    InvalidValue(String, &'static str, String),
+    #[display("Property {_0} is not in a usr or jms folder")]
+    #[error(ignore)]
+    InvalidName(String),
+    #[from]
+    NameValueCcsid(FromStringCcsidError),
+}
+
+/// In memory map of the properties held in the `usr` and `jms` folders of an RFH2 header.
+///
+/// Property names are fully qualified with the folder, such as `usr.colour` or `jms.Dst`.
+/// Nested groups in a folder are separated with a `.`.
+#[derive(Debug, Clone, Default, derive_more::Deref, derive_more::DerefMut, derive_more::From)]
+pub struct Rfh2Properties(pub BTreeMap<String, Value>);
+
+impl<'a> EncodedHeader<'a, sys::MQRFH2> {
+    /// Iterate over each length delimited `NameValueData` folder in the RFH2 header
+    pub fn name_value_folders(&self) -> impl Iterator<Item = Result<StrCcsid<'a>, Rfh2Error>> + 'a {
+        let ccsid = CCSID(self.native_mqlong(self.raw_header.NameValueCCSID));
+        let le = (self.encoding & sys::MQENC_INTEGER_REVERSED) != 0;
+        let native = (self.encoding & super::INTEGER_NATIVE_MASK) != 0;
+        let mut tail = self.tail;
+        let mut offset = 0;
+
+        std::iter::from_fn(move || {
+            if tail.is_empty() {
+                return None;
+            }
+            let Some((length, data)) = tail.split_first_chunk::<4>() else {
+                tail = &[];
+                return Some(Err(Rfh2Error::Malformed(offset, "NameValueLength is truncated")));
+            };
+            let length = super::swap_to_native(sys::MQLONG::from_ne_bytes(*length), native);
+            match usize::try_from(length) {
+                Ok(length) if length <= data.len() => {
+                    let (folder, remaining) = data.split_at(length);
+                    tail = remaining;
+                    offset += length + 4;
+                    Some(Ok(StringCcsid::new(folder, ccsid, le)))
+                }
+                _ => {
+                    tail = &[];
+                    Some(Err(Rfh2Error::Malformed(offset, "NameValueLength exceeds the header length")))
+                }
+            }
+        })
+    }
+}
+
+impl Rfh2Properties {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// Read the `usr` and `jms` folders of an RFH2 header. Other folders are ignored.
+    pub fn from_header(header: &EncodedHeader<sys::MQRFH2>) -> Result<Self, Rfh2Error> {
+        let mut result = Self::new();
+        for folder in header.name_value_folders() {
+            let xml: Cow<str> = folder?.try_into()?;
+            result.parse_folder(&xml)?;
+        }
+        Ok(result)
+    }
+
+    /// Parse a single `NameValueData` folder, retaining properties from the `usr` and `jms` folders.
+    pub fn parse_folder(&mut self, xml: &str) -> Result<(), Rfh2Error> {
+        let mut parser = Parser { data: xml, pos: 0 };
+        let (folder, properties) = parser.folder()?;
+        if PROPERTY_FOLDERS.contains(&folder.as_str()) {
+            self.0.extend(
+                properties
+                    .into_iter()
+                    .map(|(name, value)| (format!("{folder}.{name}"), value)),
+            );
+        }
+        Ok(())
+    }
+
+    /// Render the properties as RFH2 `NameValueData` folders, one `String` for each folder
+    pub fn folders(&self) -> Result<Vec<String>, Rfh2Error> {
+        let mut result = Vec::new();
+        for &folder in PROPERTY_FOLDERS {
+            let prefix = format!("{folder}.");
+            let mut xml = String::new();
+            // Groups are contiguous as the keys of the map are ordered
+            let mut open: Vec<&str> = Vec::new();
+
+            for (name, value) in self
+                .0
+                .range(prefix.clone()..)
+                .take_while(|(name, ..)| name.starts_with(&prefix))
+            {
+                let path: Vec<&str> = name[prefix.len()..].split('.').collect();
+                let (leaf, groups) = path.split_last().ok_or_else(|| Rfh2Error::InvalidName(name.clone()))?;
+                let common = open.iter().zip(groups).take_while(|(a, b)| a == b).count();
+                for group in open.drain(common..).rev() {
+                    write!(xml, "</{group}>").expect("write to String should not fail");
+                }
+                for &group in &groups[common..] {
+                    write!(xml, "<{group}>").expect("write to String should not fail");
+                    open.push(group);
+                }
+                render_element(&mut xml, name, leaf, value)?;
+            }
+
+            if !xml.is_empty() {
+                for group in open.drain(..).rev() {
+                    write!(xml, "</{group}>").expect("write to String should not fail");
+                }
+                result.push(format!("<{folder}>{xml}</{folder}>"));
+            }
+        }
+        Ok(result)
+    }
+
+    /// Render the properties as the `NameValueLength` and `NameValueData` pairs that follow the fixed
+    /// portion of an RFH2 header, using native encoding and padding each folder to a multiple of 4 bytes.
+    pub fn name_value_data(&self) -> Result<Vec<u8>, Rfh2Error> {
+        let mut data = Vec::new();
+        for mut folder in self.folders()? {
+            while folder.len() % 4 != 0 {
+                folder.push(' ');
+            }
+            let length = sys::MQLONG::try_from(folder.len()).expect("folder length should fit within MQLONG range");
+            data.extend_from_slice(&length.to_ne_bytes());
+            data.extend_from_slice(folder.as_bytes());
+        }
+        Ok(data)
+    }
+
+    /// Set all the properties on a message handle
+    pub fn apply_to<C: Conn>(&self, properties: &Properties<C>, location: MQSMPO) -> ResultComp<()> {
+        let mut warning = None;
+        for (name, value) in &self.0 {
+            let Completion((), property_warning) = properties.set_property(name.as_str(), value, location)?;
+            warning = warning.or(property_warning);
+        }
+        Ok(Completion((), warning))
+    }
+
+    /// Read the `usr` and `jms` properties from a message handle.
+    ///
+    /// Properties without a folder prefix belong to the `usr` folder.
+    pub fn from_properties<C: Conn>(properties: &Properties<C>) -> ResultComp<Self> {
+        let mut result = Self::new();
+        let mut warning = None;
+        for property in properties.property_iter::<(Value, Name<String>), _>(INQUIRE_ALL, MQIMPO::default()) {
+            let Completion((value, Name(name)), property_warning) = property?;
+            warning = warning.or(property_warning);
+            match name.split_once('.') {
+                Some((folder, ..)) if PROPERTY_FOLDERS.contains(&folder) => {
+                    result.0.insert(name, value);
+                }
+                Some((folder, ..)) if RESERVED_FOLDERS.contains(&folder) => {}
+                _ => {
+                    result.0.insert(format!("usr.{name}"), value);
+                }
+            }
+        }
+        Ok(Completion(result, warning))
+    }
+}
+
+impl<C: Conn> TryFrom<&Properties<C>> for Rfh2Properties {
+    type Error = Error;
+
+    fn try_from(value: &Properties<C>) -> Result<Self, Self::Error> {
+        Self::from_properties(value).warn_as_error()
+    }
+}
+
+fn render_element(xml: &mut String, name: &str, element: &str, value: &Value) -> Result<(), Rfh2Error> {
+    if element.is_empty() {
+        return Err(Rfh2Error::InvalidName(name.to_string()));
+    }
+
+    let (dt, text): (Option<&str>, Cow<str>) = match value {
+        Value::Boolean(value) => (Some("boolean"), Cow::Borrowed(if *value { "1" } else { "0" })),
+        Value::Int8(value) => (Some("i1"), value.to_string().into()),
+        Value::Int16(value) => (Some("i2"), value.to_string().into()),
+        Value::Int32(value) => (Some("i4"), value.to_string().into()),
+        Value::Int64(value) => (Some("i8"), value.to_string().into()),
+        Value::Float32(value) => (Some("r4"), format!("{value:E}").into()),
+        Value::Float64(value) => (Some("r8"), format!("{value:E}").into()),
+        Value::ByteString(value) => (
+            Some("bin.hex"),
+            value
+                .iter()
+                .fold(String::with_capacity(value.len() * 2), |mut hex, byte| {
+                    write!(hex, "{byte:02X}").expect("write to String should not fail");
+                    hex
+                })
+                .into(),
+        ),
+        Value::String(value) => (
+            None,
+            Cow::<str>::try_from(StringCcsid::new(&*value.data, value.ccsid, value.le))?,
+        ),
+        Value::Null => {
+            write!(xml, "<{element} xsi:nil='true'/>").expect("write to String should not fail");
+            return Ok(());
+        }
+    };
+
+    match dt {
+        Some(dt) => write!(xml, "<{element} dt='{dt}'>"),
+        None => write!(xml, "<{element}>"),
+    }
+    .expect("write to String should not fail");
+    escape_into(xml, &text);
+    write!(xml, "</{element}>").expect("write to String should not fail");
+    Ok(())
+}
+
+fn escape_into(xml: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '<' => xml.push_str("&lt;"),
+            '>' => xml.push_str("&gt;"),
+            '&' => xml.push_str("&amp;"),
+            '"' => xml.push_str("&quot;"),
+            '\'' => xml.push_str("&apos;"),
+            other => xml.push(other),
+        }
+    }
+}
+
+fn unescape(text: &str, offset: usize) -> Result<Cow<str>, Rfh2Error> {
+    if !text.contains('&') {
+        return Ok(Cow::Borrowed(text));
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut remaining = text;
+    while let Some(start) = remaining.find('&') {
+        result.push_str(&remaining[..start]);
+        let end = remaining[start..]
+            .find(';')
+            .ok_or(Rfh2Error::Malformed(offset, "unterminated entity"))?;
+        result.push(match &remaining[start + 1..start + end] {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => return Err(Rfh2Error::Malformed(offset, "unknown entity")),
+        });
+        remaining = &remaining[start + end + 1..];
+    }
+    result.push_str(remaining);
+    Ok(Cow::Owned(result))
+}
+
+fn parse_value(name: &str, dt: Option<&str>, text: &str) -> Result<Value, Rfh2Error> {
+    fn invalid(name: &str, dt: &'static str, text: &str) -> Rfh2Error {
+        Rfh2Error::InvalidValue(name.to_string(), dt, text.to_string())
+    }
+
+    let trimmed = text.trim();
+    Ok(match dt {
+        None | Some("string") => Value::String(StrCcsidOwned::from(text)),
+        Some("boolean") => Value::Boolean(match trimmed {
+            "1" | "true" => true,
+            "0" | "false" => false,
+            _ => return Err(invalid(name, "boolean", text)),
+        }),
+        Some("i1") => Value::Int8(trimmed.parse().map_err(|_| invalid(name, "i1", text))?),
+        Some("i2") => Value::Int16(trimmed.parse().map_err(|_| invalid(name, "i2", text))?),
+        Some("i4" | "int") => Value::Int32(trimmed.parse().map_err(|_| invalid(name, "i4", text))?),
+        Some("i8") => Value::Int64(trimmed.parse().map_err(|_| invalid(name, "i8", text))?),
+        Some("r4") => Value::Float32(trimmed.parse().map_err(|_| invalid(name, "r4", text))?),
+        Some("r8") => Value::Float64(trimmed.parse().map_err(|_| invalid(name, "r8", text))?),
+        Some("bin.hex") => {
+            if trimmed.len() % 2 != 0 || !trimmed.is_ascii() {
+                return Err(invalid(name, "bin.hex", text));
+            }
+            Value::ByteString(
+                (0..trimmed.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&trimmed[i..i + 2], 16))
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| invalid(name, "bin.hex", text))?,
+            )
+        }
+        Some(other) => return Err(Rfh2Error::UnsupportedType(name.to_string(), other.to_string())),
+    })
+}
+
+/// Minimal parser for the XML-like subset permitted in RFH2 `NameValueData`
+struct Parser<'a> {
+    data: &'a str,
+    pos: usize,
+}
+
+struct StartTag<'a> {
+    name: &'a str,
+    dt: Option<&'a str>,
+    nil: bool,
+    empty: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn folder(&mut self) -> Result<(String, Vec<(String, Value)>), Rfh2Error> {
+        self.skip_whitespace();
+        let tag = self.start_tag()?;
+        let mut properties = Vec::new();
+        if !tag.empty {
+            self.children(tag.name, "", &mut properties)?;
+        }
+        self.skip_whitespace();
+        if self.pos != self.data.len() {
+            return Err(Rfh2Error::Malformed(self.pos, "unexpected data after folder"));
+        }
+        Ok((tag.name.to_string(), properties))
+    }
+
+    fn children(&mut self, parent: &str, path: &str, properties: &mut Vec<(String, Value)>) -> Result<(), Rfh2Error> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("</") {
+                return self.end_tag(parent);
+            }
+            let tag = self.start_tag()?;
+            let name = if path.is_empty() {
+                tag.name.to_string()
+            } else {
+                format!("{path}.{}", tag.name)
+            };
+            if tag.empty {
+                let value = if tag.nil {
+                    Value::Null
+                } else {
+                    parse_value(&name, tag.dt, "")?
+                };
+                properties.push((name, value));
+                continue;
+            }
+
+            let text_start = self.pos;
+            let text_len = self
+                .rest()
+                .find('<')
+                .ok_or(Rfh2Error::Malformed(self.pos, "unterminated element"))?;
+            self.pos += text_len;
+
+            if self.rest().starts_with("</") {
+                let text = unescape(&self.data[text_start..self.pos], text_start)?;
+                self.end_tag(tag.name)?;
+                let value = if tag.nil {
+                    Value::Null
+                } else {
+                    parse_value(&name, tag.dt, &text)?
+                };
+                properties.push((name, value));
+            } else if self.data[text_start..self.pos].trim().is_empty() {
+                self.children(tag.name, &name, properties)?;
+            } else {
+                return Err(Rfh2Error::Malformed(text_start, "mixed content is not supported"));
+            }
+        }
+    }
+
+    fn start_tag(&mut self) -> Result<StartTag<'a>, Rfh2Error> {
+        if !self.rest().starts_with('<') {
+            return Err(Rfh2Error::Malformed(self.pos, "expected start of element"));
+        }
+        self.pos += 1;
+        let name = self.name()?;
+        let mut tag = StartTag {
+            name,
+            dt: None,
+            nil: false,
+            empty: false,
+        };
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                tag.empty = true;
+                return Ok(tag);
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                return Ok(tag);
+            }
+            let attribute = self.name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(Rfh2Error::Malformed(self.pos, "expected '=' after attribute name"));
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            let value = self.quoted()?;
+            match attribute {
+                "dt" => tag.dt = Some(value),
+                "xsi:nil" => tag.nil = value == "true",
+                _ => {}
+            }
+        }
+    }
+
+    fn end_tag(&mut self, expected: &str) -> Result<(), Rfh2Error> {
+        self.pos += 2; // Skip the "</"
+        let name = self.name()?;
+        if name != expected {
+            return Err(Rfh2Error::Malformed(self.pos, "end element does not match start element"));
+        }
+        self.skip_whitespace();
+        if !self.rest().starts_with('>') {
+            return Err(Rfh2Error::Malformed(self.pos, "expected '>'"));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn name(&mut self) -> Result<&'a str, Rfh2Error> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '>' | '/' | '=' | '<'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(Rfh2Error::Malformed(self.pos, "expected a name"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    fn quoted(&mut self) -> Result<&'a str, Rfh2Error> {
+        let rest = self.rest();
+        let quote = rest
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '\'' | '"'))
+            .ok_or(Rfh2Error::Malformed(self.pos, "expected quoted attribute value"))?;
+        let len = rest[1..]
+            .find(quote)
+            .ok_or(Rfh2Error::Malformed(self.pos, "unterminated attribute value"))?;
+        self.pos += len + 2;
+        Ok(&rest[1..=len])
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        // Folders are padded with spaces (or nulls) to a multiple of 4 bytes
+        self.pos += rest.len() - rest.trim_start_matches(|c: char| c.is_whitespace() || c == '\0').len();
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.data[self.pos..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::properties_options::Value;
+
+    use super::{Rfh2Error, Rfh2Properties};
+
+    #[test]
+    fn parse_usr_folder() -> Result<(), Rfh2Error> {
+        let mut properties = Rfh2Properties::new();
+        properties.parse_folder(
+            "<usr><count dt='i4'>42</count><big dt=\"i8\">-9000000000</big><ratio dt='r8'>1.5E0</ratio>\
+             <flag dt='boolean'>1</flag><raw dt='bin.hex'>0A0b</raw><text>a &amp; b</text>\
+             <grp><inner dt='i4'>7</inner></grp><missing xsi:nil='true'/></usr>   ",
+        )?;
+
+        assert!(matches!(properties.get("usr.count"), Some(Value::Int32(42))));
+        assert!(matches!(properties.get("usr.big"), Some(Value::Int64(-9_000_000_000))));
+        assert!(matches!(properties.get("usr.ratio"), Some(Value::Float64(v)) if (*v - 1.5).abs() < f64::EPSILON));
+        assert!(matches!(properties.get("usr.flag"), Some(Value::Boolean(true))));
+        assert!(matches!(properties.get("usr.raw"), Some(Value::ByteString(v)) if v == &[0x0a, 0x0b]));
+        assert!(matches!(properties.get("usr.text"), Some(Value::String(s)) if s.data == b"a & b"));
+        assert!(matches!(properties.get("usr.grp.inner"), Some(Value::Int32(7))));
+        assert!(matches!(properties.get("usr.missing"), Some(Value::Null)));
+        Ok(())
+    }
+
+    #[test]
+    fn ignore_other_folders() -> Result<(), Rfh2Error> {
+        let mut properties = Rfh2Properties::new();
+        properties.parse_folder("<mcd><Msd>jms_text</Msd></mcd>")?;
+        properties.parse_folder("<jms><Dst>queue:///Q1</Dst></jms>")?;
+
+        assert_eq!(properties.len(), 1);
+        assert!(properties.contains_key("jms.Dst"));
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_type() {
+        let mut properties = Rfh2Properties::new();
+        assert!(matches!(
+            properties.parse_folder("<usr><a dt='date'>x</a></usr>"),
+            Err(Rfh2Error::UnsupportedType(..))
+        ));
+        assert!(matches!(
+            properties.parse_folder("<usr><a dt='i4'>x</a></usr>"),
+            Err(Rfh2Error::InvalidValue(..))
+        ));
+        assert!(matches!(
+            properties.parse_folder("<usr><a>x</b></usr>"),
+            Err(Rfh2Error::Malformed(..))
+        ));
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), Rfh2Error> {
+        let mut properties = Rfh2Properties::new();
+        properties.insert("usr.a".into(), Value::Int32(1));
+        properties.insert("usr.g.b".into(), Value::String("<x>".into()));
+        properties.insert("usr.g.c".into(), Value::Null);
+        properties.insert("usr.h".into(), Value::Float64(0.25));
+        properties.insert("jms.Pri".into(), Value::Int32(4));
+
+        let folders = properties.folders()?;
+        assert_eq!(
+            folders,
+            [
+                "<usr><a dt='i4'>1</a><g><b>&lt;x&gt;</b><c xsi:nil='true'/></g><h dt='r8'>2.5E-1</h></usr>",
+                "<jms><Pri dt='i4'>4</Pri></jms>"
+            ]
+        );
+
+        let mut parsed = Rfh2Properties::new();
+        for folder in &folders {
+            parsed.parse_folder(folder)?;
+        }
+        assert_eq!(parsed.len(), properties.len());
+        assert!(matches!(parsed.get("usr.h"), Some(Value::Float64(v)) if (*v - 0.25).abs() < f64::EPSILON));
+
+        let data = properties.name_value_data()?;
+        assert_eq!(data.len() % 4, 0);
+        Ok(())
+    }
+}
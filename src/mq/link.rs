@@ -61,4 +61,8 @@ impl Bag<Owned, LinkedMq> {
     pub fn new(options: MQCBO) -> ResultComp<Self> {
         Self::new_lib(LinkedMq, options)
     }
+
+    pub fn from_buffer(options: MQCBO, data: &[u8]) -> ResultComp<Self> {
+        Self::from_buffer_lib(LinkedMq, options, data)
+    }
 }
@@ -29,14 +29,19 @@ pub use stat::*;
 pub use properties::*;
 pub use syncpoint::*;
 pub use queue_manager::*;
+pub use callback::*;
 
 pub mod attribute;
 pub mod get;
 pub mod put;
 
+#[cfg(feature = "serde")]
+pub mod codec;
+
 mod attribute_types;
 
 pub mod connect_options;
+pub mod connect_profile;
 pub mod get_options;
 pub mod open_options;
 pub mod properties_options;
@@ -45,6 +50,6 @@ pub mod subscribe_options;
 
 pub mod values {
     pub use crate::core::values::*;
-    #[cfg(feature = "mqai")]
+    #[cfg(any(feature = "mqai", feature = "pcf"))]
     pub use crate::core::mqai::values::*;
 }
@@ -1,4 +1,17 @@
-use crate::sys;
+use std::{
+    fmt::Display,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    get::GetParam,
+    prelude::*,
+    put::{PutOption, PutParam},
+    sys,
+    types::{self, QueueManagerName, QueueName},
+    values::{MQEI, MQFB, MQMF, MQMT, MQPER, MQPRI, MQRO},
+    MqStr, MqStruct, MqiAttr, ResultComp,
+};
 
 trait Sealed {}
 #[expect(private_bounds, reason = "sealed trait pattern")]
@@ -8,3 +21,661 @@ impl Sealed for sys::MQMD2 {}
 
 impl MQMD for sys::MQMD {}
 impl MQMD for sys::MQMD2 {}
+
+/// Message persistence of the `MQMD`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Persistence {
+    NotPersistent,
+    Persistent,
+    #[default]
+    AsQueueDefault,
+    AsParent,
+}
+
+impl Persistence {
+    #[must_use]
+    pub const fn from_mqlong(value: sys::MQLONG) -> Option<Self> {
+        match value {
+            sys::MQPER_NOT_PERSISTENT => Some(Self::NotPersistent),
+            sys::MQPER_PERSISTENT => Some(Self::Persistent),
+            sys::MQPER_PERSISTENCE_AS_Q_DEF => Some(Self::AsQueueDefault),
+            sys::MQPER_PERSISTENCE_AS_PARENT => Some(Self::AsParent),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn value(self) -> sys::MQLONG {
+        match self {
+            Self::NotPersistent => sys::MQPER_NOT_PERSISTENT,
+            Self::Persistent => sys::MQPER_PERSISTENT,
+            Self::AsQueueDefault => sys::MQPER_PERSISTENCE_AS_Q_DEF,
+            Self::AsParent => sys::MQPER_PERSISTENCE_AS_PARENT,
+        }
+    }
+}
+
+impl From<Persistence> for MQPER {
+    fn from(persistence: Persistence) -> Self {
+        Self(persistence.value())
+    }
+}
+
+impl TryFrom<MQPER> for Persistence {
+    type Error = MQPER;
+
+    fn try_from(value: MQPER) -> Result<Self, Self::Error> {
+        Self::from_mqlong(value.value()).ok_or(value)
+    }
+}
+
+/// Message priority in the range of 0 to 9
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub struct Priority(u8);
+
+impl Priority {
+    pub const MIN: Self = Self(0);
+    pub const MAX: Self = Self(9);
+
+    /// Create a priority. Returns `None` when the priority is outside the range of 0 to 9.
+    #[must_use]
+    pub const fn new(priority: u8) -> Option<Self> {
+        if priority <= Self::MAX.0 {
+            Some(Self(priority))
+        } else {
+            None
+        }
+    }
+
+    /// Create a priority, saturating values outside the range of 0 to 9
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn saturating_from(priority: sys::MQLONG) -> Self {
+        if priority < 0 {
+            Self::MIN
+        } else if priority > Self::MAX.0 as sys::MQLONG {
+            Self::MAX
+        } else {
+            Self(priority as u8)
+        }
+    }
+
+    #[must_use]
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<sys::MQLONG> for Priority {
+    type Error = sys::MQLONG;
+
+    fn try_from(value: sys::MQLONG) -> Result<Self, Self::Error> {
+        u8::try_from(value).ok().and_then(Self::new).ok_or(value)
+    }
+}
+
+impl From<Priority> for sys::MQLONG {
+    fn from(Priority(value): Priority) -> Self {
+        value.into()
+    }
+}
+
+impl From<Priority> for MQPRI {
+    fn from(priority: Priority) -> Self {
+        Self(priority.into())
+    }
+}
+
+impl TryFrom<MQPRI> for Priority {
+    type Error = MQPRI;
+
+    fn try_from(value: MQPRI) -> Result<Self, Self::Error> {
+        Self::try_from(value.value()).map_err(MQPRI)
+    }
+}
+
+/// The `PutDate` and `PutTime` of a message in GMT with a resolution of hundredths of a second
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PutTimestamp {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    hundredths: u8,
+}
+
+fn parse_digits(digits: &[u8]) -> Option<u16> {
+    digits.iter().try_fold(0, |acc: u16, &digit| {
+        digit.is_ascii_digit().then(|| acc * 10 + u16::from(digit - b'0'))
+    })
+}
+
+/// Whether the parts are a date and time in the years 0 to 9999, with the day checked against the length of the month
+pub(crate) const fn is_valid_date_time(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> bool {
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        2 if leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return false,
+    };
+    year <= 9999 && day >= 1 && day <= days_in_month && hour < 24 && minute < 60 && second < 60
+}
+
+// Days since the unix epoch to the civil date and vice versa as per http://howardhinnant.github.io/date_algorithms.html
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (yoe + era * 400 + i64::from(month <= 2), month, day)
+}
+
+impl PutTimestamp {
+    /// Timestamp from its parts. Returns `None` when a part is out of range or the year is after 9999.
+    #[must_use]
+    pub const fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8, hundredths: u8) -> Option<Self> {
+        let result = Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            hundredths,
+        };
+        if result.is_valid() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    #[must_use]
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    #[must_use]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    #[must_use]
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    #[must_use]
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    #[must_use]
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    #[must_use]
+    pub const fn hundredths(&self) -> u8 {
+        self.hundredths
+    }
+
+    /// Parse the `PutDate` (`YYYYMMDD`) and `PutTime` (`HHMMSSTH`) fields of a `MQMD`
+    #[must_use]
+    pub fn from_mqmd(date: &MqStr<8>, time: &MqStr<8>) -> Option<Self> {
+        let date = date.as_bytes();
+        let time = time.as_bytes();
+        Self::new(
+            parse_digits(&date[..4])?,
+            parse_digits(&date[4..6])?.try_into().ok()?,
+            parse_digits(&date[6..])?.try_into().ok()?,
+            parse_digits(&time[..2])?.try_into().ok()?,
+            parse_digits(&time[2..4])?.try_into().ok()?,
+            parse_digits(&time[4..6])?.try_into().ok()?,
+            parse_digits(&time[6..])?.try_into().ok()?,
+        )
+    }
+
+    /// Render the timestamp as the `PutDate` and `PutTime` fields of a `MQMD`
+    #[must_use]
+    pub fn to_mqmd(&self) -> (MqStr<8>, MqStr<8>) {
+        // The parts are validated on construction, so each field is exactly 8 digits
+        let date = format!("{:04}{:02}{:02}", self.year, self.month, self.day);
+        let time = format!("{:02}{:02}{:02}{:02}", self.hour, self.minute, self.second, self.hundredths);
+        (MqStr::def_from_str(&date), MqStr::def_from_str(&time))
+    }
+
+    #[must_use]
+    const fn is_valid(&self) -> bool {
+        is_valid_date_time(self.year, self.month, self.day, self.hour, self.minute, self.second) && self.hundredths < 100
+    }
+
+    /// Timestamp of the system time, truncated to hundredths of a second.
+    /// Returns `None` when the time is before the year 0 or after the year 9999.
+    #[must_use]
+    pub fn from_system_time(time: SystemTime) -> Option<Self> {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_millis()).ok()?,
+            Err(before) => -i64::try_from(before.duration().as_millis()).ok()?,
+        };
+        let secs = millis.div_euclid(1000);
+        let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+        let day_secs = secs.rem_euclid(86_400);
+        Self::new(
+            year.try_into().ok()?,
+            month.try_into().ok()?,
+            day.try_into().ok()?,
+            (day_secs / 3600).try_into().ok()?,
+            (day_secs / 60 % 60).try_into().ok()?,
+            (day_secs % 60).try_into().ok()?,
+            (millis.rem_euclid(1000) / 10).try_into().ok()?,
+        )
+    }
+
+    #[must_use]
+    pub fn to_system_time(&self) -> SystemTime {
+        let days = days_from_civil(self.year.into(), self.month.into(), self.day.into());
+        let secs = days * 86_400 + i64::from(self.hour) * 3600 + i64::from(self.minute) * 60 + i64::from(self.second);
+        let millis = secs * 1000 + i64::from(self.hundredths) * 10;
+        let offset = Duration::from_millis(millis.unsigned_abs());
+        if millis < 0 {
+            UNIX_EPOCH - offset
+        } else {
+            UNIX_EPOCH + offset
+        }
+    }
+}
+
+impl Display for PutTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:02}Z",
+            self.year, self.month, self.day, self.hour, self.minute, self.second, self.hundredths
+        )
+    }
+}
+
+impl TryFrom<SystemTime> for PutTimestamp {
+    type Error = SystemTime;
+
+    fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
+        Self::from_system_time(value).ok_or(value)
+    }
+}
+
+impl From<PutTimestamp> for SystemTime {
+    fn from(value: PutTimestamp) -> Self {
+        value.to_system_time()
+    }
+}
+
+/// Typed view of the message descriptor (`MQMD2`) used by put and get.
+///
+/// Conversion to and from the underlying `MQMD2` is lossless. Fields without a typed
+/// accessor are retained.
+#[derive(Debug, Clone, Default)]
+pub struct MessageDescriptor {
+    md: MqStruct<'static, sys::MQMD2>,
+}
+
+impl From<MqStruct<'static, sys::MQMD2>> for MessageDescriptor {
+    fn from(md: MqStruct<'static, sys::MQMD2>) -> Self {
+        Self { md }
+    }
+}
+
+impl From<sys::MQMD2> for MessageDescriptor {
+    fn from(md: sys::MQMD2) -> Self {
+        Self { md: MqStruct::new(md) }
+    }
+}
+
+impl From<MessageDescriptor> for MqStruct<'static, sys::MQMD2> {
+    fn from(value: MessageDescriptor) -> Self {
+        value.md
+    }
+}
+
+impl From<MessageDescriptor> for sys::MQMD2 {
+    fn from(value: MessageDescriptor) -> Self {
+        *value.md
+    }
+}
+
+impl AsRef<sys::MQMD2> for MessageDescriptor {
+    fn as_ref(&self) -> &sys::MQMD2 {
+        &self.md
+    }
+}
+
+impl MessageDescriptor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn persistence(&self) -> Option<Persistence> {
+        Persistence::from_mqlong(self.md.Persistence)
+    }
+
+    pub fn set_persistence(&mut self, persistence: impl Into<MQPER>) -> &mut Self {
+        self.md.Persistence = persistence.into().value();
+        self
+    }
+
+    /// The message priority. Returns `None` when the priority is one of the special `MQPRI_*` values.
+    #[must_use]
+    pub fn priority(&self) -> Option<Priority> {
+        self.md.Priority.try_into().ok()
+    }
+
+    pub fn set_priority(&mut self, priority: impl Into<MQPRI>) -> &mut Self {
+        self.md.Priority = priority.into().value();
+        self
+    }
+
+    pub fn set_priority_as_queue_default(&mut self) -> &mut Self {
+        self.set_priority(MQPRI(sys::MQPRI_PRIORITY_AS_Q_DEF))
+    }
+
+    /// The message expiry. `None` represents an unlimited expiry.
+    #[must_use]
+    pub fn expiry(&self) -> Option<Duration> {
+        u64::try_from(self.md.Expiry)
+            .ok()
+            .map(|tenths| Duration::from_millis(tenths * 100))
+    }
+
+    /// Set the message expiry, rounded up to the nearest tenth of a second with a minimum of one tenth,
+    /// as the queue manager rejects an expiry of zero. `None` represents an unlimited expiry.
+    pub fn set_expiry(&mut self, expiry: Option<Duration>) -> &mut Self {
+        self.set_expiry_interval(expiry.map_or(MQEI(sys::MQEI_UNLIMITED), |expiry| {
+            MQEI(sys::MQLONG::try_from(expiry.as_millis().div_ceil(100)).map_or(sys::MQLONG::MAX, |tenths| tenths.max(1)))
+        }))
+    }
+
+    /// The raw `Expiry` field in tenths of a second
+    #[must_use]
+    pub const fn expiry_interval(&self) -> MQEI {
+        MQEI(self.md.Expiry)
+    }
+
+    pub fn set_expiry_interval(&mut self, expiry: MQEI) -> &mut Self {
+        self.md.Expiry = expiry.value();
+        self
+    }
+
+    #[must_use]
+    pub const fn report(&self) -> MQRO {
+        MQRO(self.md.Report)
+    }
+
+    pub fn set_report(&mut self, report: MQRO) -> &mut Self {
+        self.md.Report = report.value();
+        self
+    }
+
+    #[must_use]
+    pub const fn msg_type(&self) -> MQMT {
+        MQMT(self.md.MsgType)
+    }
+
+    pub fn set_msg_type(&mut self, msg_type: MQMT) -> &mut Self {
+        self.md.MsgType = msg_type.value();
+        self
+    }
+
+    #[must_use]
+    pub const fn feedback(&self) -> MQFB {
+        MQFB(self.md.Feedback)
+    }
+
+    pub fn set_feedback(&mut self, feedback: MQFB) -> &mut Self {
+        self.md.Feedback = feedback.value();
+        self
+    }
+
+    #[must_use]
+    pub const fn msg_flags(&self) -> MQMF {
+        MQMF(self.md.MsgFlags)
+    }
+
+    pub fn set_msg_flags(&mut self, msg_flags: MQMF) -> &mut Self {
+        self.md.MsgFlags = msg_flags.value();
+        self
+    }
+
+    #[must_use]
+    pub fn reply_to_queue(&self) -> Option<QueueName> {
+        Some(MqStr::from(self.md.ReplyToQ)).filter(MqStr::has_value).map(QueueName)
+    }
+
+    pub fn set_reply_to_queue(&mut self, queue: Option<&QueueName>) -> &mut Self {
+        queue.copied().unwrap_or_default().copy_into_mqchar(&mut self.md.ReplyToQ);
+        self
+    }
+
+    #[must_use]
+    pub fn reply_to_queue_manager(&self) -> Option<QueueManagerName> {
+        Some(MqStr::from(self.md.ReplyToQMgr))
+            .filter(MqStr::has_value)
+            .map(QueueManagerName)
+    }
+
+    pub fn set_reply_to_queue_manager(&mut self, queue_manager: Option<&QueueManagerName>) -> &mut Self {
+        queue_manager
+            .copied()
+            .unwrap_or_default()
+            .copy_into_mqchar(&mut self.md.ReplyToQMgr);
+        self
+    }
+
+    #[must_use]
+    pub fn message_id(&self) -> types::MessageId {
+        types::MessageId(self.md.MsgId.into())
+    }
+
+    pub fn set_message_id(&mut self, message_id: &types::MessageId) -> &mut Self {
+        self.md.MsgId = *message_id.0;
+        self
+    }
+
+    #[must_use]
+    pub fn correlation_id(&self) -> types::CorrelationId {
+        types::CorrelationId(self.md.CorrelId.into())
+    }
+
+    pub fn set_correlation_id(&mut self, correlation_id: &types::CorrelationId) -> &mut Self {
+        self.md.CorrelId = *correlation_id.0;
+        self
+    }
+
+    /// The `PutDate` and `PutTime` of the message. Returns `None` when the fields are blank or invalid.
+    #[must_use]
+    pub fn put_timestamp(&self) -> Option<PutTimestamp> {
+        PutTimestamp::from_mqmd(&MqStr::from(self.md.PutDate), &MqStr::from(self.md.PutTime))
+    }
+
+    /// Set the `PutDate` and `PutTime`. The queue manager only honours these fields when
+    /// the message is put with `MQPMO_SET_ALL_CONTEXT`.
+    pub fn set_put_timestamp(&mut self, timestamp: Option<&PutTimestamp>) -> &mut Self {
+        let (date, time) = timestamp.map(PutTimestamp::to_mqmd).unwrap_or_default();
+        date.copy_into_mqchar(&mut self.md.PutDate);
+        time.copy_into_mqchar(&mut self.md.PutTime);
+        self
+    }
+}
+
+/// The message format fields (`Format`, `CodedCharSetId` and `Encoding`) are retained
+/// from the message being put.
+impl PutOption for MessageDescriptor {
+    fn apply_param(self, (md, ..): &mut PutParam) {
+        let mut descriptor = self.md;
+        descriptor.Format = md.Format;
+        descriptor.CodedCharSetId = md.CodedCharSetId;
+        descriptor.Encoding = md.Encoding;
+        *md = descriptor;
+    }
+}
+
+impl PutOption for &MessageDescriptor {
+    fn apply_param(self, param: &mut PutParam) {
+        self.clone().apply_param(param);
+    }
+}
+
+impl<'b, S> MqiAttr<PutParam<'b>, S> for MessageDescriptor {
+    #[inline]
+    fn extract<F>(param: &mut PutParam<'b>, put: F) -> ResultComp<(Self, S)>
+    where
+        F: FnOnce(&mut PutParam<'b>) -> ResultComp<S>,
+    {
+        put(param).map_completion(|state| {
+            let (md, ..) = param;
+            (md.clone().into(), state)
+        })
+    }
+}
+
+impl<S> MqiAttr<GetParam, S> for MessageDescriptor {
+    fn extract<F>(param: &mut GetParam, get: F) -> ResultComp<(Self, S)>
+    where
+        F: FnOnce(&mut GetParam) -> ResultComp<S>,
+    {
+        get(param).map_completion(|state| (param.md.clone().into(), state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{
+        sys,
+        types::QueueName,
+        values::{MQEI, MQFB, MQMF, MQPER, MQPRI, MQRO},
+        MqStr,
+    };
+
+    use super::{MessageDescriptor, Persistence, Priority, PutTimestamp};
+
+    #[test]
+    fn priority() {
+        assert_eq!(Priority::new(9), Some(Priority::MAX));
+        assert_eq!(Priority::new(10), None);
+        assert_eq!(
+            Priority::try_from(sys::MQPRI_PRIORITY_AS_Q_DEF),
+            Err(sys::MQPRI_PRIORITY_AS_Q_DEF)
+        );
+        assert_eq!(Priority::saturating_from(12), Priority::MAX);
+        assert_eq!(Priority::saturating_from(-1), Priority::MIN);
+        assert_eq!(MQPRI::from(Priority::MAX), MQPRI(9));
+        assert_eq!(
+            Priority::try_from(MQPRI(sys::MQPRI_PRIORITY_AS_PARENT)),
+            Err(MQPRI(sys::MQPRI_PRIORITY_AS_PARENT))
+        );
+    }
+
+    #[test]
+    fn put_timestamp() {
+        let timestamp = PutTimestamp::from_mqmd(&MqStr::def_from_str("20240229"), &MqStr::def_from_str("23595999"))
+            .expect("timestamp should be valid");
+        assert_eq!(timestamp.to_string(), "2024-02-29T23:59:59.99Z");
+        assert_eq!(PutTimestamp::from_system_time(timestamp.to_system_time()), Some(timestamp));
+        assert_eq!(
+            PutTimestamp::from_system_time(UNIX_EPOCH + Duration::from_secs(86_400)).map(|ts| ts.to_string()),
+            Some("1970-01-02T00:00:00.00Z".to_string())
+        );
+        assert_eq!(
+            PutTimestamp::from_system_time(UNIX_EPOCH - Duration::from_millis(10)).map(|ts| ts.to_string()),
+            Some("1969-12-31T23:59:59.99Z".to_string())
+        );
+        let last = PutTimestamp::new(9999, 12, 31, 23, 59, 59, 99).expect("timestamp should be valid");
+        assert_eq!(PutTimestamp::from_system_time(last.to_system_time()), Some(last));
+        assert_eq!(
+            PutTimestamp::from_system_time(last.to_system_time() + Duration::from_millis(10)),
+            None
+        );
+        assert_eq!(PutTimestamp::new(10_000, 1, 1, 0, 0, 0, 0), None);
+        assert_eq!(PutTimestamp::new(2024, 1, 1, 0, 0, 0, 100), None);
+        assert_eq!(PutTimestamp::new(2024, 4, 31, 0, 0, 0, 0), None);
+        assert_eq!(PutTimestamp::new(1900, 2, 29, 0, 0, 0, 0), None);
+        assert!(PutTimestamp::new(2000, 2, 29, 0, 0, 0, 0).is_some());
+        for date in ["20240231", "20230229"] {
+            assert_eq!(
+                PutTimestamp::from_mqmd(&MqStr::def_from_str(date), &MqStr::def_from_str("00000000")),
+                None,
+                "{date} should be rejected"
+            );
+        }
+        let (date, time) = timestamp.to_mqmd();
+        assert_eq!(date, *"20240229");
+        assert_eq!(time, *"23595999");
+        assert_eq!(PutTimestamp::from_mqmd(&MqStr::empty(), &MqStr::empty()), None);
+    }
+
+    #[test]
+    fn descriptor_round_trip() {
+        let mut md = MessageDescriptor::new();
+        md.set_persistence(Persistence::Persistent)
+            .set_priority(Priority::new(4).expect("priority should be valid"))
+            .set_expiry(Some(Duration::from_millis(1050)))
+            .set_report(MQRO(sys::MQRO_COA | sys::MQRO_PASS_CORREL_ID))
+            .set_feedback(MQFB(sys::MQFB_APPL_FIRST))
+            .set_msg_flags(MQMF(sys::MQMF_SEGMENTATION_ALLOWED))
+            .set_reply_to_queue(Some(&QueueName(MqStr::def_from_str("REPLY.Q"))));
+
+        let raw: sys::MQMD2 = md.clone().into();
+        assert_eq!(raw.Persistence, sys::MQPER_PERSISTENT);
+        assert_eq!(raw.Priority, 4);
+        assert_eq!(raw.Expiry, 11);
+
+        let md = MessageDescriptor::from(raw);
+        assert_eq!(md.persistence(), Some(Persistence::Persistent));
+        assert_eq!(md.priority(), Priority::new(4));
+        assert_eq!(md.expiry(), Some(Duration::from_millis(1100)));
+        assert_eq!(md.report(), MQRO(sys::MQRO_COA | sys::MQRO_PASS_CORREL_ID));
+        assert_eq!(md.reply_to_queue(), Some(QueueName(MqStr::def_from_str("REPLY.Q"))));
+        assert_eq!(md.reply_to_queue_manager(), None);
+        assert_eq!(md.feedback(), MQFB(sys::MQFB_APPL_FIRST));
+        assert_eq!(md.msg_flags(), MQMF(sys::MQMF_SEGMENTATION_ALLOWED));
+        assert_eq!(MQPER::from(Persistence::Persistent), MQPER(sys::MQPER_PERSISTENT));
+        assert_eq!(Persistence::try_from(MQPER(5)), Err(MQPER(5)));
+
+        let mut md = md;
+        md.set_expiry(None);
+        assert_eq!(md.expiry(), None);
+        assert_eq!(md.expiry_interval(), MQEI(sys::MQEI_UNLIMITED));
+        md.set_expiry(Some(Duration::ZERO));
+        assert_eq!(md.expiry_interval(), MQEI(1));
+        md.set_expiry(Some(Duration::from_micros(1)));
+        assert_eq!(md.expiry_interval(), MQEI(1));
+        md.set_expiry_interval(MQEI(50)).set_priority_as_queue_default();
+        assert_eq!(md.expiry(), Some(Duration::from_secs(5)));
+        assert_eq!(md.priority(), None);
+    }
+}
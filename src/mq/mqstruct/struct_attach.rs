@@ -71,6 +71,18 @@ impl<'ptr> MqStruct<'ptr, sys::MQCNO> {
     }
 }
 
+// Functions to attach references to MQCD
+impl<'ptr> MqStruct<'ptr, sys::MQCD> {
+    pub fn attach_ssl_peer_name(&mut self, peer_name: &'ptr str) {
+        self.set_min_version(sys::MQCD_VERSION_7);
+        self.SSLPeerNamePtr = mq_str_ptr(peer_name);
+        self.SSLPeerNameLength = peer_name
+            .len()
+            .try_into()
+            .expect("SSL peer name length should not exceed maximum positive MQLONG");
+    }
+}
+
 impl_mqstruct_min_version!(sys::MQCSP);
 
 // Functions to attach references to MQCSP
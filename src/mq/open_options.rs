@@ -4,7 +4,7 @@ use crate::{
     macros::all_multi_tuples,
     prelude::*,
     sys,
-    types::{QueueManagerName, QueueName},
+    types::{ObjectName, QueueManagerName, QueueName},
     values::{CCSID, MQOO, MQOT, MQPMO},
     Conn, EncodedString, Error, MqStr, MqiAttr, MqiValue, ResultComp, StrCcsidOwned,
 };
@@ -56,6 +56,12 @@ impl_from_str!(AlternateUserId, MqStr<12>);
 #[derive(Debug, Clone)]
 pub struct ResObjectString(pub StrCcsidOwned);
 
+/// Name of the dynamic queue created when opening a model queue. A trailing `*` is replaced
+/// with a unique suffix by the queue manager.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Deref, derive_more::DerefMut, derive_more::From)]
+pub struct DynamicQueueName(pub ObjectName);
+impl_from_str!(DynamicQueueName, ObjectName);
+
 impl<'a, T: EncodedString + ?Sized, O> OpenOption<'a, O> for SelectionString<&'a T> {
     fn apply_param(self, OpenParamOption { mqod, .. }: &mut OpenParamOption<'a, O>) {
         mqod.attach_selection_string(self.0);
@@ -79,6 +85,12 @@ impl<'b, O> OpenOption<'b, O> for QueueName {
     }
 }
 
+impl<'b, O> OpenOption<'b, O> for DynamicQueueName {
+    fn apply_param(self, OpenParamOption { mqod, .. }: &mut OpenParamOption<O>) {
+        mqod.DynamicQName = self.0.into();
+    }
+}
+
 impl<'b, O> OpenOption<'b, O> for QueueManagerName {
     fn apply_param(self, OpenParamOption { mqod, .. }: &mut OpenParamOption<O>) {
         mqod.ObjectQMgrName = self.0.into();
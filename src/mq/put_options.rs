@@ -1,4 +1,6 @@
-use crate::{macros::all_option_tuples, prelude::*, sys, types, values, Conn, MqStruct, MqiAttr, Properties, ResultComp};
+use crate::{
+    macros::all_option_tuples, prelude::*, sys, types, values, Conn, MqStruct, MqiAttr, Properties, PutTimestamp, ResultComp,
+};
 
 use super::{
     put::{PutOption, PutParam},
@@ -115,3 +117,29 @@ impl<'b, S> MqiAttr<PutParam<'b>, S> for Option<types::UserIdentifier> {
         })
     }
 }
+
+/// Implement a put attribute that is derived from the `MQMD2` after the put
+macro_rules! impl_put_md_attr {
+    ($ty:ty, |$md:ident| $value:expr) => {
+        impl<'b, S> MqiAttr<PutParam<'b>, S> for $ty {
+            #[inline]
+            fn extract<F>(param: &mut PutParam<'b>, put: F) -> ResultComp<(Self, S)>
+            where
+                F: FnOnce(&mut PutParam<'b>) -> ResultComp<S>,
+            {
+                put(param).map_completion(|state| {
+                    let ($md, ..) = param;
+                    ($value, state)
+                })
+            }
+        }
+    };
+}
+
+impl_put_md_attr!(types::GroupId, |md| Self(md.GroupId.into()));
+impl_put_md_attr!(types::MsgSeqNumber, |md| Self(md.MsgSeqNumber));
+impl_put_md_attr!(types::PutApplName, |md| Self(md.PutApplName.into()));
+impl_put_md_attr!(Option<PutTimestamp>, |md| PutTimestamp::from_mqmd(
+    &md.PutDate.into(),
+    &md.PutTime.into()
+));
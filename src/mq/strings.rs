@@ -1,10 +1,10 @@
 use std::{borrow::Cow, ptr};
 
-use crate::sys;
+use crate::{sys, MqStr};
 
 use super::{values::CCSID, MqStruct};
 
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct StringCcsid<T> {
     pub(crate) ccsid: CCSID,
     pub(crate) le: bool,
@@ -132,6 +132,26 @@ impl EncodedString for str {
     }
 }
 
+impl EncodedString for String {
+    fn ccsid(&self) -> CCSID {
+        self.as_str().ccsid()
+    }
+
+    fn data(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> EncodedString for MqStr<N> {
+    fn ccsid(&self) -> CCSID {
+        CCSID(1208)
+    }
+
+    fn data(&self) -> &[u8] {
+        self.value()
+    }
+}
+
 impl<T: AsRef<[u8]>> EncodedString for StringCcsid<T> {
     fn ccsid(&self) -> CCSID {
         self.ccsid
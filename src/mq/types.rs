@@ -1,11 +1,15 @@
 use crate::{
-    values::{MQRC, MQENC, CCSID},
+    values::{MQRC, MQENC, MQFB, CCSID},
     headers::TextEnc,
-    sys, MqStr,
+    sys, MqStr, MqStrError,
 };
 use std::{
     fmt::{Debug, Display},
-    mem, ptr, str,
+    mem,
+    net::{Ipv4Addr, Ipv6Addr},
+    ops::RangeInclusive,
+    ptr,
+    str::{self, FromStr},
 };
 
 use super::{headers::fmt::MQFMT_NONE, MqStruct};
@@ -92,6 +96,41 @@ impl<const N: usize> Display for Identifier<N> {
     }
 }
 
+#[derive(derive_more::Error, derive_more::Display, Debug, Clone, PartialEq, Eq)]
+pub enum IdentifierError {
+    #[display("Identifier does not start with `ID:`")]
+    Prefix,
+    #[display("Identifier has {length} hex digits, expected {expected}")]
+    Length { length: usize, expected: usize },
+    #[display("Identifier has an invalid hex digit at position {_0}")]
+    #[error(ignore)]
+    Hex(usize),
+}
+
+/// Parses the `ID:` hex form used by the `Display` implementation
+impl<const N: usize> FromStr for Identifier<N> {
+    type Err = IdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix("ID:").ok_or(IdentifierError::Prefix)?.as_bytes();
+        if hex.len() != N * 2 {
+            return Err(IdentifierError::Length {
+                length: hex.len(),
+                expected: N * 2,
+            });
+        }
+        let mut result = [0; N];
+        for ((index, pair), byte) in hex.chunks_exact(2).enumerate().zip(&mut result) {
+            *byte = str::from_utf8(pair)
+                .ok()
+                .filter(|pair| pair.bytes().all(|c| c.is_ascii_hexdigit()))
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or(IdentifierError::Hex(index * 2))?;
+        }
+        Ok(Self(result))
+    }
+}
+
 impl<const N: usize> Debug for Identifier<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.debug_tuple("Identifier").field(&format_args!("{self}")).finish()
@@ -114,10 +153,199 @@ impl UserIdentifier {
 
 pub type ObjectName = MqStr<48>;
 
+/// Number of times the message has been backed out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Deref, derive_more::From, derive_more::Display)]
+pub struct BackoutCount(pub sys::MQLONG);
+
+/// Sequence number of the logical message within a group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Deref, derive_more::From, derive_more::Display)]
+pub struct MsgSeqNumber(pub sys::MQLONG);
+
+/// Feedback or reason code of a report message
+pub type Feedback = MQFB;
+
+/// Name of the application that put the message
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Deref, derive_more::DerefMut, derive_more::From)]
+pub struct PutApplName(pub MqStr<{ sys::MQ_PUT_APPL_NAME_LENGTH }>);
+impl_from_str!(PutApplName, MqStr<{ sys::MQ_PUT_APPL_NAME_LENGTH }>);
+
+/// Reply-to queue and queue manager of a message
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ReplyTo {
+    pub queue: Option<QueueName>,
+    pub queue_manager: Option<QueueManagerName>,
+}
+
+impl ReplyTo {
+    #[must_use]
+    pub fn new(queue: [sys::MQCHAR; sys::MQ_Q_NAME_LENGTH], queue_manager: [sys::MQCHAR; sys::MQ_Q_MGR_NAME_LENGTH]) -> Self {
+        Self {
+            queue: Some(MqStr::from(queue)).filter(MqStr::has_value).map(QueueName),
+            queue_manager: Some(MqStr::from(queue_manager))
+                .filter(MqStr::has_value)
+                .map(QueueManagerName),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Deref, derive_more::DerefMut, derive_more::From)]
 pub struct ConnectionName(pub MqStr<264>);
 impl_from_str!(ConnectionName, MqStr<264>);
 
+/// Host of a TCP connection name address
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::From)]
+pub enum Host {
+    #[from(ignore)]
+    Name(String),
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+}
+
+impl Display for Host {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Name(name) => f.write_str(name),
+            Self::Ipv4(addr) => Display::fmt(addr, f),
+            // MQ delimits the port with parentheses, so IPv6 addresses are rendered without brackets
+            Self::Ipv6(addr) => Display::fmt(addr, f),
+        }
+    }
+}
+
+/// A single `host(port)` address of a TCP connection name
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConnectionAddress {
+    pub host: Host,
+    /// Port of the listener, or `None` for the default port of 1414
+    pub port: Option<u16>,
+}
+
+impl ConnectionAddress {
+    #[must_use]
+    pub fn new(host: impl Into<Host>, port: Option<u16>) -> Self {
+        Self { host: host.into(), port }
+    }
+}
+
+impl Display for ConnectionAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.port {
+            Some(port) => write!(f, "{}({port})", self.host),
+            None => write!(f, "{}", self.host),
+        }
+    }
+}
+
+#[derive(derive_more::Error, derive_more::Display, Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionNameError {
+    #[display("Connection name address {_0} is empty")]
+    #[error(ignore)]
+    Empty(usize),
+    #[display("Connection name address \"{_0}\" has an invalid host")]
+    #[error(ignore)]
+    Host(String),
+    #[display("Connection name address \"{_0}\" has an invalid port")]
+    #[error(ignore)]
+    Port(String),
+    #[display("Connection name of length {length} exceeds maximum length {max}")]
+    Length { length: usize, max: usize },
+}
+
+/// Validates a hostname of `.` separated labels of letters, digits and hyphens
+fn is_hostname(name: &str) -> bool {
+    name.len() <= 253
+        && name.split('.').all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_')
+        })
+}
+
+impl FromStr for ConnectionAddress {
+    type Err = ConnectionNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let address = s.trim();
+        let (host, port) = match address.strip_prefix('[') {
+            Some(bracketed) => {
+                let (host, rest) = bracketed
+                    .split_once(']')
+                    .ok_or_else(|| ConnectionNameError::Host(address.to_string()))?;
+                let host = host
+                    .parse()
+                    .map(Host::Ipv6)
+                    .map_err(|_| ConnectionNameError::Host(address.to_string()))?;
+                (host, rest)
+            }
+            None => {
+                let (host, port) = address
+                    .strip_suffix(')')
+                    .and_then(|prefix| prefix.rsplit_once('('))
+                    .map_or((address, ""), |(host, _)| (host, &address[host.len()..]));
+                let host = if let Ok(addr) = host.parse() {
+                    Host::Ipv4(addr)
+                } else if let Ok(addr) = host.parse() {
+                    Host::Ipv6(addr)
+                } else if is_hostname(host) {
+                    Host::Name(host.to_string())
+                } else {
+                    return Err(ConnectionNameError::Host(address.to_string()));
+                };
+                (host, port)
+            }
+        };
+
+        let port = match port {
+            "" => None,
+            port => Some(
+                port.strip_prefix('(')
+                    .and_then(|port| port.strip_suffix(')'))
+                    .and_then(|port| port.trim().parse().ok())
+                    .filter(|&port: &u16| port != 0)
+                    .ok_or_else(|| ConnectionNameError::Port(address.to_string()))?,
+            ),
+        };
+
+        Ok(Self { host, port })
+    }
+}
+
+impl ConnectionName {
+    /// Builds a connection name from a list of TCP addresses, validating the length of the list
+    pub fn from_addresses<'a>(addresses: impl IntoIterator<Item = &'a ConnectionAddress>) -> Result<Self, ConnectionNameError> {
+        let list = addresses.into_iter().map(ToString::to_string).collect::<Vec<_>>().join(",");
+        MqStr::from_str(&list)
+            .map(Self)
+            .map_err(|MqStrError::Length { length, max }| ConnectionNameError::Length { length, max })
+    }
+
+    /// Parses and validates a `,` separated list of TCP addresses, such as `host1(1414),host2(1414)`.
+    /// The length of the list is checked when the addresses are rendered by [`ConnectionName::from_addresses`].
+    pub fn parse_addresses(list: &str) -> Result<Vec<ConnectionAddress>, ConnectionNameError> {
+        list.split(',')
+            .enumerate()
+            .map(|(index, address)| {
+                if address.trim().is_empty() {
+                    Err(ConnectionNameError::Empty(index))
+                } else {
+                    address.parse()
+                }
+            })
+            .collect()
+    }
+
+    /// Parses and validates a `,` separated list of TCP addresses into a connection name
+    pub fn from_addresses_str(list: &str) -> Result<Self, ConnectionNameError> {
+        Self::from_addresses(&Self::parse_addresses(list)?)
+    }
+
+    /// The TCP addresses of the connection name
+    pub fn addresses(&self) -> Result<Vec<ConnectionAddress>, ConnectionNameError> {
+        Self::parse_addresses(&self.to_string())
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Deref, derive_more::DerefMut, derive_more::From)]
 pub struct ChannelName(pub MqStr<20>);
 impl_from_str!(ChannelName, MqStr<20>);
@@ -146,9 +374,39 @@ impl_from_str!(CryptoHardware, MqStr<256>);
 pub struct CertificateLabel(pub MqStr<64>);
 impl_from_str!(CertificateLabel, MqStr<64>);
 
+/// Name of a channel exit, such as `library(function)`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Deref, derive_more::DerefMut, derive_more::From)]
+pub struct ExitName(pub MqStr<{ sys::MQ_EXIT_NAME_LENGTH }>);
+impl_from_str!(ExitName, MqStr<{ sys::MQ_EXIT_NAME_LENGTH }>);
+
+/// User data passed to a channel exit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Deref, derive_more::DerefMut, derive_more::From)]
+pub struct ExitData(pub MqStr<{ sys::MQ_EXIT_DATA_LENGTH }>);
+impl_from_str!(ExitData, MqStr<{ sys::MQ_EXIT_DATA_LENGTH }>);
+
+/// Local address and port range of a client channel, in the `address(low,high)` form
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Deref, derive_more::DerefMut, derive_more::From)]
+pub struct LocalAddress(pub MqStr<{ sys::MQ_LOCAL_ADDRESS_LENGTH }>);
+impl_from_str!(LocalAddress, MqStr<{ sys::MQ_LOCAL_ADDRESS_LENGTH }>);
+
+impl LocalAddress {
+    /// Local address from an optional IP address or hostname, and an optional range of local ports
+    pub fn new(address: Option<&str>, ports: Option<RangeInclusive<u16>>) -> Result<Self, MqStrError> {
+        let address = address.unwrap_or_default();
+        match ports {
+            Some(ports) if ports.start() == ports.end() => format!("{address}({})", ports.start()),
+            Some(ports) => format!("{address}({},{})", ports.start(), ports.end()),
+            None => address.to_string(),
+        }
+        .parse()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Identifier;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::{ConnectionAddress, ConnectionName, ConnectionNameError, Host, Identifier, IdentifierError, LocalAddress};
 
     #[test]
     fn correlation_id() {
@@ -159,4 +417,91 @@ mod tests {
             "CorrelationId(ID:000000000000000000000000000000000000000000000000)"
         );
     }
+
+    #[test]
+    fn identifier_from_str() {
+        let id = Identifier([0x0f, 0xa0, 0x01]);
+        assert_eq!(id.to_string().parse(), Ok(id));
+        assert_eq!("ID:0FA001".parse(), Ok(id));
+        assert_eq!("0fa001".parse::<Identifier<3>>(), Err(IdentifierError::Prefix));
+        assert_eq!(
+            "ID:0fa0".parse::<Identifier<3>>(),
+            Err(IdentifierError::Length { length: 4, expected: 6 })
+        );
+        assert_eq!("ID:0fa0+1".parse::<Identifier<3>>(), Err(IdentifierError::Hex(4)));
+    }
+
+    #[test]
+    fn local_address() -> Result<(), crate::MqStrError> {
+        assert_eq!(LocalAddress::new(Some("10.0.0.1"), None)?.to_string(), "10.0.0.1");
+        assert_eq!(
+            LocalAddress::new(Some("10.0.0.1"), Some(1000..=2000))?.to_string(),
+            "10.0.0.1(1000,2000)"
+        );
+        assert_eq!(LocalAddress::new(None, Some(1414..=1414))?.to_string(), "(1414)");
+        assert!(LocalAddress::new(Some(&"a".repeat(49)), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn connection_address() {
+        assert_eq!(
+            "host1.example.com(1414)".parse(),
+            Ok(ConnectionAddress::new(
+                Host::Name("host1.example.com".to_string()),
+                Some(1414)
+            ))
+        );
+        assert_eq!(
+            " 10.0.0.1 ".parse(),
+            Ok(ConnectionAddress::new(Ipv4Addr::new(10, 0, 0, 1), None))
+        );
+        assert_eq!(
+            "[2001:db8::1](1415)".parse(),
+            Ok(ConnectionAddress::new(
+                Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+                Some(1415)
+            ))
+        );
+        assert_eq!(
+            "::1(1414)".parse(),
+            Ok(ConnectionAddress::new(Ipv6Addr::LOCALHOST, Some(1414)))
+        );
+        assert_eq!(
+            "host_(1414".parse::<ConnectionAddress>(),
+            Err(ConnectionNameError::Host("host_(1414".to_string()))
+        );
+        assert_eq!(
+            "-host(1414)".parse::<ConnectionAddress>(),
+            Err(ConnectionNameError::Host("-host(1414)".to_string()))
+        );
+        assert_eq!(
+            "host(0)".parse::<ConnectionAddress>(),
+            Err(ConnectionNameError::Port("host(0)".to_string()))
+        );
+        assert_eq!(
+            "[::1]1414".parse::<ConnectionAddress>(),
+            Err(ConnectionNameError::Port("[::1]1414".to_string()))
+        );
+    }
+
+    #[test]
+    fn connection_name_list() -> Result<(), ConnectionNameError> {
+        let name = ConnectionName::from_addresses_str("host1(1414), [::1](1415),10.0.0.1")?;
+        assert_eq!(name.to_string(), "host1(1414),::1(1415),10.0.0.1");
+        assert_eq!(name.addresses()?.len(), 3);
+
+        assert_eq!(ConnectionName::parse_addresses("host1,"), Err(ConnectionNameError::Empty(1)));
+        let padded = format!("host1(1414),{}host2(1414)", " ".repeat(300));
+        assert_eq!(
+            ConnectionName::from_addresses_str(&padded)?.to_string(),
+            "host1(1414),host2(1414)"
+        );
+        let long = vec![ConnectionAddress::new(Host::Name("a".repeat(60)), Some(1414)); 5];
+        assert_eq!(
+            ConnectionName::from_addresses(&long),
+            Err(ConnectionNameError::Length { length: 334, max: 264 })
+        );
+        Ok(())
+    }
 }
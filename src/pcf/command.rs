@@ -0,0 +1,204 @@
+use crate::{
+    get::{GetConvert, GetWait},
+    mqstr,
+    open_options::DynamicQueueName,
+    prelude::*,
+    sys,
+    types::{CorrelationId, MessageFormat, MessageId, QueueName},
+    values::{CCSID, MQCC, MQCMD, MQENC, MQGMO, MQMT, MQOO, MQPMO, MQRC},
+    Completion, Conn, Error, MessageDescriptor, Object, ResultComp,
+};
+
+use super::{Header, Parameters, PcfError, PcfMessage};
+
+/// Queue the command server reads PCF requests from
+pub const COMMAND_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.ADMIN.COMMAND.QUEUE"));
+/// Model queue used to create the dynamic reply queue
+pub const MODEL_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.DEFAULT.MODEL.QUEUE"));
+
+const REPLY_QUEUE_PREFIX: DynamicQueueName = DynamicQueueName(mqstr!("MQI.PCF.*"));
+const DEFAULT_WAIT_INTERVAL: sys::MQLONG = 30_000;
+const DEFAULT_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// Failed response to a PCF command
+#[derive(derive_more::Error, derive_more::Display, Debug, Clone, PartialEq, Eq)]
+#[display("PCF command {command} response {comp_code}: {reason}")]
+pub struct ResponseError {
+    pub command: MQCMD,
+    pub comp_code: MQCC,
+    pub reason: MQRC,
+    /// Parameters of the response, such as the `MQIACF_PARAMETER_ID` in error
+    pub parameters: Parameters,
+}
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum CommandError {
+    #[display("No response from the command server within the wait interval")]
+    NoResponse,
+    #[from]
+    Pcf(PcfError),
+    #[from]
+    MQ(Error),
+}
+
+/// Outcome of a single PCF response message
+pub type Response = Result<Parameters, ResponseError>;
+
+impl PcfMessage {
+    /// Convert a response message into its parameters, or a [`ResponseError`] when the
+    /// `CompCode` of the response is not `MQCC_OK`
+    pub fn into_response(self) -> Response {
+        let Self { header, parameters } = self;
+        if header.comp_code.0 == sys::MQCC_OK {
+            Ok(parameters)
+        } else {
+            Err(ResponseError {
+                command: header.command,
+                comp_code: header.comp_code,
+                reason: header.reason,
+                parameters,
+            })
+        }
+    }
+}
+
+/// Sends PCF commands to the command server using MQI put and get, without the MQAI functions.
+///
+/// Responses are received on a dynamic queue created from a model queue when the client is opened.
+#[derive(Debug)]
+pub struct CommandClient<C: Conn> {
+    command_queue: Object<C>,
+    reply_queue: Object<C>,
+    reply_queue_name: Option<QueueName>,
+    wait_interval: sys::MQLONG,
+    buffer_size: usize,
+}
+
+impl<C: Conn + Clone> CommandClient<C> {
+    /// Open a client for the `SYSTEM.ADMIN.COMMAND.QUEUE` with a reply queue created from the
+    /// `SYSTEM.DEFAULT.MODEL.QUEUE`
+    pub fn open(connection: C) -> ResultComp<Self> {
+        Self::open_with_queues(connection, COMMAND_QUEUE, MODEL_QUEUE)
+    }
+
+    pub fn open_with_queues(connection: C, command_queue: QueueName, model_queue: QueueName) -> ResultComp<Self> {
+        let Completion(command_queue, command_warning) = Object::open(
+            connection.clone(),
+            (command_queue, MQOO(sys::MQOO_OUTPUT | sys::MQOO_FAIL_IF_QUIESCING)),
+        )?;
+        let Completion((reply_queue, reply_queue_name), reply_warning) = Object::open_with(
+            connection,
+            (
+                model_queue,
+                REPLY_QUEUE_PREFIX,
+                MQOO(sys::MQOO_INPUT_EXCLUSIVE | sys::MQOO_FAIL_IF_QUIESCING),
+            ),
+        )?;
+
+        Ok(Completion(
+            Self {
+                command_queue,
+                reply_queue,
+                reply_queue_name,
+                wait_interval: DEFAULT_WAIT_INTERVAL,
+                buffer_size: DEFAULT_BUFFER_SIZE,
+            },
+            command_warning.or(reply_warning),
+        ))
+    }
+}
+
+impl<C: Conn> CommandClient<C> {
+    /// Milliseconds to wait for each response message. Defaults to 30 seconds.
+    pub fn set_wait_interval(&mut self, wait_interval: sys::MQLONG) -> &mut Self {
+        self.wait_interval = wait_interval;
+        self
+    }
+
+    /// Size of the largest response message that can be received. Defaults to 1MiB.
+    pub fn set_buffer_size(&mut self, buffer_size: usize) -> &mut Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    /// Name of the dynamic queue that responses are received on
+    #[must_use]
+    pub const fn reply_queue_name(&self) -> Option<&QueueName> {
+        self.reply_queue_name.as_ref()
+    }
+
+    /// Execute the PCF `command`, returning every response up to and including the one
+    /// with `MQCFC_LAST`.
+    pub fn execute(&self, command: MQCMD, parameters: Parameters) -> Result<Vec<Response>, CommandError> {
+        self.execute_message(&PcfMessage::new(Header::command(command), parameters))
+    }
+
+    /// Execute a prepared PCF request message
+    pub fn execute_message(&self, request: &PcfMessage) -> Result<Vec<Response>, CommandError> {
+        let mut md = MessageDescriptor::new();
+        md.set_msg_type(MQMT(sys::MQMT_REQUEST))
+            .set_reply_to_queue(self.reply_queue_name.as_ref());
+
+        let MessageId(request_id) = self
+            .command_queue
+            .put_message_with::<MessageId>(
+                (
+                    md,
+                    MQPMO(sys::MQPMO_NO_SYNCPOINT | sys::MQPMO_NEW_MSG_ID | sys::MQPMO_FAIL_IF_QUIESCING),
+                ),
+                request,
+            )
+            .discard_warning()?;
+
+        let mut buffer = vec![0; self.buffer_size];
+        let mut responses = Vec::new();
+        loop {
+            // Conversion warnings are ignored as the decoder handles either integer encoding
+            let (data, format) = self
+                .reply_queue
+                .get_data_with::<MessageFormat, _>(
+                    (
+                        CorrelationId(request_id),
+                        GetWait::Wait(self.wait_interval),
+                        GetConvert::ConvertTo(CCSID(1208), MQENC(sys::MQENC_NATIVE)),
+                        MQGMO(sys::MQGMO_NO_SYNCPOINT | sys::MQGMO_FAIL_IF_QUIESCING),
+                    ),
+                    buffer.as_mut_slice(),
+                )
+                .discard_warning()?
+                .ok_or(CommandError::NoResponse)?;
+
+            let response = PcfMessage::from_message(&data, &format)?;
+            let last = response.header.is_last();
+            responses.push(response.into_response());
+            if last {
+                return Ok(responses);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pcf::{Header, Parameters, PcfMessage},
+        sys,
+        values::{MqaiSelector, MQCC, MQCMD, MQRC},
+    };
+
+    #[test]
+    fn response_error() {
+        let mut header = Header::command(MQCMD(sys::MQCMD_CHANGE_Q));
+        header.comp_code = MQCC(sys::MQCC_FAILED);
+        header.reason = MQRC(sys::MQRCCF_CFST_PARM_ID_ERROR);
+        let parameters = Parameters::new().with(MqaiSelector(sys::MQIACF_PARAMETER_ID), sys::MQCA_Q_NAME);
+
+        let error = PcfMessage::new(header, parameters.clone())
+            .into_response()
+            .expect_err("failed response should be an error");
+        assert_eq!(error.reason, MQRC(sys::MQRCCF_CFST_PARM_ID_ERROR));
+        assert_eq!(error.parameters, parameters);
+
+        assert!(PcfMessage::command(MQCMD(sys::MQCMD_CHANGE_Q)).into_response().is_ok());
+    }
+}
@@ -0,0 +1,358 @@
+use crate::{
+    core::mqai::Filter,
+    headers::{fmt, TextEnc},
+    sys,
+    types::{Fmt, MessageFormat, StrucId},
+    values::{MqaiSelector, CCSID, MQCC, MQCFC, MQCFOP, MQCFT, MQCMD, MQENC, MQRC},
+    StrCcsidOwned,
+};
+
+use super::{is_le, Header, Parameters, PcfMessage, Value};
+
+#[derive(derive_more::Error, derive_more::Display, Debug, Clone, PartialEq)]
+pub enum PcfError {
+    #[display("Unexpected message format '{}'", _0)]
+    #[error(ignore)]
+    UnexpectedFormat(TextEnc<Fmt>),
+    #[display("PCF data truncated at offset {}: {} bytes required", _0, _1)]
+    #[error(ignore)]
+    Truncated(usize, usize),
+    #[display("Invalid structure length {} at offset {}", _1, _0)]
+    #[error(ignore)]
+    InvalidLength(usize, sys::MQLONG),
+    #[display("Unsupported structure type {} at offset {}", _1, _0)]
+    #[error(ignore)]
+    UnsupportedType(usize, MQCFT),
+    #[display("Invalid count or length {} at offset {}", _1, _0)]
+    #[error(ignore)]
+    InvalidCount(usize, sys::MQLONG),
+    #[display("{} bytes of unexpected data after the PCF message", _0)]
+    #[error(ignore)]
+    TrailingData(usize),
+    #[display("Unexpected embedded PCF header STRUC_ID: {:?}", _0)]
+    #[error(ignore)]
+    UnexpectedStrucId(StrucId),
+}
+
+/// Cursor over PCF data
+struct Reader<'a> {
+    data: &'a [u8],
+    base: usize,
+    offset: usize,
+    le: bool,
+}
+
+impl<'a> Reader<'a> {
+    const fn new(data: &'a [u8], base: usize, le: bool) -> Self {
+        Self {
+            data,
+            base,
+            offset: 0,
+            le,
+        }
+    }
+
+    const fn position(&self) -> usize {
+        self.base + self.offset
+    }
+
+    const fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], PcfError> {
+        let data = self
+            .data
+            .get(self.offset..self.offset + length)
+            .ok_or(PcfError::Truncated(self.position(), length))?;
+        self.offset += length;
+        Ok(data)
+    }
+
+    fn long(&mut self) -> Result<sys::MQLONG, PcfError> {
+        let bytes = self.bytes(4)?.try_into().expect("slice should be 4 bytes");
+        Ok(if self.le {
+            sys::MQLONG::from_le_bytes(bytes)
+        } else {
+            sys::MQLONG::from_be_bytes(bytes)
+        })
+    }
+
+    fn long64(&mut self) -> Result<i64, PcfError> {
+        let bytes = self.bytes(8)?.try_into().expect("slice should be 8 bytes");
+        Ok(if self.le {
+            i64::from_le_bytes(bytes)
+        } else {
+            i64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Reads a non-negative count or length
+    fn count(&mut self) -> Result<usize, PcfError> {
+        let position = self.position();
+        let value = self.long()?;
+        usize::try_from(value).map_err(|_| PcfError::InvalidCount(position, value))
+    }
+
+    /// Checks that `count` items of `size` bytes fit in the rest of the structure, so a forged count is rejected
+    /// before anything is allocated. Zero sized items are bounded by the structure length.
+    fn items(&self, position: usize, count: usize, size: usize) -> Result<(), PcfError> {
+        if count <= self.data.len() && count.checked_mul(size).is_some_and(|total| total <= self.remaining()) {
+            Ok(())
+        } else {
+            let count = sys::MQLONG::try_from(count).expect("count should be read from an MQLONG");
+            Err(PcfError::InvalidCount(position, count))
+        }
+    }
+
+    /// Splits off the structure starting at the current offset, returning its type and a reader
+    /// positioned after the type and length fields.
+    fn structure(&mut self, min_length: sys::MQLONG) -> Result<(MQCFT, Self), PcfError> {
+        let position = self.position();
+        let start = self.offset;
+        let kind = MQCFT(self.long()?);
+        let length = self.long()?;
+        if length < min_length || length % 4 != 0 {
+            return Err(PcfError::InvalidLength(position, length));
+        }
+        self.offset = start;
+        let data = self.bytes(usize::try_from(length).map_err(|_| PcfError::InvalidLength(position, length))?)?;
+        let mut reader = Self::new(data, position, self.le);
+        reader.offset = 8;
+        Ok((kind, reader))
+    }
+}
+
+/// Length of the `MQEPH` fields that precede the embedded `MQCFH`
+const EPH_PREFIX_LENGTH: usize = 32;
+
+/// Smallest structure with a type, length and parameter field
+const MIN_PARAMETER_LENGTH: sys::MQLONG = 12;
+
+fn parameters(reader: &mut Reader, count: usize) -> Result<Parameters, PcfError> {
+    (0..count).map(|_| parameter(reader)).collect()
+}
+
+fn parameter(outer: &mut Reader) -> Result<(MqaiSelector, Value), PcfError> {
+    let (kind, mut reader) = outer.structure(MIN_PARAMETER_LENGTH)?;
+    let selector = MqaiSelector(reader.long()?);
+    let value = match kind.0 {
+        sys::MQCFT_INTEGER => Value::Integer(reader.long()?),
+        sys::MQCFT_INTEGER64 => {
+            let _reserved = reader.long()?;
+            Value::Integer64(reader.long64()?)
+        }
+        sys::MQCFT_STRING => {
+            let ccsid = CCSID(reader.long()?);
+            let length = reader.count()?;
+            Value::String(StrCcsidOwned::new(reader.bytes(length)?.to_vec(), ccsid, reader.le))
+        }
+        sys::MQCFT_BYTE_STRING => {
+            let length = reader.count()?;
+            Value::ByteString(reader.bytes(length)?.to_vec())
+        }
+        sys::MQCFT_INTEGER_LIST => {
+            let position = reader.position();
+            let count = reader.count()?;
+            reader.items(position, count, 4)?;
+            Value::IntegerList((0..count).map(|_| reader.long()).collect::<Result<_, _>>()?)
+        }
+        sys::MQCFT_INTEGER64_LIST => {
+            let position = reader.position();
+            let count = reader.count()?;
+            reader.items(position, count, 8)?;
+            Value::Integer64List((0..count).map(|_| reader.long64()).collect::<Result<_, _>>()?)
+        }
+        sys::MQCFT_STRING_LIST => {
+            let ccsid = CCSID(reader.long()?);
+            let position = reader.position();
+            let count = reader.count()?;
+            let length = reader.count()?;
+            reader.items(position, count, length)?;
+            Value::StringList(
+                ccsid,
+                (0..count)
+                    .map(|_| reader.bytes(length).map(<[u8]>::to_vec))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        sys::MQCFT_GROUP => {
+            let count = reader.count()?;
+            // Group members follow the MQCFGR structure rather than being contained within it
+            Value::Group(parameters(outer, count)?)
+        }
+        sys::MQCFT_INTEGER_FILTER => {
+            let operator = MQCFOP(reader.long()?);
+            Value::IntegerFilter(Filter::new(reader.long()?, operator))
+        }
+        sys::MQCFT_STRING_FILTER => {
+            let operator = MQCFOP(reader.long()?);
+            let ccsid = CCSID(reader.long()?);
+            let length = reader.count()?;
+            let value = StrCcsidOwned::new(reader.bytes(length)?.to_vec(), ccsid, reader.le);
+            Value::StringFilter(Filter::new(value, operator))
+        }
+        sys::MQCFT_BYTE_STRING_FILTER => {
+            let operator = MQCFOP(reader.long()?);
+            let length = reader.count()?;
+            Value::ByteStringFilter(Filter::new(reader.bytes(length)?.to_vec(), operator))
+        }
+        _ => return Err(PcfError::UnsupportedType(reader.base, kind)),
+    };
+    Ok((selector, value))
+}
+
+impl PcfMessage {
+    /// Decode a PCF message using the integer `encoding` of the message descriptor
+    pub fn from_bytes(data: &[u8], encoding: MQENC) -> Result<Self, PcfError> {
+        Self::decode(data, 0, encoding)
+    }
+
+    /// Decode the PCF message embedded in an `MQEPH` header, such as an activity report, returning the message
+    /// and the data that follows the header.
+    pub fn from_embedded(data: &[u8], encoding: MQENC) -> Result<(Self, &[u8]), PcfError> {
+        let mut reader = Reader::new(data, 0, is_le(encoding));
+        let struc_id: StrucId = reader.bytes(4)?.try_into().expect("slice should be 4 bytes");
+        if &struc_id != b"EPH " {
+            return Err(PcfError::UnexpectedStrucId(struc_id));
+        }
+        let _version = reader.long()?;
+        let position = reader.position();
+        let length = reader.long()?;
+        if length < sys::MQEPH_STRUC_LENGTH_FIXED || length % 4 != 0 {
+            return Err(PcfError::InvalidLength(position, length));
+        }
+        let end = usize::try_from(length).map_err(|_| PcfError::InvalidLength(position, length))?;
+        let pcf = data
+            .get(EPH_PREFIX_LENGTH..end)
+            .ok_or(PcfError::Truncated(EPH_PREFIX_LENGTH, end - EPH_PREFIX_LENGTH))?;
+        Ok((Self::decode(pcf, EPH_PREFIX_LENGTH, encoding)?, &data[end..]))
+    }
+
+    fn decode(data: &[u8], base: usize, encoding: MQENC) -> Result<Self, PcfError> {
+        let mut outer = Reader::new(data, base, is_le(encoding));
+        let (kind, mut reader) = outer.structure(sys::MQCFH_STRUC_LENGTH)?;
+        let header = Header {
+            kind,
+            version: reader.long()?,
+            command: MQCMD(reader.long()?),
+            msg_seq_number: reader.long()?,
+            control: MQCFC(reader.long()?),
+            comp_code: MQCC(reader.long()?),
+            reason: MQRC(reader.long()?),
+        };
+        let count = reader.count()?;
+        let parameters = parameters(&mut outer, count)?;
+
+        match outer.remaining() {
+            0 => Ok(Self { header, parameters }),
+            remaining => Err(PcfError::TrailingData(remaining)),
+        }
+    }
+
+    /// Decode a PCF message retrieved from a queue with the message `format`. Any data following an
+    /// embedded PCF header is ignored.
+    pub fn from_message(data: &[u8], format: &MessageFormat) -> Result<Self, PcfError> {
+        if format.fmt == TextEnc::Ascii(fmt::MQFMT_EMBEDDED_PCF) {
+            return Self::from_embedded(data, format.encoding).map(|(message, _)| message);
+        }
+        if ![fmt::MQFMT_ADMIN, fmt::MQFMT_EVENT, fmt::MQFMT_PCF]
+            .map(TextEnc::Ascii)
+            .contains(&format.fmt)
+        {
+            return Err(PcfError::UnexpectedFormat(format.fmt));
+        }
+        Self::from_bytes(data, format.encoding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sys,
+        values::{MqaiSelector, MQCFT, MQENC},
+    };
+
+    use super::{PcfError, PcfMessage, Value};
+
+    const HEADER: [u8; 36] = [
+        0, 0, 0, 2, 0, 0, 0, 36, 0, 0, 0, 1, 0, 0, 0, 13, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    ];
+
+    #[test]
+    fn response_fixture() -> Result<(), PcfError> {
+        // MQCFH response to MQCMD_INQUIRE_Q followed by an MQCFST Q_NAME padded to 8 bytes
+        let mut data = HEADER.to_vec();
+        data.extend_from_slice(&[0, 0, 0, 4, 0, 0, 0, 28, 0, 0, 7, 0xE0, 0, 0, 4, 0xB8, 0, 0, 0, 5]);
+        data.extend_from_slice(b"Q1   \0\0\0");
+
+        let message = PcfMessage::from_bytes(&data, MQENC(sys::MQENC_INTEGER_NORMAL))?;
+        assert_eq!(message.header.command.0, sys::MQCMD_INQUIRE_Q);
+        assert!(message.header.is_last());
+        let name = message
+            .parameters
+            .string(MqaiSelector(sys::MQCA_Q_NAME))
+            .expect("queue name should be present");
+        assert_eq!(name.data, b"Q1   ");
+        assert_eq!(name.ccsid, 1208);
+        Ok(())
+    }
+
+    #[test]
+    fn malformed() {
+        let encoding = MQENC(sys::MQENC_INTEGER_NORMAL);
+        assert_eq!(
+            PcfMessage::from_bytes(&HEADER[..20], encoding),
+            Err(PcfError::Truncated(0, 36))
+        );
+
+        let mut data = HEADER.to_vec();
+        data.extend_from_slice(&[0, 0, 0, 99, 0, 0, 0, 12, 0, 0, 0, 1]);
+        assert_eq!(
+            PcfMessage::from_bytes(&data, encoding),
+            Err(PcfError::UnsupportedType(36, MQCFT(99)))
+        );
+
+        let mut data = HEADER.to_vec();
+        data.extend_from_slice(&[0, 0, 0, 3, 0, 0, 0, 13, 0, 0, 0, 1, 0, 0, 0, 1]);
+        assert_eq!(PcfMessage::from_bytes(&data, encoding), Err(PcfError::InvalidLength(36, 13)));
+
+        let mut data = HEADER.to_vec();
+        data.extend_from_slice(&[0, 0, 0, 3, 0, 0, 0, 16, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(PcfMessage::from_bytes(&data, encoding), Err(PcfError::TrailingData(4)));
+        assert!(matches!(
+            PcfMessage::from_bytes(&data[..52], encoding).map(|m| m.parameters.get(MqaiSelector(1)).cloned()),
+            Ok(Some(Value::Integer(1)))
+        ));
+    }
+
+    #[test]
+    fn malformed_list_count() {
+        let encoding = MQENC(sys::MQENC_INTEGER_NORMAL);
+
+        // MQCFSL of i32::MAX empty strings
+        let mut data = HEADER.to_vec();
+        data.extend_from_slice(&[
+            0, 0, 0, 6, 0, 0, 0, 24, 0, 0, 0, 1, 0, 0, 4, 0xB8, 0x7F, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0,
+        ]);
+        assert_eq!(
+            PcfMessage::from_bytes(&data, encoding),
+            Err(PcfError::InvalidCount(52, sys::MQLONG::MAX))
+        );
+
+        // MQCFSL of 3 strings of 4 bytes with only 8 bytes of string data
+        let mut data = HEADER.to_vec();
+        data.extend_from_slice(&[0, 0, 0, 6, 0, 0, 0, 32, 0, 0, 0, 1, 0, 0, 4, 0xB8, 0, 0, 0, 3, 0, 0, 0, 4]);
+        data.extend_from_slice(b"ABCDEFGH");
+        assert_eq!(PcfMessage::from_bytes(&data, encoding), Err(PcfError::InvalidCount(52, 3)));
+
+        // MQCFIL and MQCFIL64 claiming 1000 values with only one present
+        let mut data = HEADER.to_vec();
+        data.extend_from_slice(&[0, 0, 0, 5, 0, 0, 0, 20, 0, 0, 0, 1, 0, 0, 0x03, 0xE8, 0, 0, 0, 1]);
+        assert_eq!(PcfMessage::from_bytes(&data, encoding), Err(PcfError::InvalidCount(48, 1000)));
+
+        let mut data = HEADER.to_vec();
+        data.extend_from_slice(&[0, 0, 0, 25, 0, 0, 0, 24, 0, 0, 0, 1, 0, 0, 0x03, 0xE8, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(PcfMessage::from_bytes(&data, encoding), Err(PcfError::InvalidCount(48, 1000)));
+    }
+}
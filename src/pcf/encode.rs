@@ -0,0 +1,231 @@
+use crate::{
+    sys,
+    values::{MqaiSelector, MQENC},
+};
+
+use super::{is_le, Parameters, PcfMessage, Value};
+
+/// Blank used to pad the strings of a `MQCFSL` to a common length
+const STRING_PAD: u8 = b' ';
+
+struct Writer {
+    data: Vec<u8>,
+    le: bool,
+}
+
+fn long_length(length: usize) -> sys::MQLONG {
+    sys::MQLONG::try_from(length).expect("PCF structure length should fit in an MQLONG")
+}
+
+/// Rounds the length up to the 4 byte boundary required of PCF structures
+const fn padded(length: usize) -> usize {
+    length.next_multiple_of(4)
+}
+
+impl Writer {
+    fn long(&mut self, value: sys::MQLONG) {
+        self.data
+            .extend_from_slice(&if self.le { value.to_le_bytes() } else { value.to_be_bytes() });
+    }
+
+    fn long64(&mut self, value: i64) {
+        self.data
+            .extend_from_slice(&if self.le { value.to_le_bytes() } else { value.to_be_bytes() });
+    }
+
+    fn length(&mut self, length: usize) {
+        self.long(long_length(length));
+    }
+
+    /// Writes the type, length and parameter fields of a parameter structure
+    fn start(&mut self, kind: sys::MQLONG, length: usize, selector: MqaiSelector) {
+        self.long(kind);
+        self.length(length);
+        self.long(selector.0);
+    }
+
+    /// Writes the data followed by nulls up to the 4 byte boundary
+    fn padded_bytes(&mut self, data: &[u8]) {
+        self.data.extend_from_slice(data);
+        self.data.resize(self.data.len() + padded(data.len()) - data.len(), 0);
+    }
+
+    fn parameters(&mut self, parameters: &Parameters) {
+        for (selector, value) in parameters {
+            self.parameter(*selector, value);
+        }
+    }
+
+    fn parameter(&mut self, selector: MqaiSelector, value: &Value) {
+        let kind = value.kind().0;
+        match value {
+            &Value::Integer(value) => {
+                self.start(kind, fixed_length(sys::MQCFIN_STRUC_LENGTH), selector);
+                self.long(value);
+            }
+            &Value::Integer64(value) => {
+                self.start(kind, fixed_length(sys::MQCFIN64_STRUC_LENGTH), selector);
+                self.long(0); // Reserved
+                self.long64(value);
+            }
+            Value::String(value) => {
+                self.start(
+                    kind,
+                    fixed_length(sys::MQCFST_STRUC_LENGTH_FIXED) + padded(value.data.len()),
+                    selector,
+                );
+                self.long(value.ccsid.0);
+                self.length(value.data.len());
+                self.padded_bytes(&value.data);
+            }
+            Value::ByteString(value) => {
+                self.start(
+                    kind,
+                    fixed_length(sys::MQCFBS_STRUC_LENGTH_FIXED) + padded(value.len()),
+                    selector,
+                );
+                self.length(value.len());
+                self.padded_bytes(value);
+            }
+            Value::IntegerList(values) => {
+                self.start(
+                    kind,
+                    fixed_length(sys::MQCFIL_STRUC_LENGTH_FIXED) + values.len() * 4,
+                    selector,
+                );
+                self.length(values.len());
+                for &value in values {
+                    self.long(value);
+                }
+            }
+            Value::Integer64List(values) => {
+                self.start(
+                    kind,
+                    fixed_length(sys::MQCFIL64_STRUC_LENGTH_FIXED) + values.len() * 8,
+                    selector,
+                );
+                self.length(values.len());
+                for &value in values {
+                    self.long64(value);
+                }
+            }
+            Value::StringList(ccsid, values) => {
+                let string_length = values.iter().map(Vec::len).max().unwrap_or(0);
+                let strings_length = string_length * values.len();
+                self.start(
+                    kind,
+                    fixed_length(sys::MQCFSL_STRUC_LENGTH_FIXED) + padded(strings_length),
+                    selector,
+                );
+                self.long(ccsid.0);
+                self.length(values.len());
+                self.length(string_length);
+                for value in values {
+                    self.data.extend_from_slice(value);
+                    self.data.resize(self.data.len() + string_length - value.len(), STRING_PAD);
+                }
+                self.data.resize(self.data.len() + padded(strings_length) - strings_length, 0);
+            }
+            Value::Group(parameters) => {
+                self.start(kind, fixed_length(sys::MQCFGR_STRUC_LENGTH), selector);
+                self.length(parameters.len());
+                self.parameters(parameters);
+            }
+            Value::IntegerFilter(filter) => {
+                self.start(kind, fixed_length(sys::MQCFIF_STRUC_LENGTH), selector);
+                self.long(filter.operator.0);
+                self.long(filter.value);
+            }
+            Value::StringFilter(filter) => {
+                let value = &filter.value;
+                self.start(
+                    kind,
+                    fixed_length(sys::MQCFSF_STRUC_LENGTH_FIXED) + padded(value.data.len()),
+                    selector,
+                );
+                self.long(filter.operator.0);
+                self.long(value.ccsid.0);
+                self.length(value.data.len());
+                self.padded_bytes(&value.data);
+            }
+            Value::ByteStringFilter(filter) => {
+                let value = &filter.value;
+                self.start(
+                    kind,
+                    fixed_length(sys::MQCFBF_STRUC_LENGTH_FIXED) + padded(value.len()),
+                    selector,
+                );
+                self.long(filter.operator.0);
+                self.length(value.len());
+                self.padded_bytes(value);
+            }
+        }
+    }
+}
+
+/// Fixed structure length as a `usize`
+fn fixed_length(length: sys::MQLONG) -> usize {
+    usize::try_from(length).expect("PCF structure length should be positive")
+}
+
+impl PcfMessage {
+    /// Encode the PCF message using the integer `encoding` of the message descriptor
+    #[must_use]
+    pub fn to_bytes(&self, encoding: MQENC) -> Vec<u8> {
+        let mut writer = Writer {
+            data: Vec::new(),
+            le: is_le(encoding),
+        };
+        let header = &self.header;
+        writer.long(header.kind.0);
+        writer.long(sys::MQCFH_STRUC_LENGTH);
+        writer.long(header.version);
+        writer.long(header.command.0);
+        writer.long(header.msg_seq_number);
+        writer.long(header.control.0);
+        writer.long(header.comp_code.0);
+        writer.long(header.reason.0);
+        writer.length(self.parameters.len());
+        writer.parameters(&self.parameters);
+        writer.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sys,
+        values::{MqaiSelector, CCSID, MQCMD, MQENC},
+    };
+
+    use crate::pcf::{PcfMessage, Value};
+
+    #[test]
+    fn padding() {
+        let mut message = PcfMessage::command(MQCMD(sys::MQCMD_INQUIRE_Q));
+        message.parameters.add(MqaiSelector(sys::MQCA_Q_NAME), "Q1").add(
+            MqaiSelector(sys::MQCACF_Q_NAMES),
+            Value::StringList(CCSID(1208), vec![b"A".to_vec(), b"BBB".to_vec()]),
+        );
+
+        let data = message.to_bytes(MQENC(sys::MQENC_INTEGER_NORMAL));
+        assert_eq!(
+            &data[36..],
+            [
+                [0, 0, 0, 4, 0, 0, 0, 24, 0, 0, 7, 0xE0, 0, 0, 4, 0xB8, 0, 0, 0, 2].as_slice(),
+                b"Q1\0\0",
+                &[0, 0, 0, 6, 0, 0, 0, 32, 0, 0, 11, 0xC3, 0, 0, 4, 0xB8, 0, 0, 0, 2, 0, 0, 0, 3],
+                b"A  BBB\0\0",
+            ]
+            .concat()
+        );
+
+        let reversed = message.to_bytes(MQENC(sys::MQENC_INTEGER_REVERSED));
+        assert_eq!(&reversed[..8], [1, 0, 0, 0, 36, 0, 0, 0]);
+        let decoded = PcfMessage::from_bytes(&reversed, MQENC(sys::MQENC_INTEGER_REVERSED)).expect("message should decode");
+        assert_eq!(
+            decoded.parameters.get(MqaiSelector(sys::MQCACF_Q_NAMES)),
+            Some(&Value::StringList(CCSID(1208), vec![b"A  ".to_vec(), b"BBB".to_vec()]))
+        );
+    }
+}
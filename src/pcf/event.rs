@@ -0,0 +1,659 @@
+use crate::{
+    mqstr, sys,
+    types::{ChannelName, ConnectionName, ObjectName, QueueManagerName, QueueName, UserIdentifier},
+    values::{MqaiSelector, MQAT, MQCFT, MQCMD, MQCO, MQENC, MQOO, MQOT},
+    MqStr,
+};
+
+use super::{
+    fields::{FieldError, Fields},
+    Parameters, PcfError, PcfMessage,
+};
+
+/// Queue receiving queue manager events such as not authorized
+pub const QMGR_EVENT_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.ADMIN.QMGR.EVENT"));
+/// Queue receiving channel events
+pub const CHANNEL_EVENT_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.ADMIN.CHANNEL.EVENT"));
+/// Queue receiving performance events
+pub const PERFM_EVENT_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.ADMIN.PERFM.EVENT"));
+/// Queue receiving configuration events
+pub const CONFIG_EVENT_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.ADMIN.CONFIG.EVENT"));
+/// Queue receiving command events
+pub const COMMAND_EVENT_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.ADMIN.COMMAND.EVENT"));
+
+pub type ApplName = MqStr<{ sys::MQ_APPL_NAME_LENGTH }>;
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug, Clone, PartialEq)]
+pub enum EventError {
+    #[display("PCF message is not an event: {}", _0)]
+    #[error(ignore)]
+    NotEvent(MQCFT),
+    #[display("Event parameter {} is missing or has an unexpected type", _0)]
+    #[error(ignore)]
+    Parameter(MqaiSelector),
+    #[display("Event parameter {} is not a valid string", _0)]
+    #[error(ignore)]
+    InvalidString(MqaiSelector),
+    #[from]
+    Pcf(PcfError),
+}
+
+/// Instrumentation event parsed from a PCF event message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// `MQRC_NOT_AUTHORIZED` queue manager event
+    NotAuthorized(NotAuthorized),
+    /// `MQRC_Q_FULL` performance event
+    QueueFull(QueuePerformance),
+    /// `MQRC_Q_DEPTH_HIGH` performance event
+    QueueDepthHigh(QueuePerformance),
+    /// `MQRC_Q_DEPTH_LOW` performance event
+    QueueDepthLow(QueuePerformance),
+    /// `MQRC_Q_SERVICE_INTERVAL_HIGH` performance event
+    QueueServiceIntervalHigh(QueuePerformance),
+    /// `MQRC_Q_SERVICE_INTERVAL_OK` performance event
+    QueueServiceIntervalOk(QueuePerformance),
+    /// `MQRC_CHANNEL_STARTED` channel event
+    ChannelStarted(ChannelStarted),
+    /// `MQRC_CHANNEL_STOPPED` channel event
+    ChannelStopped(ChannelStopped),
+    /// `MQRC_CHANNEL_STOPPED_BY_USER` channel event
+    ChannelStoppedByUser(ChannelStopped),
+    /// `MQRC_CONFIG_CREATE_OBJECT` configuration event
+    ConfigCreate(ConfigObject),
+    /// `MQRC_CONFIG_CHANGE_OBJECT` configuration event. Each change is reported as a pair of
+    /// events with the object before and after the change.
+    ConfigChange(ChangePhase, ConfigObject),
+    /// `MQRC_CONFIG_DELETE_OBJECT` configuration event
+    ConfigDelete(ConfigObject),
+    /// `MQRC_CONFIG_REFRESH_OBJECT` configuration event
+    ConfigRefresh(ConfigObject),
+    /// `MQRC_COMMAND_MQSC` or `MQRC_COMMAND_PCF` command event
+    Command(CommandEvent),
+    /// Event message without a typed representation
+    Other(PcfMessage),
+}
+
+/// Performance event data for a queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueuePerformance {
+    pub queue_manager: QueueManagerName,
+    pub queue: QueueName,
+    /// Seconds since the statistics were last reset
+    pub time_since_reset: sys::MQLONG,
+    pub high_depth: sys::MQLONG,
+    pub enqueue_count: sys::MQLONG,
+    pub dequeue_count: sys::MQLONG,
+}
+
+/// Reason qualifier of a not authorized event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotAuthorizedKind {
+    /// Type 1: `MQCONN` or `MQCONNX`
+    Connect,
+    /// Type 2: `MQOPEN` or `MQPUT1`
+    Open { options: MQOO, object: Option<ObjectName> },
+    /// Type 3: `MQCLOSE` of a permanent dynamic queue
+    Close { options: MQCO, object: Option<ObjectName> },
+    /// Type 4: command
+    Command { command: MQCMD },
+    /// Any other `MQRQ_*` reason qualifier, such as `MQRQ_SUB_NOT_AUTHORIZED`
+    Other(sys::MQLONG),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotAuthorized {
+    pub queue_manager: QueueManagerName,
+    pub kind: NotAuthorizedKind,
+    pub user: UserIdentifier,
+    pub appl_type: MQAT,
+    pub appl_name: ApplName,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelStarted {
+    pub queue_manager: QueueManagerName,
+    pub channel: ChannelName,
+    pub connection_name: Option<ConnectionName>,
+    pub transmission_queue: Option<QueueName>,
+}
+
+/// Error details of a stopped channel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelError {
+    /// Identifier of the error message (`AMQ` number) that reported the stop
+    pub identifier: sys::MQLONG,
+    pub aux_int: [sys::MQLONG; 2],
+    pub aux_str: [String; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelStopped {
+    pub queue_manager: QueueManagerName,
+    /// `MQRQ_*` reason qualifier
+    pub qualifier: sys::MQLONG,
+    pub channel: ChannelName,
+    pub error: Option<ChannelError>,
+    pub connection_name: Option<ConnectionName>,
+    pub transmission_queue: Option<QueueName>,
+}
+
+/// Application that caused a configuration or command event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EventContext {
+    pub user: Option<UserIdentifier>,
+    /// `MQEVO_*` origin of the event
+    pub origin: Option<sys::MQLONG>,
+    pub queue_manager: Option<QueueManagerName>,
+    pub appl_type: Option<MQAT>,
+    pub appl_name: Option<ApplName>,
+}
+
+/// Whether a change object event reports the object before or after the change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangePhase {
+    Before,
+    After,
+}
+
+/// Object reported by a configuration event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigObject {
+    pub context: EventContext,
+    pub object_type: MQOT,
+    pub object_name: Option<ObjectName>,
+    /// All attributes of the object, including the event context
+    pub attributes: Parameters,
+}
+
+/// Command reported by a command event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandData {
+    Mqsc(String),
+    Pcf(Parameters),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandEvent {
+    pub context: EventContext,
+    pub command: MQCMD,
+    pub data: CommandData,
+}
+
+impl From<FieldError> for EventError {
+    fn from(error: FieldError) -> Self {
+        match error {
+            FieldError::Parameter(selector) => Self::Parameter(selector),
+            FieldError::InvalidString(selector) => Self::InvalidString(selector),
+        }
+    }
+}
+
+/// Selectors of the object name in not authorized events
+const OBJECT_NAMES: &[sys::MQLONG] = &[
+    sys::MQCA_Q_NAME,
+    sys::MQCA_PROCESS_NAME,
+    sys::MQCA_NAMELIST_NAME,
+    sys::MQCA_TOPIC_NAME,
+    sys::MQCACF_OBJECT_Q_MGR_NAME,
+];
+
+/// Selector of the object name for each configuration event object type
+const fn object_name_selector(object_type: MQOT) -> Option<sys::MQLONG> {
+    match object_type.0 {
+        sys::MQOT_Q | sys::MQOT_LOCAL_Q | sys::MQOT_MODEL_Q | sys::MQOT_ALIAS_Q | sys::MQOT_REMOTE_Q => Some(sys::MQCA_Q_NAME),
+        sys::MQOT_Q_MGR => Some(sys::MQCA_Q_MGR_NAME),
+        sys::MQOT_CHANNEL => Some(sys::MQCACH_CHANNEL_NAME),
+        sys::MQOT_PROCESS => Some(sys::MQCA_PROCESS_NAME),
+        sys::MQOT_NAMELIST => Some(sys::MQCA_NAMELIST_NAME),
+        sys::MQOT_TOPIC => Some(sys::MQCA_TOPIC_NAME),
+        sys::MQOT_AUTH_INFO => Some(sys::MQCA_AUTH_INFO_NAME),
+        sys::MQOT_LISTENER => Some(sys::MQCACH_LISTENER_NAME),
+        sys::MQOT_SERVICE => Some(sys::MQCA_SERVICE_NAME),
+        sys::MQOT_COMM_INFO => Some(sys::MQCA_COMM_INFO_NAME),
+        sys::MQOT_CF_STRUC => Some(sys::MQCA_CF_STRUC_NAME),
+        sys::MQOT_STORAGE_CLASS => Some(sys::MQCA_STORAGE_CLASS),
+        _ => None,
+    }
+}
+
+impl EventContext {
+    fn parse(fields: &Fields) -> Result<Self, EventError> {
+        Ok(Self {
+            user: fields.name_opt(sys::MQCACF_EVENT_USER_ID)?,
+            origin: fields.integer_opt(sys::MQIACF_EVENT_ORIGIN)?,
+            queue_manager: fields.name_opt(sys::MQCACF_EVENT_Q_MGR)?,
+            appl_type: fields.integer_opt(sys::MQIACF_EVENT_APPL_TYPE)?.map(MQAT),
+            appl_name: fields.name_opt(sys::MQCACF_EVENT_APPL_NAME)?,
+        })
+    }
+}
+
+impl QueuePerformance {
+    fn parse(fields: &Fields) -> Result<Self, EventError> {
+        Ok(Self {
+            queue_manager: fields.name(sys::MQCA_Q_MGR_NAME)?,
+            queue: fields.name(sys::MQCA_BASE_OBJECT_NAME)?,
+            time_since_reset: fields.integer(sys::MQIA_TIME_SINCE_RESET)?,
+            high_depth: fields.integer(sys::MQIA_HIGH_Q_DEPTH)?,
+            enqueue_count: fields.integer(sys::MQIA_MSG_ENQ_COUNT)?,
+            dequeue_count: fields.integer(sys::MQIA_MSG_DEQ_COUNT)?,
+        })
+    }
+}
+
+impl NotAuthorized {
+    fn parse(fields: &Fields) -> Result<Self, EventError> {
+        let kind = match fields.integer(sys::MQIACF_REASON_QUALIFIER)? {
+            sys::MQRQ_CONN_NOT_AUTHORIZED => NotAuthorizedKind::Connect,
+            sys::MQRQ_OPEN_NOT_AUTHORIZED => NotAuthorizedKind::Open {
+                options: MQOO(fields.integer(sys::MQIACF_OPEN_OPTIONS)?),
+                object: fields.first_name_opt(OBJECT_NAMES)?,
+            },
+            sys::MQRQ_CLOSE_NOT_AUTHORIZED => NotAuthorizedKind::Close {
+                options: MQCO(fields.integer(sys::MQIACF_CLOSE_OPTIONS)?),
+                object: fields.first_name_opt(OBJECT_NAMES)?,
+            },
+            sys::MQRQ_CMD_NOT_AUTHORIZED => NotAuthorizedKind::Command {
+                command: MQCMD(fields.integer(sys::MQIACF_COMMAND)?),
+            },
+            qualifier => NotAuthorizedKind::Other(qualifier),
+        };
+        Ok(Self {
+            queue_manager: fields.name(sys::MQCA_Q_MGR_NAME)?,
+            kind,
+            user: fields.name(sys::MQCACF_USER_IDENTIFIER)?,
+            appl_type: MQAT(fields.integer(sys::MQIA_APPL_TYPE)?),
+            appl_name: fields.name(sys::MQCACF_APPL_NAME)?,
+        })
+    }
+}
+
+impl ChannelStarted {
+    fn parse(fields: &Fields) -> Result<Self, EventError> {
+        Ok(Self {
+            queue_manager: fields.name(sys::MQCA_Q_MGR_NAME)?,
+            channel: fields.name(sys::MQCACH_CHANNEL_NAME)?,
+            connection_name: fields.name_opt(sys::MQCACH_CONNECTION_NAME)?,
+            transmission_queue: fields.name_opt(sys::MQCACH_XMIT_Q_NAME)?,
+        })
+    }
+}
+
+impl ChannelStopped {
+    fn parse(fields: &Fields) -> Result<Self, EventError> {
+        let error = fields
+            .integer_opt(sys::MQIACF_ERROR_IDENTIFIER)?
+            .map(|identifier| {
+                Ok::<_, EventError>(ChannelError {
+                    identifier,
+                    aux_int: [
+                        fields.integer(sys::MQIACF_AUX_ERROR_DATA_INT_1)?,
+                        fields.integer(sys::MQIACF_AUX_ERROR_DATA_INT_2)?,
+                    ],
+                    aux_str: [
+                        fields.string(sys::MQCACF_AUX_ERROR_DATA_STR_1)?,
+                        fields.string(sys::MQCACF_AUX_ERROR_DATA_STR_2)?,
+                        fields.string(sys::MQCACF_AUX_ERROR_DATA_STR_3)?,
+                    ],
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            queue_manager: fields.name(sys::MQCA_Q_MGR_NAME)?,
+            qualifier: fields.integer(sys::MQIACF_REASON_QUALIFIER)?,
+            channel: fields.name(sys::MQCACH_CHANNEL_NAME)?,
+            error,
+            connection_name: fields.name_opt(sys::MQCACH_CONNECTION_NAME)?,
+            transmission_queue: fields.name_opt(sys::MQCACH_XMIT_Q_NAME)?,
+        })
+    }
+}
+
+impl ConfigObject {
+    fn parse(fields: &Fields) -> Result<Self, EventError> {
+        let object_type = MQOT(fields.integer(sys::MQIACF_OBJECT_TYPE)?);
+        Ok(Self {
+            context: EventContext::parse(fields)?,
+            object_type,
+            object_name: object_name_selector(object_type)
+                .map(|selector| fields.name_opt(selector))
+                .transpose()?
+                .flatten(),
+            attributes: fields.0.clone(),
+        })
+    }
+}
+
+impl CommandEvent {
+    fn parse(fields: &Fields) -> Result<Self, EventError> {
+        let context = Fields(fields.group(sys::MQGACF_COMMAND_CONTEXT)?);
+        let data = fields.group(sys::MQGACF_COMMAND_DATA)?;
+        Ok(Self {
+            context: EventContext::parse(&context)?,
+            command: MQCMD(context.integer(sys::MQIACF_COMMAND)?),
+            data: match Fields(data).str_opt(sys::MQCACF_COMMAND_MQSC)? {
+                Some(mqsc) => CommandData::Mqsc(mqsc.to_string()),
+                None => CommandData::Pcf(data.clone()),
+            },
+        })
+    }
+}
+
+impl Event {
+    /// Parse an event from a decoded PCF message. Strings in the event must be ASCII or UTF-8,
+    /// so event messages should be retrieved with conversion to CCSID 1208.
+    pub fn from_message(message: PcfMessage) -> Result<Self, EventError> {
+        let header = &message.header;
+        if header.kind.0 != sys::MQCFT_EVENT {
+            return Err(EventError::NotEvent(header.kind));
+        }
+
+        let fields = Fields(&message.parameters);
+        Ok(match (header.command.0, header.reason.0) {
+            (_, sys::MQRC_NOT_AUTHORIZED) => Self::NotAuthorized(NotAuthorized::parse(&fields)?),
+            (_, sys::MQRC_Q_FULL) => Self::QueueFull(QueuePerformance::parse(&fields)?),
+            (_, sys::MQRC_Q_DEPTH_HIGH) => Self::QueueDepthHigh(QueuePerformance::parse(&fields)?),
+            (_, sys::MQRC_Q_DEPTH_LOW) => Self::QueueDepthLow(QueuePerformance::parse(&fields)?),
+            (_, sys::MQRC_Q_SERVICE_INTERVAL_HIGH) => Self::QueueServiceIntervalHigh(QueuePerformance::parse(&fields)?),
+            (_, sys::MQRC_Q_SERVICE_INTERVAL_OK) => Self::QueueServiceIntervalOk(QueuePerformance::parse(&fields)?),
+            (_, sys::MQRC_CHANNEL_STARTED) => Self::ChannelStarted(ChannelStarted::parse(&fields)?),
+            (_, sys::MQRC_CHANNEL_STOPPED) => Self::ChannelStopped(ChannelStopped::parse(&fields)?),
+            (_, sys::MQRC_CHANNEL_STOPPED_BY_USER) => Self::ChannelStoppedByUser(ChannelStopped::parse(&fields)?),
+            (sys::MQCMD_CONFIG_EVENT, sys::MQRC_CONFIG_CREATE_OBJECT) => Self::ConfigCreate(ConfigObject::parse(&fields)?),
+            (sys::MQCMD_CONFIG_EVENT, sys::MQRC_CONFIG_CHANGE_OBJECT) => Self::ConfigChange(
+                if header.is_last() {
+                    ChangePhase::After
+                } else {
+                    ChangePhase::Before
+                },
+                ConfigObject::parse(&fields)?,
+            ),
+            (sys::MQCMD_CONFIG_EVENT, sys::MQRC_CONFIG_DELETE_OBJECT) => Self::ConfigDelete(ConfigObject::parse(&fields)?),
+            (sys::MQCMD_CONFIG_EVENT, sys::MQRC_CONFIG_REFRESH_OBJECT) => Self::ConfigRefresh(ConfigObject::parse(&fields)?),
+            (sys::MQCMD_COMMAND_EVENT, sys::MQRC_COMMAND_MQSC | sys::MQRC_COMMAND_PCF) => {
+                Self::Command(CommandEvent::parse(&fields)?)
+            }
+            _ => Self::Other(message),
+        })
+    }
+
+    /// Parse an event from the data of an event message using the `Encoding` of the message descriptor
+    pub fn from_bytes(data: &[u8], encoding: MQENC) -> Result<Self, EventError> {
+        Self::from_message(PcfMessage::from_bytes(data, encoding)?)
+    }
+}
+
+impl TryFrom<PcfMessage> for Event {
+    type Error = EventError;
+
+    fn try_from(message: PcfMessage) -> Result<Self, Self::Error> {
+        Self::from_message(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mqstr,
+        pcf::{Header, Parameters, PcfMessage},
+        sys,
+        types::{ChannelName, QueueManagerName, QueueName, UserIdentifier},
+        values::{MqaiSelector, MQAT, MQCC, MQCFC, MQCFT, MQCMD, MQENC, MQOT, MQRC},
+    };
+
+    use super::{ChangePhase, CommandData, Event, EventError, NotAuthorizedKind};
+
+    /// Queue depth high performance event captured with `MQENC_INTEGER_REVERSED`
+    const QUEUE_DEPTH_HIGH: &[u8] = &[
+        // MQCFH
+        0x07, 0, 0, 0, 0x24, 0, 0, 0, 0x02, 0, 0, 0, 0x2D, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0xB0, 0x08, 0, 0,
+        0x06, 0, 0, 0, //
+        // MQCFST MQCA_Q_MGR_NAME
+        0x04, 0, 0, 0, 0x18, 0, 0, 0, 0xDF, 0x07, 0, 0, 0xB8, 0x04, 0, 0, 0x03, 0, 0, 0, b'Q', b'M', b'1', 0, //
+        // MQCFST MQCA_BASE_OBJECT_NAME
+        0x04, 0, 0, 0, 0x1C, 0, 0, 0, 0xD2, 0x07, 0, 0, 0xB8, 0x04, 0, 0, 0x07, 0, 0, 0, b'D', b'E', b'V', b'.', b'Q', b'.', b'1',
+        0, //
+        // MQCFIN MQIA_TIME_SINCE_RESET, MQIA_HIGH_Q_DEPTH, MQIA_MSG_ENQ_COUNT and MQIA_MSG_DEQ_COUNT
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0x23, 0, 0, 0, 0x3C, 0, 0, 0, //
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0x24, 0, 0, 0, 0x50, 0, 0, 0, //
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0x25, 0, 0, 0, 0x64, 0, 0, 0, //
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0x26, 0, 0, 0, 0x14, 0, 0, 0, //
+    ];
+
+    /// Type 1 not authorized event with blank padded names in `MQENC_INTEGER_REVERSED`
+    const NOT_AUTHORIZED_CONNECT: &[u8] = &[
+        // MQCFH
+        0x07, 0, 0, 0, 0x24, 0, 0, 0, 0x02, 0, 0, 0, 0x2C, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0xF3, 0x07, 0, 0,
+        0x05, 0, 0, 0, //
+        // MQCFST MQCA_Q_MGR_NAME
+        0x04, 0, 0, 0, 0x44, 0, 0, 0, 0xDF, 0x07, 0, 0, 0xB8, 0x04, 0, 0, 0x30, 0, 0, 0, b'Q', b'M', b'1', 0x20, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, //
+        // MQCFIN MQIACF_REASON_QUALIFIER
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0xFC, 0x03, 0, 0, 0x01, 0, 0, 0, //
+        // MQCFST MQCACF_USER_IDENTIFIER
+        0x04, 0, 0, 0, 0x20, 0, 0, 0, 0xD1, 0x0B, 0, 0, 0xB8, 0x04, 0, 0, 0x0C, 0, 0, 0, b'a', b'p', b'p', 0x20, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, //
+        // MQCFIN MQIA_APPL_TYPE
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0x01, 0, 0, 0, 0x06, 0, 0, 0, //
+        // MQCFST MQCACF_APPL_NAME
+        0x04, 0, 0, 0, 0x30, 0, 0, 0, 0xD0, 0x0B, 0, 0, 0xB8, 0x04, 0, 0, 0x1C, 0, 0, 0, b'a', b'm', b'q', b's', b'p', b'u', b't',
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, //
+    ];
+
+    /// Channel stopped event with error details in `MQENC_INTEGER_REVERSED`
+    const CHANNEL_STOPPED: &[u8] = &[
+        // MQCFH
+        0x07, 0, 0, 0, 0x24, 0, 0, 0, 0x02, 0, 0, 0, 0x2E, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0xEB, 0x08, 0, 0,
+        0x09, 0, 0, 0, //
+        // MQCFST MQCA_Q_MGR_NAME
+        0x04, 0, 0, 0, 0x44, 0, 0, 0, 0xDF, 0x07, 0, 0, 0xB8, 0x04, 0, 0, 0x30, 0, 0, 0, b'Q', b'M', b'1', 0x20, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, //
+        // MQCFIN MQIACF_REASON_QUALIFIER
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0xFC, 0x03, 0, 0, 0x08, 0, 0, 0, //
+        // MQCFST MQCACH_CHANNEL_NAME
+        0x04, 0, 0, 0, 0x28, 0, 0, 0, 0xAD, 0x0D, 0, 0, 0xB8, 0x04, 0, 0, 0x14, 0, 0, 0, b'T', b'O', b'.', b'Q', b'M', b'2', 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, //
+        // MQCFIN MQIACF_ERROR_IDENTIFIER
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0xF5, 0x03, 0, 0, 0x99, 0x99, 0, 0x20, //
+        // MQCFIN MQIACF_AUX_ERROR_DATA_INT_1
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0x2E, 0x04, 0, 0, 0, 0, 0, 0, //
+        // MQCFIN MQIACF_AUX_ERROR_DATA_INT_2
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0x2F, 0x04, 0, 0, 0, 0, 0, 0, //
+        // MQCFST MQCACF_AUX_ERROR_DATA_STR_1
+        0x04, 0, 0, 0, 0x20, 0, 0, 0, 0xD2, 0x0B, 0, 0, 0xB8, 0x04, 0, 0, 0x0A, 0, 0, 0, b'h', b'o', b's', b't', b'(', b'1', b'4',
+        b'1', b'4', b')', 0, 0, //
+        // MQCFST MQCACF_AUX_ERROR_DATA_STR_2
+        0x04, 0, 0, 0, 0x14, 0, 0, 0, 0xD3, 0x0B, 0, 0, 0xB8, 0x04, 0, 0, 0, 0, 0, 0, //
+        // MQCFST MQCACF_AUX_ERROR_DATA_STR_3
+        0x04, 0, 0, 0, 0x14, 0, 0, 0, 0xD4, 0x0B, 0, 0, 0xB8, 0x04, 0, 0, 0, 0, 0, 0, //
+    ];
+
+    /// First message of a change object configuration event in `MQENC_INTEGER_REVERSED`
+    const CONFIG_CHANGE_BEFORE: &[u8] = &[
+        // MQCFH
+        0x07, 0, 0, 0, 0x24, 0, 0, 0, 0x02, 0, 0, 0, 0x2B, 0, 0, 0, 0x01, 0, 0, 0, 0, 0, 0, 0, 0x01, 0, 0, 0, 0x40, 0x09, 0, 0,
+        0x06, 0, 0, 0, //
+        // MQCFST MQCACF_EVENT_USER_ID
+        0x04, 0, 0, 0, 0x20, 0, 0, 0, 0xE5, 0x0B, 0, 0, 0xB8, 0x04, 0, 0, 0x0C, 0, 0, 0, b'a', b'd', b'm', b'i', b'n', 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, //
+        // MQCFIN MQIACF_EVENT_ORIGIN
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0xF3, 0x03, 0, 0, 0x01, 0, 0, 0, //
+        // MQCFST MQCACF_EVENT_Q_MGR
+        0x04, 0, 0, 0, 0x44, 0, 0, 0, 0xE7, 0x0B, 0, 0, 0xB8, 0x04, 0, 0, 0x30, 0, 0, 0, b'Q', b'M', b'1', 0x20, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, //
+        // MQCFIN MQIACF_OBJECT_TYPE
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0xF8, 0x03, 0, 0, 0x01, 0, 0, 0, //
+        // MQCFST MQCA_Q_NAME
+        0x04, 0, 0, 0, 0x44, 0, 0, 0, 0xE0, 0x07, 0, 0, 0xB8, 0x04, 0, 0, 0x30, 0, 0, 0, b'D', b'E', b'V', b'.', b'Q', b'.', b'1',
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x20, //
+        // MQCFIN MQIA_MAX_Q_DEPTH
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0x0F, 0, 0, 0, 0x88, 0x13, 0, 0, //
+    ];
+
+    /// MQSC command event with grouped context and data in `MQENC_INTEGER_REVERSED`
+    const COMMAND_MQSC: &[u8] = &[
+        // MQCFH
+        0x07, 0, 0, 0, 0x24, 0, 0, 0, 0x02, 0, 0, 0, 0x63, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0x6C, 0x09, 0, 0,
+        0x02, 0, 0, 0, //
+        // MQCFGR MQGACF_COMMAND_CONTEXT
+        0x14, 0, 0, 0, 0x10, 0, 0, 0, 0x41, 0x1F, 0, 0, 0x02, 0, 0, 0, //
+        // MQCFST MQCACF_EVENT_USER_ID
+        0x04, 0, 0, 0, 0x20, 0, 0, 0, 0xE5, 0x0B, 0, 0, 0xB8, 0x04, 0, 0, 0x0C, 0, 0, 0, b'a', b'd', b'm', b'i', b'n', 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, //
+        // MQCFIN MQIACF_COMMAND
+        0x03, 0, 0, 0, 0x10, 0, 0, 0, 0xFD, 0x03, 0, 0, 0x08, 0, 0, 0, //
+        // MQCFGR MQGACF_COMMAND_DATA
+        0x14, 0, 0, 0, 0x10, 0, 0, 0, 0x42, 0x1F, 0, 0, 0x01, 0, 0, 0, //
+        // MQCFST MQCACF_COMMAND_MQSC
+        0x04, 0, 0, 0, 0x2C, 0, 0, 0, 0x03, 0x0C, 0, 0, 0xB8, 0x04, 0, 0, 0x15, 0, 0, 0, b'A', b'L', b'T', b'E', b'R', 0x20, b'Q',
+        b'L', b'O', b'C', b'A', b'L', b'(', b'D', b'E', b'V', b'.', b'Q', b'.', b'1', b')', 0, 0, 0, //
+    ];
+
+    fn event(command: sys::MQLONG, reason: sys::MQLONG, parameters: Parameters) -> PcfMessage {
+        PcfMessage::new(
+            Header {
+                kind: MQCFT(sys::MQCFT_EVENT),
+                version: sys::MQCFH_VERSION_2,
+                command: MQCMD(command),
+                msg_seq_number: 1,
+                control: MQCFC(sys::MQCFC_LAST),
+                comp_code: MQCC(sys::MQCC_WARNING),
+                reason: MQRC(reason),
+            },
+            parameters,
+        )
+    }
+
+    #[test]
+    fn queue_depth_high() -> Result<(), EventError> {
+        let Event::QueueDepthHigh(performance) = Event::from_bytes(QUEUE_DEPTH_HIGH, MQENC(sys::MQENC_INTEGER_REVERSED))? else {
+            panic!("event should be queue depth high");
+        };
+        assert_eq!(performance.queue_manager, QueueManagerName(mqstr!("QM1")));
+        assert_eq!(performance.queue, QueueName(mqstr!("DEV.Q.1")));
+        assert_eq!(
+            (
+                performance.time_since_reset,
+                performance.high_depth,
+                performance.enqueue_count,
+                performance.dequeue_count
+            ),
+            (60, 80, 100, 20)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn not_authorized() -> Result<(), EventError> {
+        let Event::NotAuthorized(event) = Event::from_bytes(NOT_AUTHORIZED_CONNECT, MQENC(sys::MQENC_INTEGER_REVERSED))? else {
+            panic!("event should be not authorized");
+        };
+        assert_eq!(event.queue_manager, QueueManagerName(mqstr!("QM1")));
+        assert_eq!(event.kind, NotAuthorizedKind::Connect);
+        assert_eq!(event.user, UserIdentifier(mqstr!("app")));
+        assert_eq!(event.appl_type, MQAT(sys::MQAT_UNIX));
+        assert_eq!(event.appl_name, mqstr!("amqsput"));
+        Ok(())
+    }
+
+    #[test]
+    fn not_authorized_qualifiers() -> Result<(), EventError> {
+        let not_authorized = |qualifier: sys::MQLONG, command: sys::MQLONG| {
+            event(
+                sys::MQCMD_Q_MGR_EVENT,
+                sys::MQRC_NOT_AUTHORIZED,
+                Parameters::new()
+                    .with(MqaiSelector(sys::MQCA_Q_MGR_NAME), "QM1")
+                    .with(MqaiSelector(sys::MQIACF_REASON_QUALIFIER), qualifier)
+                    .with(MqaiSelector(sys::MQIACF_COMMAND), command)
+                    .with(MqaiSelector(sys::MQCACF_USER_IDENTIFIER), "app")
+                    .with(MqaiSelector(sys::MQIA_APPL_TYPE), sys::MQAT_UNIX)
+                    .with(MqaiSelector(sys::MQCACF_APPL_NAME), "runmqsc"),
+            )
+        };
+
+        let Event::NotAuthorized(event) = Event::from_message(not_authorized(sys::MQRQ_CMD_NOT_AUTHORIZED, sys::MQCMD_DELETE_Q))?
+        else {
+            panic!("event should be not authorized");
+        };
+        assert_eq!(
+            event.kind,
+            NotAuthorizedKind::Command {
+                command: MQCMD(sys::MQCMD_DELETE_Q)
+            }
+        );
+
+        let Event::NotAuthorized(event) = Event::from_message(not_authorized(sys::MQRQ_SUB_NOT_AUTHORIZED, sys::MQCMD_NONE))?
+        else {
+            panic!("event should be not authorized");
+        };
+        assert_eq!(event.kind, NotAuthorizedKind::Other(sys::MQRQ_SUB_NOT_AUTHORIZED));
+        Ok(())
+    }
+
+    #[test]
+    fn channel_stopped() -> Result<(), EventError> {
+        let Event::ChannelStopped(event) = Event::from_bytes(CHANNEL_STOPPED, MQENC(sys::MQENC_INTEGER_REVERSED))? else {
+            panic!("event should be channel stopped");
+        };
+        assert_eq!(event.qualifier, sys::MQRQ_CHANNEL_STOPPED_ERROR);
+        assert_eq!(event.channel, ChannelName(mqstr!("TO.QM2")));
+        let error = event.error.expect("channel stopped with an error should have error details");
+        assert_eq!(error.identifier, 0x2000_9999);
+        assert_eq!(error.aux_str, ["host(1414)".to_string(), String::new(), String::new()]);
+        assert_eq!(event.transmission_queue, None);
+        Ok(())
+    }
+
+    #[test]
+    fn config_change() -> Result<(), EventError> {
+        let Event::ConfigChange(phase, object) = Event::from_bytes(CONFIG_CHANGE_BEFORE, MQENC(sys::MQENC_INTEGER_REVERSED))?
+        else {
+            panic!("event should be change object");
+        };
+        assert_eq!(phase, ChangePhase::Before);
+        assert_eq!(object.context.user, Some(UserIdentifier(mqstr!("admin"))));
+        assert_eq!(object.context.origin, Some(sys::MQEVO_CONSOLE));
+        assert_eq!(object.object_type, MQOT(sys::MQOT_Q));
+        assert_eq!(object.object_name, Some(mqstr!("DEV.Q.1")));
+        assert_eq!(object.attributes.integer(MqaiSelector(sys::MQIA_MAX_Q_DEPTH)), Some(5000));
+        Ok(())
+    }
+
+    #[test]
+    fn command() -> Result<(), EventError> {
+        let Event::Command(event) = Event::from_bytes(COMMAND_MQSC, MQENC(sys::MQENC_INTEGER_REVERSED))? else {
+            panic!("event should be a command event");
+        };
+        assert_eq!(event.context.user, Some(UserIdentifier(mqstr!("admin"))));
+        assert_eq!(event.command, MQCMD(sys::MQCMD_CHANGE_Q));
+        assert_eq!(event.data, CommandData::Mqsc("ALTER QLOCAL(DEV.Q.1)".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Event::from_message(PcfMessage::command(MQCMD(sys::MQCMD_INQUIRE_Q))),
+            Err(EventError::NotEvent(MQCFT(sys::MQCFT_COMMAND)))
+        );
+        assert_eq!(
+            Event::from_message(event(sys::MQCMD_PERFM_EVENT, sys::MQRC_Q_FULL, Parameters::new())),
+            Err(EventError::Parameter(MqaiSelector(sys::MQCA_Q_MGR_NAME)))
+        );
+        assert!(matches!(
+            Event::from_message(event(sys::MQCMD_PERFM_EVENT, sys::MQRC_BRIDGE_STARTED, Parameters::new())),
+            Ok(Event::Other(_))
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{sys, types::ObjectName, values::MqaiSelector, MqStr};
+
+use super::{LocalTimestamp, Parameters, Value};
+
+/// Missing or malformed parameter found by [`Fields`]
+#[derive(Debug, Clone, Copy)]
+pub(super) enum FieldError {
+    /// The parameter is missing or has an unexpected type
+    Parameter(MqaiSelector),
+    /// The parameter is not UTF-8 or is too long for the target type
+    InvalidString(MqaiSelector),
+}
+
+/// Typed access to the parameters of an event or statistics message
+pub(super) struct Fields<'a>(pub(super) &'a Parameters);
+
+impl Fields<'_> {
+    pub(super) fn integer(&self, selector: sys::MQLONG) -> Result<sys::MQLONG, FieldError> {
+        self.integer_opt(selector)?
+            .ok_or(FieldError::Parameter(MqaiSelector(selector)))
+    }
+
+    pub(super) fn integer_opt(&self, selector: sys::MQLONG) -> Result<Option<sys::MQLONG>, FieldError> {
+        match self.0.get(MqaiSelector(selector)) {
+            None => Ok(None),
+            Some(&Value::Integer(value)) => Ok(Some(value)),
+            Some(_) => Err(FieldError::Parameter(MqaiSelector(selector))),
+        }
+    }
+
+    /// 64 bit integer that may be sent as either a `MQCFIN` or `MQCFIN64`
+    pub(super) fn integer64_opt(&self, selector: sys::MQLONG) -> Result<Option<i64>, FieldError> {
+        match self.0.get(MqaiSelector(selector)) {
+            None => Ok(None),
+            Some(&Value::Integer(value)) => Ok(Some(value.into())),
+            Some(&Value::Integer64(value)) => Ok(Some(value)),
+            Some(_) => Err(FieldError::Parameter(MqaiSelector(selector))),
+        }
+    }
+
+    pub(super) fn str_opt(&self, selector: sys::MQLONG) -> Result<Option<&str>, FieldError> {
+        match self.0.get(MqaiSelector(selector)) {
+            None => Ok(None),
+            Some(Value::String(value)) => std::str::from_utf8(&value.data)
+                .map(|value| Some(value.trim_end_matches([' ', '\0'])))
+                .map_err(|_| FieldError::InvalidString(MqaiSelector(selector))),
+            Some(_) => Err(FieldError::Parameter(MqaiSelector(selector))),
+        }
+    }
+
+    pub(super) fn string(&self, selector: sys::MQLONG) -> Result<String, FieldError> {
+        self.str_opt(selector)?
+            .map(str::to_string)
+            .ok_or(FieldError::Parameter(MqaiSelector(selector)))
+    }
+
+    pub(super) fn name_opt<const N: usize, T: From<MqStr<N>>>(&self, selector: sys::MQLONG) -> Result<Option<T>, FieldError> {
+        self.str_opt(selector)?
+            .map(|value| {
+                MqStr::from_bytes(value.as_bytes())
+                    .map(T::from)
+                    .map_err(|_| FieldError::InvalidString(MqaiSelector(selector)))
+            })
+            .transpose()
+    }
+
+    pub(super) fn name<const N: usize, T: From<MqStr<N>>>(&self, selector: sys::MQLONG) -> Result<T, FieldError> {
+        self.name_opt(selector)?.ok_or(FieldError::Parameter(MqaiSelector(selector)))
+    }
+
+    /// First of the `selectors` that is present
+    pub(super) fn first_name_opt(&self, selectors: &[sys::MQLONG]) -> Result<Option<ObjectName>, FieldError> {
+        for &selector in selectors {
+            if let Some(name) = self.name_opt(selector)? {
+                return Ok(Some(name));
+            }
+        }
+        Ok(None)
+    }
+
+    pub(super) fn group(&self, selector: sys::MQLONG) -> Result<&Parameters, FieldError> {
+        self.0
+            .groups(MqaiSelector(selector))
+            .next()
+            .ok_or(FieldError::Parameter(MqaiSelector(selector)))
+    }
+
+    /// Local timestamp from a `yyyy-mm-dd` date and `hh.mm.ss` time parameter pair
+    pub(super) fn local_timestamp(&self, date: sys::MQLONG, time: sys::MQLONG) -> Result<Option<LocalTimestamp>, FieldError> {
+        let (Some(date_value), Some(time_value)) = (self.str_opt(date)?, self.str_opt(time)?) else {
+            return Ok(None);
+        };
+        LocalTimestamp::parse(date_value, time_value)
+            .map(Some)
+            .ok_or(FieldError::InvalidString(MqaiSelector(date)))
+    }
+}
@@ -0,0 +1,360 @@
+//! Pure Rust encoding and decoding of Programmable Command Format (PCF) messages.
+//!
+//! Unlike the [`admin`](crate::admin) bag API, this module does not require the MQAI functions
+//! from the MQ library. A [`PcfMessage`] is a [`Header`] (`MQCFH`) followed by a tree of
+//! [`Parameters`], where groups (`MQCFGR`) are nested [`Parameters`].
+
+mod command;
+mod decode;
+mod encode;
+mod event;
+mod fields;
+mod route;
+mod statistics;
+mod timestamp;
+
+pub use command::*;
+pub use decode::*;
+pub use event::*;
+pub use route::*;
+pub use statistics::*;
+pub use timestamp::*;
+
+use std::borrow::Cow;
+
+use crate::{
+    core::mqai::Filter,
+    headers::{fmt, TextEnc},
+    put::PutMessage,
+    sys,
+    types::MessageFormat,
+    values::{MqaiSelector, CCSID, MQCC, MQCFC, MQCFT, MQCMD, MQENC, MQRC},
+    StrCcsidOwned,
+};
+
+/// PCF header (`MQCFH`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Header {
+    pub kind: MQCFT,
+    pub version: sys::MQLONG,
+    pub command: MQCMD,
+    pub msg_seq_number: sys::MQLONG,
+    pub control: MQCFC,
+    pub comp_code: MQCC,
+    pub reason: MQRC,
+}
+
+impl Header {
+    /// Header for a single message command request
+    #[must_use]
+    pub const fn command(command: MQCMD) -> Self {
+        Self {
+            kind: MQCFT(sys::MQCFT_COMMAND),
+            version: sys::MQCFH_VERSION_1,
+            command,
+            msg_seq_number: 1,
+            control: MQCFC(sys::MQCFC_LAST),
+            comp_code: MQCC(sys::MQCC_OK),
+            reason: MQRC(sys::MQRC_NONE),
+        }
+    }
+
+    /// Whether this is the last message in a set of messages
+    #[must_use]
+    pub const fn is_last(&self) -> bool {
+        self.control.0 == sys::MQCFC_LAST
+    }
+}
+
+/// Value of a PCF parameter
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::From)]
+pub enum Value {
+    /// `MQCFIN`
+    Integer(sys::MQLONG),
+    /// `MQCFIN64`
+    Integer64(i64),
+    /// `MQCFST`
+    String(StrCcsidOwned),
+    /// `MQCFBS`
+    #[from(ignore)]
+    ByteString(Vec<u8>),
+    /// `MQCFIL`
+    IntegerList(Vec<sys::MQLONG>),
+    /// `MQCFIL64`
+    Integer64List(Vec<i64>),
+    /// `MQCFSL`. All strings in the list share the CCSID.
+    #[from(ignore)]
+    StringList(CCSID, Vec<Vec<u8>>),
+    /// `MQCFGR`
+    Group(Parameters),
+    /// `MQCFIF`
+    IntegerFilter(Filter<sys::MQLONG>),
+    /// `MQCFSF`
+    StringFilter(Filter<StrCcsidOwned>),
+    /// `MQCFBF`
+    ByteStringFilter(Filter<Vec<u8>>),
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl Value {
+    /// The PCF structure type used to encode the value
+    #[must_use]
+    pub const fn kind(&self) -> MQCFT {
+        MQCFT(match self {
+            Self::Integer(_) => sys::MQCFT_INTEGER,
+            Self::Integer64(_) => sys::MQCFT_INTEGER64,
+            Self::String(_) => sys::MQCFT_STRING,
+            Self::ByteString(_) => sys::MQCFT_BYTE_STRING,
+            Self::IntegerList(_) => sys::MQCFT_INTEGER_LIST,
+            Self::Integer64List(_) => sys::MQCFT_INTEGER64_LIST,
+            Self::StringList(..) => sys::MQCFT_STRING_LIST,
+            Self::Group(_) => sys::MQCFT_GROUP,
+            Self::IntegerFilter(_) => sys::MQCFT_INTEGER_FILTER,
+            Self::StringFilter(_) => sys::MQCFT_STRING_FILTER,
+            Self::ByteStringFilter(_) => sys::MQCFT_BYTE_STRING_FILTER,
+        })
+    }
+}
+
+/// Ordered collection of PCF parameters, analogous to an MQAI bag
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parameters(Vec<(MqaiSelector, Value)>);
+
+impl Parameters {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Append a parameter
+    pub fn add(&mut self, selector: MqaiSelector, value: impl Into<Value>) -> &mut Self {
+        self.0.push((selector, value.into()));
+        self
+    }
+
+    #[must_use]
+    pub fn with(mut self, selector: MqaiSelector, value: impl Into<Value>) -> Self {
+        self.add(selector, value);
+        self
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(MqaiSelector, Value)> {
+        self.0.iter()
+    }
+
+    /// First value with the selector
+    #[must_use]
+    pub fn get(&self, selector: MqaiSelector) -> Option<&Value> {
+        self.get_all(selector).next()
+    }
+
+    /// All values with the selector, in message order
+    pub fn get_all(&self, selector: MqaiSelector) -> impl Iterator<Item = &Value> {
+        self.0.iter().filter(move |(s, _)| *s == selector).map(|(_, value)| value)
+    }
+
+    #[must_use]
+    pub fn integer(&self, selector: MqaiSelector) -> Option<sys::MQLONG> {
+        match self.get(selector)? {
+            &Value::Integer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn integer64(&self, selector: MqaiSelector) -> Option<i64> {
+        match self.get(selector)? {
+            &Value::Integer64(value) => Some(value),
+            &Value::Integer(value) => Some(value.into()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn string(&self, selector: MqaiSelector) -> Option<&StrCcsidOwned> {
+        match self.get(selector)? {
+            Value::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn byte_string(&self, selector: MqaiSelector) -> Option<&[u8]> {
+        match self.get(selector)? {
+            Value::ByteString(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// All nested groups with the selector
+    pub fn groups(&self, selector: MqaiSelector) -> impl Iterator<Item = &Self> {
+        self.get_all(selector).filter_map(|value| match value {
+            Value::Group(group) => Some(group),
+            _ => None,
+        })
+    }
+}
+
+impl FromIterator<(MqaiSelector, Value)> for Parameters {
+    fn from_iter<T: IntoIterator<Item = (MqaiSelector, Value)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Parameters {
+    type Item = (MqaiSelector, Value);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Parameters {
+    type Item = &'a (MqaiSelector, Value);
+    type IntoIter = std::slice::Iter<'a, (MqaiSelector, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// A complete PCF message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PcfMessage {
+    pub header: Header,
+    pub parameters: Parameters,
+}
+
+impl PcfMessage {
+    #[must_use]
+    pub const fn new(header: Header, parameters: Parameters) -> Self {
+        Self { header, parameters }
+    }
+
+    /// Command request message with no parameters
+    #[must_use]
+    pub const fn command(command: MQCMD) -> Self {
+        Self::new(Header::command(command), Parameters::new())
+    }
+}
+
+/// PCF messages are put in the native encoding with the `MQFMT_EVENT` format for events
+/// and `MQFMT_ADMIN` otherwise.
+impl PutMessage for PcfMessage {
+    type Data = Self;
+
+    fn render(&self) -> Cow<[u8]> {
+        Cow::Owned(self.to_bytes(MQENC(sys::MQENC_NATIVE)))
+    }
+
+    fn format(&self) -> MessageFormat {
+        MessageFormat {
+            ccsid: CCSID(1208),
+            encoding: MQENC(sys::MQENC_NATIVE),
+            fmt: TextEnc::Ascii(if self.header.kind.0 == sys::MQCFT_EVENT {
+                fmt::MQFMT_EVENT
+            } else {
+                fmt::MQFMT_ADMIN
+            }),
+        }
+    }
+}
+
+/// Whether PCF integers are little endian in the `encoding`
+const fn is_le(encoding: MQENC) -> bool {
+    (encoding.0 & sys::MQENC_INTEGER_REVERSED) != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::mqai::Filter,
+        sys,
+        values::{MqaiSelector, CCSID, MQCMD, MQENC},
+        StrCcsidOwned,
+    };
+
+    use super::{Header, Parameters, PcfMessage, Value};
+
+    fn sample() -> PcfMessage {
+        PcfMessage::new(
+            Header::command(MQCMD(sys::MQCMD_INQUIRE_Q)),
+            Parameters::new()
+                .with(MqaiSelector(sys::MQCA_Q_NAME), "APP.*")
+                .with(MqaiSelector(sys::MQIA_Q_TYPE), sys::MQQT_LOCAL)
+                .with(
+                    MqaiSelector(sys::MQIACF_Q_ATTRS),
+                    vec![sys::MQCA_Q_NAME, sys::MQIA_CURRENT_Q_DEPTH],
+                )
+                .with(MqaiSelector(sys::MQIACF_Q_MGR_ATTRS), vec![1_i64 << 40, -1])
+                .with(MqaiSelector(sys::MQBACF_CONNECTION_ID), Value::ByteString(vec![1, 2, 3]))
+                .with(
+                    MqaiSelector(sys::MQCACF_Q_NAMES),
+                    Value::StringList(CCSID(1208), vec![b"AAA".to_vec(), b"BBB".to_vec()]),
+                )
+                .with(MqaiSelector(sys::MQIA_CURRENT_Q_DEPTH), Filter::<sys::MQLONG>::greater(10))
+                .with(MqaiSelector(sys::MQCA_Q_DESC), Filter::like(StrCcsidOwned::from("Test*")))
+                .with(MqaiSelector(sys::MQBACF_CONNECTION_ID), Filter::equal(vec![9_u8]))
+                .with(
+                    MqaiSelector(sys::MQGACF_Q_STATISTICS_DATA),
+                    Parameters::new()
+                        .with(MqaiSelector(sys::MQIA_MSG_DEQ_COUNT), 12_i64)
+                        .with(MqaiSelector(sys::MQCA_Q_NAME), "QUEUE"),
+                ),
+        )
+    }
+
+    #[test]
+    fn tree() {
+        let message = sample();
+        let params = &message.parameters;
+        assert_eq!(params.len(), 10);
+        assert_eq!(params.integer(MqaiSelector(sys::MQIA_Q_TYPE)), Some(sys::MQQT_LOCAL));
+        assert_eq!(
+            params.byte_string(MqaiSelector(sys::MQBACF_CONNECTION_ID)),
+            Some(&[1, 2, 3][..])
+        );
+        assert_eq!(params.get_all(MqaiSelector(sys::MQBACF_CONNECTION_ID)).count(), 2);
+
+        let group = params
+            .groups(MqaiSelector(sys::MQGACF_Q_STATISTICS_DATA))
+            .next()
+            .expect("group should be present");
+        assert_eq!(group.integer64(MqaiSelector(sys::MQIA_MSG_DEQ_COUNT)), Some(12));
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), super::PcfError> {
+        let message = sample();
+        for encoding in [
+            MQENC(sys::MQENC_NATIVE),
+            MQENC(sys::MQENC_INTEGER_NORMAL),
+            MQENC(sys::MQENC_INTEGER_REVERSED),
+        ] {
+            let data = message.to_bytes(encoding);
+            assert_eq!(data.len() % 4, 0);
+            assert_eq!(PcfMessage::from_bytes(&data, encoding)?.to_bytes(encoding), data);
+        }
+        assert_eq!(
+            PcfMessage::from_bytes(&message.to_bytes(MQENC(sys::MQENC_NATIVE)), MQENC(sys::MQENC_NATIVE))?,
+            message
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,385 @@
+use crate::{
+    get::{GetConvert, GetWait},
+    prelude::*,
+    sys,
+    types::{ChannelName, CorrelationId, MessageFormat, MessageId, QueueManagerName, QueueName},
+    values::{MqaiSelector, CCSID, MQAT, MQCC, MQCFC, MQCFT, MQCMD, MQENC, MQGMO, MQMT, MQOPER, MQPMO, MQRC, MQRO},
+    Conn, Error, MessageDescriptor, Object, ResultComp,
+};
+
+use super::{
+    fields::{FieldError, Fields},
+    Header, LocalTimestamp, Parameters, PcfError, PcfMessage,
+};
+
+const DEFAULT_BUFFER_SIZE: usize = 1024 * 1024;
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum RouteError {
+    #[display("PCF message is not an activity report or trace-route reply: {}", _0)]
+    #[error(ignore)]
+    UnexpectedCommand(MQCMD),
+    #[display("Activity parameter {} is missing or has an unexpected type", _0)]
+    #[error(ignore)]
+    Parameter(MqaiSelector),
+    #[display("Activity parameter {} is not a valid string", _0)]
+    #[error(ignore)]
+    InvalidString(MqaiSelector),
+    #[from]
+    Pcf(PcfError),
+    #[from]
+    MQ(Error),
+}
+
+impl From<FieldError> for RouteError {
+    fn from(error: FieldError) -> Self {
+        match error {
+            FieldError::Parameter(selector) => Self::Parameter(selector),
+            FieldError::InvalidString(selector) => Self::InvalidString(selector),
+        }
+    }
+}
+
+/// Options of the `MQGACF_TRACE_ROUTE` group in a trace-route message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceRoute {
+    /// `MQROUTE_DETAIL_*` level of detail recorded for each activity
+    pub detail: sys::MQLONG,
+    /// Maximum number of activities, or `MQROUTE_UNLIMITED_ACTIVITIES`
+    pub max_activities: sys::MQLONG,
+    /// `MQROUTE_FORWARD_*` handling by queue managers that do not support trace-route messages
+    pub forwarding: sys::MQLONG,
+    /// `MQROUTE_DELIVER_*` whether the message is delivered to an application at the destination
+    pub delivery: sys::MQLONG,
+    /// `MQROUTE_ACCUMULATE_*` whether activities are accumulated in the message
+    pub accumulation: sys::MQLONG,
+}
+
+/// Medium detail and unlimited activities, forwarded only to queue managers that support trace-route
+/// messages and discarded at the destination.
+impl Default for TraceRoute {
+    fn default() -> Self {
+        Self {
+            detail: sys::MQROUTE_DETAIL_MEDIUM,
+            max_activities: sys::MQROUTE_UNLIMITED_ACTIVITIES,
+            forwarding: sys::MQROUTE_FORWARD_IF_SUPPORTED,
+            delivery: sys::MQROUTE_DELIVER_NO,
+            accumulation: sys::MQROUTE_ACCUMULATE_NONE,
+        }
+    }
+}
+
+impl TraceRoute {
+    /// The trace-route PCF message (`MQCMD_TRACE_ROUTE`)
+    #[must_use]
+    pub fn message(&self) -> PcfMessage {
+        let header = Header {
+            kind: MQCFT(sys::MQCFT_TRACE_ROUTE),
+            version: sys::MQCFH_VERSION_3,
+            command: MQCMD(sys::MQCMD_TRACE_ROUTE),
+            msg_seq_number: 1,
+            control: MQCFC(sys::MQCFC_LAST),
+            comp_code: MQCC(sys::MQCC_OK),
+            reason: MQRC(sys::MQRC_NONE),
+        };
+        let group = Parameters::new()
+            .with(MqaiSelector(sys::MQIACF_ROUTE_DETAIL), self.detail)
+            .with(MqaiSelector(sys::MQIACF_RECORDED_ACTIVITIES), 0)
+            .with(MqaiSelector(sys::MQIACF_UNRECORDED_ACTIVITIES), 0)
+            .with(MqaiSelector(sys::MQIACF_DISCONTINUITY_COUNT), 0)
+            .with(MqaiSelector(sys::MQIACF_MAX_ACTIVITIES), self.max_activities)
+            .with(MqaiSelector(sys::MQIACF_ROUTE_ACCUMULATION), self.accumulation)
+            .with(MqaiSelector(sys::MQIACF_ROUTE_FORWARDING), self.forwarding)
+            .with(MqaiSelector(sys::MQIACF_ROUTE_DELIVERY), self.delivery);
+        PcfMessage::new(header, Parameters::new().with(MqaiSelector(sys::MQGACF_TRACE_ROUTE), group))
+    }
+
+    /// Put a trace-route message on the `queue` with `MQRO_ACTIVITY`, so that activity reports are
+    /// sent to the `reply_queue`. The returned [`MessageId`] correlates the reports with [`Route::collect`].
+    pub fn put<C: Conn>(
+        &self,
+        queue: &Object<C>,
+        reply_queue: &QueueName,
+        reply_queue_manager: Option<&QueueManagerName>,
+    ) -> ResultComp<MessageId> {
+        let mut md = MessageDescriptor::new();
+        md.set_msg_type(MQMT(sys::MQMT_DATAGRAM))
+            .set_report(MQRO(sys::MQRO_ACTIVITY | sys::MQRO_DISCARD_MSG))
+            .set_reply_to_queue(Some(reply_queue))
+            .set_reply_to_queue_manager(reply_queue_manager);
+
+        queue.put_message_with::<MessageId>(
+            (
+                md,
+                MQPMO(sys::MQPMO_NO_SYNCPOINT | sys::MQPMO_NEW_MSG_ID | sys::MQPMO_FAIL_IF_QUIESCING),
+            ),
+            &self.message(),
+        )
+    }
+}
+
+/// Operation performed by an application during an activity (`MQGACF_OPERATION`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operation {
+    pub kind: MQOPER,
+    pub timestamp: Option<LocalTimestamp>,
+    pub queue_manager: QueueManagerName,
+    pub queue: Option<QueueName>,
+    pub resolved_queue: Option<QueueName>,
+    pub remote_queue: Option<QueueName>,
+    pub remote_queue_manager: Option<QueueManagerName>,
+    pub channel: Option<ChannelName>,
+    pub transmission_queue: Option<QueueName>,
+    /// `MQFB_*` feedback of a discard or report operation
+    pub feedback: Option<sys::MQLONG>,
+    /// All parameters of the operation
+    pub parameters: Parameters,
+}
+
+/// Activity performed by an application on behalf of the traced message (`MQGACF_ACTIVITY`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Activity {
+    pub appl_name: String,
+    pub appl_type: MQAT,
+    pub description: Option<String>,
+    pub operations: Vec<Operation>,
+    /// Number of activities recorded by the trace-route message when this activity was reported
+    pub recorded_activities: Option<sys::MQLONG>,
+}
+
+/// Route of a traced message, ordered from the first activity to the last
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Route {
+    pub activities: Vec<Activity>,
+}
+
+impl Operation {
+    fn parse(group: &Parameters) -> Result<Self, FieldError> {
+        let fields = &Fields(group);
+        Ok(Self {
+            kind: MQOPER(fields.integer(sys::MQIACF_OPERATION_TYPE)?),
+            timestamp: fields.local_timestamp(sys::MQCACF_OPERATION_DATE, sys::MQCACF_OPERATION_TIME)?,
+            queue_manager: fields.name(sys::MQCA_Q_MGR_NAME)?,
+            queue: fields.name_opt(sys::MQCA_Q_NAME)?,
+            resolved_queue: fields.name_opt(sys::MQCACF_RESOLVED_Q_NAME)?,
+            remote_queue: fields.name_opt(sys::MQCA_REMOTE_Q_NAME)?,
+            remote_queue_manager: fields.name_opt(sys::MQCA_REMOTE_Q_MGR_NAME)?,
+            channel: fields.name_opt(sys::MQCACH_CHANNEL_NAME)?,
+            transmission_queue: fields.name_opt(sys::MQCACH_XMIT_Q_NAME)?,
+            feedback: fields.integer_opt(sys::MQIACF_FEEDBACK)?,
+            parameters: group.clone(),
+        })
+    }
+}
+
+impl Activity {
+    fn parse(group: &Parameters) -> Result<Self, FieldError> {
+        let fields = &Fields(group);
+        Ok(Self {
+            appl_name: fields.string(sys::MQCACF_APPL_NAME)?,
+            appl_type: MQAT(fields.integer(sys::MQIA_APPL_TYPE)?),
+            description: fields.str_opt(sys::MQCACF_ACTIVITY_DESC)?.map(str::to_string),
+            operations: group
+                .groups(MqaiSelector(sys::MQGACF_OPERATION))
+                .map(Operation::parse)
+                .collect::<Result<_, _>>()?,
+            recorded_activities: fields
+                .group(sys::MQGACF_TRACE_ROUTE)
+                .ok()
+                .map(|trace_route| Fields(trace_route).integer_opt(sys::MQIACF_RECORDED_ACTIVITIES))
+                .transpose()?
+                .flatten(),
+        })
+    }
+}
+
+impl Route {
+    /// Add the activities of an activity report (`MQCMD_ACTIVITY_MSG`) or trace-route reply
+    /// (`MQCMD_TRACE_ROUTE`) to the route, keeping the activities in route order.
+    pub fn add_message(&mut self, message: &PcfMessage) -> Result<(), RouteError> {
+        match message.header.command.0 {
+            sys::MQCMD_ACTIVITY_MSG | sys::MQCMD_TRACE_ROUTE => {
+                for group in message.parameters.groups(MqaiSelector(sys::MQGACF_ACTIVITY)) {
+                    self.activities.push(Activity::parse(group)?);
+                }
+                // Reports can arrive out of order, so order by the activities recorded when each was reported
+                self.activities
+                    .sort_by_key(|activity| activity.recorded_activities.unwrap_or(sys::MQLONG::MAX));
+                Ok(())
+            }
+            _ => Err(RouteError::UnexpectedCommand(message.header.command)),
+        }
+    }
+
+    /// Collect the activity reports for the trace-route message with `message_id` from the `reply_queue`,
+    /// waiting up to `wait_interval` milliseconds for each report.
+    pub fn collect<C: Conn>(
+        reply_queue: &Object<C>,
+        message_id: &MessageId,
+        wait_interval: sys::MQLONG,
+    ) -> Result<Self, RouteError> {
+        let mut route = Self::default();
+        let mut buffer = vec![0; DEFAULT_BUFFER_SIZE];
+        // The route is complete when no further reports arrive within the wait interval
+        while let Some((data, format)) = reply_queue
+            .get_data_with::<MessageFormat, _>(
+                (
+                    CorrelationId(message_id.0),
+                    GetWait::Wait(wait_interval),
+                    GetConvert::ConvertTo(CCSID(1208), MQENC(sys::MQENC_NATIVE)),
+                    MQGMO(sys::MQGMO_NO_SYNCPOINT | sys::MQGMO_FAIL_IF_QUIESCING),
+                ),
+                buffer.as_mut_slice(),
+            )
+            .discard_warning()?
+        {
+            route.add_message(&PcfMessage::from_message(&data, &format)?)?;
+        }
+        Ok(route)
+    }
+
+    /// Every operation on the route in order
+    pub fn operations(&self) -> impl Iterator<Item = &Operation> {
+        self.activities.iter().flat_map(|activity| &activity.operations)
+    }
+
+    /// Queue managers the message passed through, in order
+    pub fn queue_managers(&self) -> impl Iterator<Item = &QueueManagerName> {
+        let mut previous = None;
+        self.operations().filter_map(move |operation| {
+            let queue_manager = &operation.queue_manager;
+            (previous.replace(queue_manager) != Some(queue_manager)).then_some(queue_manager)
+        })
+    }
+
+    /// Channels the message was sent or received on, in order
+    pub fn channels(&self) -> impl Iterator<Item = &ChannelName> {
+        self.operations().filter_map(|operation| operation.channel.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mqstr,
+        pcf::{Header, Parameters, PcfMessage},
+        sys,
+        types::{ChannelName, QueueManagerName},
+        values::{MqaiSelector, MQCFT, MQCMD, MQENC, MQOPER},
+    };
+
+    use super::{Route, RouteError, TraceRoute};
+
+    fn operation(kind: sys::MQLONG, queue_manager: &str) -> Parameters {
+        Parameters::new()
+            .with(MqaiSelector(sys::MQIACF_OPERATION_TYPE), kind)
+            .with(MqaiSelector(sys::MQCACF_OPERATION_DATE), "2024-03-01")
+            .with(MqaiSelector(sys::MQCACF_OPERATION_TIME), "10.15.30")
+            .with(MqaiSelector(sys::MQCA_Q_MGR_NAME), queue_manager)
+    }
+
+    fn report(recorded: sys::MQLONG, operations: Vec<Parameters>) -> PcfMessage {
+        let mut header = Header::command(MQCMD(sys::MQCMD_ACTIVITY_MSG));
+        header.kind = MQCFT(sys::MQCFT_REPORT);
+        let activity = operations.into_iter().fold(
+            Parameters::new()
+                .with(MqaiSelector(sys::MQCACF_APPL_NAME), "amqrmppa")
+                .with(MqaiSelector(sys::MQIA_APPL_TYPE), sys::MQAT_QMGR),
+            |activity, operation| activity.with(MqaiSelector(sys::MQGACF_OPERATION), operation),
+        );
+        let activity = activity.with(
+            MqaiSelector(sys::MQGACF_TRACE_ROUTE),
+            Parameters::new().with(MqaiSelector(sys::MQIACF_RECORDED_ACTIVITIES), recorded),
+        );
+        PcfMessage::new(header, Parameters::new().with(MqaiSelector(sys::MQGACF_ACTIVITY), activity))
+    }
+
+    /// Wraps the PCF message in an `MQEPH` embedded PCF header as used by activity reports
+    fn embedded(message: &PcfMessage, encoding: MQENC) -> Vec<u8> {
+        let pcf = message.to_bytes(encoding);
+        let length = i32::try_from(32 + pcf.len()).expect("length should fit");
+        let long = |value: i32| {
+            if encoding.0 & sys::MQENC_INTEGER_REVERSED == 0 {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            }
+        };
+        [
+            b"EPH ".as_slice(),
+            &long(1),
+            &long(length),
+            &long(sys::MQENC_NATIVE),
+            &long(1208),
+            b"MQSTR   ",
+            &long(0),
+            &pcf,
+            b"original message",
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn trace_route_message() {
+        let message = TraceRoute::default().message();
+        assert_eq!(message.header.kind, MQCFT(sys::MQCFT_TRACE_ROUTE));
+        let group = message
+            .parameters
+            .groups(MqaiSelector(sys::MQGACF_TRACE_ROUTE))
+            .next()
+            .expect("trace-route group should be present");
+        assert_eq!(
+            group.integer(MqaiSelector(sys::MQIACF_ROUTE_DELIVERY)),
+            Some(sys::MQROUTE_DELIVER_NO)
+        );
+        assert_eq!(group.integer(MqaiSelector(sys::MQIACF_RECORDED_ACTIVITIES)), Some(0));
+    }
+
+    #[test]
+    fn route_order() -> Result<(), RouteError> {
+        let encoding = MQENC(sys::MQENC_INTEGER_REVERSED);
+        let receive = report(
+            2,
+            vec![
+                operation(sys::MQOPER_RECEIVE, "QM2").with(MqaiSelector(sys::MQCACH_CHANNEL_NAME), "QM1.TO.QM2"),
+                operation(sys::MQOPER_DISCARD, "QM2").with(MqaiSelector(sys::MQIACF_FEEDBACK), sys::MQFB_NOT_DELIVERED),
+            ],
+        );
+        let send = report(
+            1,
+            vec![
+                operation(sys::MQOPER_GET, "QM1"),
+                operation(sys::MQOPER_SEND, "QM1").with(MqaiSelector(sys::MQCACH_CHANNEL_NAME), "QM1.TO.QM2"),
+            ],
+        );
+
+        let mut route = Route::default();
+        for message in [receive, send] {
+            let (decoded, original) = PcfMessage::from_embedded(&embedded(&message, encoding), encoding)?;
+            assert_eq!(original, b"original message");
+            route.add_message(&decoded)?;
+        }
+
+        assert_eq!(
+            route.operations().map(|operation| operation.kind).collect::<Vec<_>>(),
+            [sys::MQOPER_GET, sys::MQOPER_SEND, sys::MQOPER_RECEIVE, sys::MQOPER_DISCARD].map(MQOPER)
+        );
+        assert_eq!(
+            route.queue_managers().collect::<Vec<_>>(),
+            [&QueueManagerName(mqstr!("QM1")), &QueueManagerName(mqstr!("QM2"))]
+        );
+        assert_eq!(route.channels().count(), 2);
+        assert_eq!(route.channels().next(), Some(&ChannelName(mqstr!("QM1.TO.QM2"))));
+        assert_eq!(
+            route.operations().last().and_then(|operation| operation.feedback),
+            Some(sys::MQFB_NOT_DELIVERED)
+        );
+        assert!(route.operations().all(|operation| operation.timestamp.is_some()));
+
+        assert!(matches!(
+            route.add_message(&PcfMessage::command(MQCMD(sys::MQCMD_INQUIRE_Q))),
+            Err(RouteError::UnexpectedCommand(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,647 @@
+use std::ops::Add;
+
+use crate::{
+    get::GetConvert,
+    mqstr,
+    prelude::*,
+    sys,
+    types::{ChannelName, ConnectionName, Identifier, MessageFormat, QueueManagerName, QueueName, UserIdentifier},
+    values::{MqaiSelector, CCSID, MQCMD, MQENC, MQGMO, MQQT},
+    Conn, ConnectionId, Error, Object,
+};
+
+use super::{
+    fields::{FieldError, Fields},
+    ApplName, LocalTimestamp, Parameters, PcfError, PcfMessage, Value,
+};
+
+/// Queue receiving MQI and queue accounting messages
+pub const ACCOUNTING_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.ADMIN.ACCOUNTING.QUEUE"));
+/// Queue receiving MQI, queue and channel statistics messages
+pub const STATISTICS_QUEUE: QueueName = QueueName(mqstr!("SYSTEM.ADMIN.STATISTICS.QUEUE"));
+
+const DEFAULT_BUFFER_SIZE: usize = 1024 * 1024;
+
+#[derive(derive_more::Error, derive_more::Display, derive_more::From, Debug)]
+pub enum StatisticsError {
+    #[display("PCF message is not an accounting or statistics record: {}", _0)]
+    #[error(ignore)]
+    UnexpectedCommand(MQCMD),
+    #[display("Record parameter {} is missing or has an unexpected type", _0)]
+    #[error(ignore)]
+    Parameter(MqaiSelector),
+    #[display("Record parameter {} is not a valid string", _0)]
+    #[error(ignore)]
+    InvalidString(MqaiSelector),
+    #[from]
+    Pcf(PcfError),
+    #[from]
+    MQ(Error),
+}
+
+impl From<FieldError> for StatisticsError {
+    fn from(error: FieldError) -> Self {
+        match error {
+            FieldError::Parameter(selector) => Self::Parameter(selector),
+            FieldError::InvalidString(selector) => Self::InvalidString(selector),
+        }
+    }
+}
+
+/// Accounting or statistics record parsed from a PCF message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Record {
+    /// `MQCMD_ACCOUNTING_MQI`
+    MqiAccounting(MqiAccounting),
+    /// `MQCMD_ACCOUNTING_Q`
+    QueueAccounting(QueueAccounting),
+    /// `MQCMD_STATISTICS_MQI`
+    MqiStatistics(MqiStatistics),
+    /// `MQCMD_STATISTICS_Q`
+    QueueStatistics(QueueStatistics),
+    /// `MQCMD_STATISTICS_CHANNEL`
+    ChannelStatistics(ChannelStatistics),
+}
+
+/// Value of an `MQIAMO_*` array indexed by message persistence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ByPersistence<T> {
+    pub non_persistent: T,
+    pub persistent: T,
+}
+
+impl<T: Add<Output = T> + Copy> ByPersistence<T> {
+    /// Sum of the non-persistent and persistent values
+    #[must_use]
+    pub fn total(&self) -> T {
+        self.non_persistent + self.persistent
+    }
+}
+
+/// Start and end of the interval a record covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: LocalTimestamp,
+    pub end: LocalTimestamp,
+}
+
+/// Application connection an accounting record belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountingConnection {
+    pub connection_id: ConnectionId,
+    /// Sequence number of the record within the connection
+    pub sequence_number: sys::MQLONG,
+    pub appl_name: ApplName,
+    pub process_id: sys::MQLONG,
+    pub thread_id: sys::MQLONG,
+    pub user: UserIdentifier,
+    pub connected: Option<LocalTimestamp>,
+    pub disconnected: Option<LocalTimestamp>,
+    pub channel: Option<ChannelName>,
+    pub connection_name: Option<ConnectionName>,
+}
+
+/// MQI operation counts shared by MQI accounting and statistics records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MqiOperations {
+    pub opens: i64,
+    pub closes: i64,
+    pub puts: ByPersistence<i64>,
+    pub puts_failed: i64,
+    pub put1s: ByPersistence<i64>,
+    pub put1s_failed: i64,
+    pub put_bytes: ByPersistence<i64>,
+    pub gets: ByPersistence<i64>,
+    pub gets_failed: i64,
+    pub get_bytes: ByPersistence<i64>,
+    pub browses: ByPersistence<i64>,
+    pub browses_failed: i64,
+    pub browse_bytes: ByPersistence<i64>,
+    pub commits: i64,
+    pub backouts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqiAccounting {
+    pub queue_manager: QueueManagerName,
+    pub interval: Interval,
+    pub connection: AccountingConnection,
+    pub operations: MqiOperations,
+    /// All parameters of the record
+    pub parameters: Parameters,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueAccounting {
+    pub queue_manager: QueueManagerName,
+    pub interval: Interval,
+    pub connection: AccountingConnection,
+    /// One entry for each queue the connection used in the interval
+    pub queues: Vec<QueueAccountingData>,
+}
+
+/// `MQGACF_Q_ACCOUNTING_DATA` group of a queue accounting record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueAccountingData {
+    pub queue: QueueName,
+    pub queue_type: MQQT,
+    pub opens: i64,
+    pub puts: ByPersistence<i64>,
+    pub puts_failed: i64,
+    pub put1s: ByPersistence<i64>,
+    pub put_bytes: ByPersistence<i64>,
+    pub gets: ByPersistence<i64>,
+    pub gets_failed: i64,
+    pub get_bytes: ByPersistence<i64>,
+    pub browses: ByPersistence<i64>,
+    pub browse_bytes: ByPersistence<i64>,
+    /// Minimum, average and maximum microseconds a retrieved message was on the queue
+    pub time_on_queue: [ByPersistence<i64>; 3],
+    /// All parameters of the group
+    pub parameters: Parameters,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqiStatistics {
+    pub queue_manager: QueueManagerName,
+    pub interval: Interval,
+    pub connections: i64,
+    pub connections_failed: i64,
+    pub connections_max: i64,
+    pub disconnects: i64,
+    pub operations: MqiOperations,
+    pub expired: ByPersistence<i64>,
+    pub purged: i64,
+    /// All parameters of the record
+    pub parameters: Parameters,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueStatistics {
+    pub queue_manager: QueueManagerName,
+    pub interval: Interval,
+    /// One entry for each queue that was used in the interval
+    pub queues: Vec<QueueStatisticsData>,
+}
+
+/// `MQGACF_Q_STATISTICS_DATA` group of a queue statistics record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueStatisticsData {
+    pub queue: QueueName,
+    pub queue_type: MQQT,
+    pub min_depth: i64,
+    pub max_depth: i64,
+    /// Average microseconds a retrieved message was on the queue
+    pub average_time_on_queue: ByPersistence<i64>,
+    pub puts: ByPersistence<i64>,
+    pub puts_failed: i64,
+    pub put1s: ByPersistence<i64>,
+    pub put_bytes: ByPersistence<i64>,
+    pub gets: ByPersistence<i64>,
+    pub gets_failed: i64,
+    pub get_bytes: ByPersistence<i64>,
+    pub browses: ByPersistence<i64>,
+    pub browse_bytes: ByPersistence<i64>,
+    pub not_queued: i64,
+    pub expired: i64,
+    pub purged: i64,
+    /// All parameters of the group
+    pub parameters: Parameters,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelStatistics {
+    pub queue_manager: QueueManagerName,
+    pub interval: Interval,
+    /// One entry for each channel instance that was active in the interval
+    pub channels: Vec<ChannelStatisticsData>,
+}
+
+/// `MQGACF_CHL_STATISTICS_DATA` group of a channel statistics record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelStatisticsData {
+    pub channel: ChannelName,
+    /// `MQCHT_*` channel type
+    pub channel_type: sys::MQLONG,
+    pub remote_queue_manager: Option<QueueManagerName>,
+    pub connection_name: Option<ConnectionName>,
+    pub messages: i64,
+    pub bytes: i64,
+    pub full_batches: i64,
+    pub incomplete_batches: i64,
+    pub average_batch_size: i64,
+    pub put_retries: i64,
+    /// All parameters of the group
+    pub parameters: Parameters,
+}
+
+/// Value of an `MQIAMO_*` count, or zero when it is absent. Counts reported as an array, such as
+/// opens indexed by object type, are summed.
+fn count(fields: &Fields, selector: sys::MQLONG) -> Result<i64, FieldError> {
+    Ok(match fields.0.get(MqaiSelector(selector)) {
+        Some(Value::IntegerList(values)) => values.iter().copied().map(i64::from).sum(),
+        Some(Value::Integer64List(values)) => values.iter().sum(),
+        _ => fields.integer64_opt(selector)?.unwrap_or(0),
+    })
+}
+
+/// Value of an `MQIAMO_*` or `MQIAMO64_*` array indexed by persistence, or zero when it is absent
+fn persistence(fields: &Fields, selector: sys::MQLONG) -> Result<ByPersistence<i64>, FieldError> {
+    let pair = match fields.0.get(MqaiSelector(selector)) {
+        None => return Ok(ByPersistence::default()),
+        Some(Value::IntegerList(values)) => match *values.as_slice() {
+            [non_persistent, persistent] => Some((i64::from(non_persistent), i64::from(persistent))),
+            _ => None,
+        },
+        Some(Value::Integer64List(values)) => match *values.as_slice() {
+            [non_persistent, persistent] => Some((non_persistent, persistent)),
+            _ => None,
+        },
+        Some(_) => None,
+    };
+    pair.map(|(non_persistent, persistent)| ByPersistence {
+        non_persistent,
+        persistent,
+    })
+    .ok_or(FieldError::Parameter(MqaiSelector(selector)))
+}
+
+impl Interval {
+    fn parse(fields: &Fields) -> Result<Self, FieldError> {
+        Ok(Self {
+            start: fields
+                .local_timestamp(sys::MQCAMO_START_DATE, sys::MQCAMO_START_TIME)?
+                .ok_or(FieldError::Parameter(MqaiSelector(sys::MQCAMO_START_DATE)))?,
+            end: fields
+                .local_timestamp(sys::MQCAMO_END_DATE, sys::MQCAMO_END_TIME)?
+                .ok_or(FieldError::Parameter(MqaiSelector(sys::MQCAMO_END_DATE)))?,
+        })
+    }
+}
+
+impl AccountingConnection {
+    fn parse(fields: &Fields) -> Result<Self, FieldError> {
+        let connection_id = fields
+            .0
+            .byte_string(MqaiSelector(sys::MQBACF_CONNECTION_ID))
+            .and_then(|id| id.try_into().ok())
+            .map(|id| ConnectionId(Identifier(id)))
+            .ok_or(FieldError::Parameter(MqaiSelector(sys::MQBACF_CONNECTION_ID)))?;
+        Ok(Self {
+            connection_id,
+            sequence_number: fields.integer(sys::MQIACF_SEQUENCE_NUMBER)?,
+            appl_name: fields.name(sys::MQCACF_APPL_NAME)?,
+            process_id: fields.integer(sys::MQIACF_PROCESS_ID)?,
+            thread_id: fields.integer(sys::MQIACF_THREAD_ID)?,
+            user: fields.name(sys::MQCACF_USER_IDENTIFIER)?,
+            connected: fields.local_timestamp(sys::MQCAMO_CONN_DATE, sys::MQCAMO_CONN_TIME)?,
+            disconnected: fields.local_timestamp(sys::MQCAMO_DISC_DATE, sys::MQCAMO_DISC_TIME)?,
+            channel: fields.name_opt(sys::MQCACH_CHANNEL_NAME)?,
+            connection_name: fields.name_opt(sys::MQCACH_CONNECTION_NAME)?,
+        })
+    }
+}
+
+impl MqiOperations {
+    fn parse(fields: &Fields) -> Result<Self, FieldError> {
+        Ok(Self {
+            opens: count(fields, sys::MQIAMO_OPENS)?,
+            closes: count(fields, sys::MQIAMO_CLOSES)?,
+            puts: persistence(fields, sys::MQIAMO_PUTS)?,
+            puts_failed: count(fields, sys::MQIAMO_PUTS_FAILED)?,
+            put1s: persistence(fields, sys::MQIAMO_PUT1S)?,
+            put1s_failed: count(fields, sys::MQIAMO_PUT1S_FAILED)?,
+            put_bytes: persistence(fields, sys::MQIAMO64_PUT_BYTES)?,
+            gets: persistence(fields, sys::MQIAMO_GETS)?,
+            gets_failed: count(fields, sys::MQIAMO_GETS_FAILED)?,
+            get_bytes: persistence(fields, sys::MQIAMO64_GET_BYTES)?,
+            browses: persistence(fields, sys::MQIAMO_BROWSES)?,
+            browses_failed: count(fields, sys::MQIAMO_BROWSES_FAILED)?,
+            browse_bytes: persistence(fields, sys::MQIAMO64_BROWSE_BYTES)?,
+            commits: count(fields, sys::MQIAMO_COMMITS)?,
+            backouts: count(fields, sys::MQIAMO_BACKOUTS)?,
+        })
+    }
+}
+
+impl QueueAccountingData {
+    fn parse(group: &Parameters) -> Result<Self, FieldError> {
+        let fields = &Fields(group);
+        Ok(Self {
+            queue: fields.name(sys::MQCA_Q_NAME)?,
+            queue_type: MQQT(fields.integer(sys::MQIA_Q_TYPE)?),
+            opens: count(fields, sys::MQIAMO_OPENS)?,
+            puts: persistence(fields, sys::MQIAMO_PUTS)?,
+            puts_failed: count(fields, sys::MQIAMO_PUTS_FAILED)?,
+            put1s: persistence(fields, sys::MQIAMO_PUT1S)?,
+            put_bytes: persistence(fields, sys::MQIAMO64_PUT_BYTES)?,
+            gets: persistence(fields, sys::MQIAMO_GETS)?,
+            gets_failed: count(fields, sys::MQIAMO_GETS_FAILED)?,
+            get_bytes: persistence(fields, sys::MQIAMO64_GET_BYTES)?,
+            browses: persistence(fields, sys::MQIAMO_BROWSES)?,
+            browse_bytes: persistence(fields, sys::MQIAMO64_BROWSE_BYTES)?,
+            time_on_queue: [
+                persistence(fields, sys::MQIAMO64_Q_TIME_MIN)?,
+                persistence(fields, sys::MQIAMO64_Q_TIME_AVG)?,
+                persistence(fields, sys::MQIAMO64_Q_TIME_MAX)?,
+            ],
+            parameters: group.clone(),
+        })
+    }
+}
+
+impl QueueStatisticsData {
+    fn parse(group: &Parameters) -> Result<Self, FieldError> {
+        let fields = &Fields(group);
+        Ok(Self {
+            queue: fields.name(sys::MQCA_Q_NAME)?,
+            queue_type: MQQT(fields.integer(sys::MQIA_Q_TYPE)?),
+            min_depth: count(fields, sys::MQIAMO_Q_MIN_DEPTH)?,
+            max_depth: count(fields, sys::MQIAMO_Q_MAX_DEPTH)?,
+            average_time_on_queue: persistence(fields, sys::MQIAMO64_AVG_Q_TIME)?,
+            puts: persistence(fields, sys::MQIAMO_PUTS)?,
+            puts_failed: count(fields, sys::MQIAMO_PUTS_FAILED)?,
+            put1s: persistence(fields, sys::MQIAMO_PUT1S)?,
+            put_bytes: persistence(fields, sys::MQIAMO64_PUT_BYTES)?,
+            gets: persistence(fields, sys::MQIAMO_GETS)?,
+            gets_failed: count(fields, sys::MQIAMO_GETS_FAILED)?,
+            get_bytes: persistence(fields, sys::MQIAMO64_GET_BYTES)?,
+            browses: persistence(fields, sys::MQIAMO_BROWSES)?,
+            browse_bytes: persistence(fields, sys::MQIAMO64_BROWSE_BYTES)?,
+            not_queued: count(fields, sys::MQIAMO_MSGS_NOT_QUEUED)?,
+            expired: count(fields, sys::MQIAMO_MSGS_EXPIRED)?,
+            purged: count(fields, sys::MQIAMO_MSGS_PURGED)?,
+            parameters: group.clone(),
+        })
+    }
+}
+
+impl ChannelStatisticsData {
+    fn parse(group: &Parameters) -> Result<Self, FieldError> {
+        let fields = &Fields(group);
+        Ok(Self {
+            channel: fields.name(sys::MQCACH_CHANNEL_NAME)?,
+            channel_type: fields.integer(sys::MQIACH_CHANNEL_TYPE)?,
+            remote_queue_manager: fields.name_opt(sys::MQCA_REMOTE_Q_MGR_NAME)?,
+            connection_name: fields.name_opt(sys::MQCACH_CONNECTION_NAME)?,
+            messages: count(fields, sys::MQIAMO_MSGS)?,
+            bytes: count(fields, sys::MQIAMO64_BYTES)?,
+            full_batches: count(fields, sys::MQIAMO_FULL_BATCHES)?,
+            incomplete_batches: count(fields, sys::MQIAMO_INCOMPLETE_BATCHES)?,
+            average_batch_size: count(fields, sys::MQIAMO_AVG_BATCH_SIZE)?,
+            put_retries: count(fields, sys::MQIAMO_PUT_RETRIES)?,
+            parameters: group.clone(),
+        })
+    }
+}
+
+impl Record {
+    /// Parse an accounting or statistics record from a decoded PCF message
+    pub fn from_message(message: &PcfMessage) -> Result<Self, StatisticsError> {
+        let parameters = &message.parameters;
+        let fields = &Fields(parameters);
+        let queue_manager = || -> Result<QueueManagerName, FieldError> { fields.name(sys::MQCA_Q_MGR_NAME) };
+        let groups = |selector| parameters.groups(MqaiSelector(selector));
+
+        Ok(match message.header.command.0 {
+            sys::MQCMD_ACCOUNTING_MQI => Self::MqiAccounting(MqiAccounting {
+                queue_manager: queue_manager()?,
+                interval: Interval::parse(fields)?,
+                connection: AccountingConnection::parse(fields)?,
+                operations: MqiOperations::parse(fields)?,
+                parameters: parameters.clone(),
+            }),
+            sys::MQCMD_ACCOUNTING_Q => Self::QueueAccounting(QueueAccounting {
+                queue_manager: queue_manager()?,
+                interval: Interval::parse(fields)?,
+                connection: AccountingConnection::parse(fields)?,
+                queues: groups(sys::MQGACF_Q_ACCOUNTING_DATA)
+                    .map(QueueAccountingData::parse)
+                    .collect::<Result<_, _>>()?,
+            }),
+            sys::MQCMD_STATISTICS_MQI => Self::MqiStatistics(MqiStatistics {
+                queue_manager: queue_manager()?,
+                interval: Interval::parse(fields)?,
+                connections: count(fields, sys::MQIAMO_CONNS)?,
+                connections_failed: count(fields, sys::MQIAMO_CONNS_FAILED)?,
+                connections_max: count(fields, sys::MQIAMO_CONNS_MAX)?,
+                // Sum of normal, implicit and queue manager initiated disconnects
+                disconnects: count(fields, sys::MQIAMO_DISCS)?,
+                operations: MqiOperations::parse(fields)?,
+                expired: persistence(fields, sys::MQIAMO_MSGS_EXPIRED)?,
+                purged: count(fields, sys::MQIAMO_MSGS_PURGED)?,
+                parameters: parameters.clone(),
+            }),
+            sys::MQCMD_STATISTICS_Q => Self::QueueStatistics(QueueStatistics {
+                queue_manager: queue_manager()?,
+                interval: Interval::parse(fields)?,
+                queues: groups(sys::MQGACF_Q_STATISTICS_DATA)
+                    .map(QueueStatisticsData::parse)
+                    .collect::<Result<_, _>>()?,
+            }),
+            sys::MQCMD_STATISTICS_CHANNEL => Self::ChannelStatistics(ChannelStatistics {
+                queue_manager: queue_manager()?,
+                interval: Interval::parse(fields)?,
+                channels: groups(sys::MQGACF_CHL_STATISTICS_DATA)
+                    .map(ChannelStatisticsData::parse)
+                    .collect::<Result<_, _>>()?,
+            }),
+            _ => return Err(StatisticsError::UnexpectedCommand(message.header.command)),
+        })
+    }
+
+    /// Parse a record from the data of a message using the `Encoding` of the message descriptor
+    pub fn from_bytes(data: &[u8], encoding: MQENC) -> Result<Self, StatisticsError> {
+        Self::from_message(&PcfMessage::from_bytes(data, encoding)?)
+    }
+}
+
+/// Iterator that destructively reads the accounting or statistics messages currently on a queue.
+///
+/// Iteration ends when the queue is empty or after an MQ error.
+#[derive(Debug)]
+pub struct Records<'o, C: Conn> {
+    queue: &'o Object<C>,
+    buffer: Vec<u8>,
+    finished: bool,
+}
+
+impl<'o, C: Conn> Records<'o, C> {
+    /// Read records from a `queue` opened for input
+    #[must_use]
+    pub fn new(queue: &'o Object<C>) -> Self {
+        Self::with_buffer_size(queue, DEFAULT_BUFFER_SIZE)
+    }
+
+    /// Read records with a buffer sized for the largest expected message
+    #[must_use]
+    pub fn with_buffer_size(queue: &'o Object<C>, buffer_size: usize) -> Self {
+        Self {
+            queue,
+            buffer: vec![0; buffer_size],
+            finished: false,
+        }
+    }
+}
+
+impl<C: Conn> Iterator for Records<'_, C> {
+    type Item = Result<Record, StatisticsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let message = self
+            .queue
+            .get_data_with::<MessageFormat, _>(
+                (
+                    GetConvert::ConvertTo(CCSID(1208), MQENC(sys::MQENC_NATIVE)),
+                    MQGMO(sys::MQGMO_NO_SYNCPOINT | sys::MQGMO_FAIL_IF_QUIESCING),
+                ),
+                self.buffer.as_mut_slice(),
+            )
+            .discard_warning();
+
+        match message {
+            Ok(Some((data, format))) => Some(
+                PcfMessage::from_message(&data, &format)
+                    .map_err(StatisticsError::from)
+                    .and_then(|message| Record::from_message(&message)),
+            ),
+            Ok(None) => {
+                self.finished = true;
+                None
+            }
+            Err(error) => {
+                self.finished = true;
+                Some(Err(error.into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mqstr,
+        pcf::{Header, LocalTimestamp, Parameters, PcfMessage, Value},
+        sys,
+        types::{ChannelName, QueueName},
+        values::{MqaiSelector, MQCMD, MQENC},
+    };
+
+    use super::{ByPersistence, Record, StatisticsError};
+
+    fn header(parameters: Parameters) -> Parameters {
+        Parameters::new()
+            .with(MqaiSelector(sys::MQCA_Q_MGR_NAME), "QM1")
+            .with(MqaiSelector(sys::MQCAMO_START_DATE), "2024-03-01")
+            .with(MqaiSelector(sys::MQCAMO_START_TIME), "10.00.00")
+            .with(MqaiSelector(sys::MQCAMO_END_DATE), "2024-03-01")
+            .with(MqaiSelector(sys::MQCAMO_END_TIME), "10.30.00")
+            .into_iter()
+            .chain(parameters)
+            .collect()
+    }
+
+    fn record(command: sys::MQLONG, parameters: Parameters) -> Result<Record, StatisticsError> {
+        let mut header_structure = Header::command(MQCMD(command));
+        header_structure.kind.0 = sys::MQCFT_STATISTICS;
+        let message = PcfMessage::new(header_structure, header(parameters));
+        Record::from_bytes(&message.to_bytes(MQENC(sys::MQENC_NATIVE)), MQENC(sys::MQENC_NATIVE))
+    }
+
+    #[test]
+    fn queue_statistics() -> Result<(), StatisticsError> {
+        let Record::QueueStatistics(statistics) = record(
+            sys::MQCMD_STATISTICS_Q,
+            Parameters::new().with(
+                MqaiSelector(sys::MQGACF_Q_STATISTICS_DATA),
+                Parameters::new()
+                    .with(MqaiSelector(sys::MQCA_Q_NAME), "DEV.Q.1")
+                    .with(MqaiSelector(sys::MQIA_Q_TYPE), sys::MQQT_LOCAL)
+                    .with(MqaiSelector(sys::MQIAMO_Q_MAX_DEPTH), 12)
+                    .with(MqaiSelector(sys::MQIAMO_PUTS), vec![3, 4])
+                    .with(MqaiSelector(sys::MQIAMO64_PUT_BYTES), vec![300_i64, 4_000_000_000]),
+            ),
+        )?
+        else {
+            panic!("record should be queue statistics");
+        };
+
+        assert_eq!(
+            statistics.interval.start,
+            LocalTimestamp::new(2024, 3, 1, 10, 0, 0).expect("timestamp should be valid")
+        );
+        assert_eq!(statistics.interval.end.minute(), 30);
+        let [queue] = statistics.queues.as_slice() else {
+            panic!("record should contain one queue");
+        };
+        assert_eq!(queue.queue, QueueName(mqstr!("DEV.Q.1")));
+        assert_eq!(queue.max_depth, 12);
+        assert_eq!(queue.puts.total(), 7);
+        assert_eq!(
+            queue.put_bytes,
+            ByPersistence {
+                non_persistent: 300,
+                persistent: 4_000_000_000
+            }
+        );
+        assert_eq!(queue.gets, ByPersistence::default());
+        Ok(())
+    }
+
+    #[test]
+    fn mqi_accounting() -> Result<(), StatisticsError> {
+        let Record::MqiAccounting(accounting) = record(
+            sys::MQCMD_ACCOUNTING_MQI,
+            Parameters::new()
+                .with(MqaiSelector(sys::MQBACF_CONNECTION_ID), Value::ByteString(vec![7; 24]))
+                .with(MqaiSelector(sys::MQIACF_SEQUENCE_NUMBER), 2)
+                .with(MqaiSelector(sys::MQCACF_APPL_NAME), "app")
+                .with(MqaiSelector(sys::MQIACF_PROCESS_ID), 100)
+                .with(MqaiSelector(sys::MQIACF_THREAD_ID), 1)
+                .with(MqaiSelector(sys::MQCACF_USER_IDENTIFIER), "mqm")
+                .with(MqaiSelector(sys::MQCACH_CHANNEL_NAME), "DEV.APP.SVRCONN")
+                .with(MqaiSelector(sys::MQIAMO_GETS), vec![5, 0])
+                .with(MqaiSelector(sys::MQIAMO_COMMITS), 5),
+        )?
+        else {
+            panic!("record should be MQI accounting");
+        };
+
+        assert_eq!(accounting.connection.connection_id.0 .0, [7; 24]);
+        assert_eq!(accounting.connection.channel, Some(ChannelName(mqstr!("DEV.APP.SVRCONN"))));
+        assert_eq!(accounting.connection.connected, None);
+        assert_eq!(accounting.operations.gets.non_persistent, 5);
+        assert_eq!(accounting.operations.commits, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            record(sys::MQCMD_INQUIRE_Q, Parameters::new()),
+            Err(StatisticsError::UnexpectedCommand(MQCMD(sys::MQCMD_INQUIRE_Q)))
+        ));
+        assert!(matches!(
+            record(
+                sys::MQCMD_STATISTICS_MQI,
+                Parameters::new().with(MqaiSelector(sys::MQIAMO_PUTS), vec![1, 2, 3])
+            ),
+            Err(StatisticsError::Parameter(MqaiSelector(sys::MQIAMO_PUTS)))
+        ));
+
+        let message = PcfMessage::new(
+            Header::command(MQCMD(sys::MQCMD_STATISTICS_CHANNEL)),
+            Parameters::new()
+                .with(MqaiSelector(sys::MQCA_Q_MGR_NAME), "QM1")
+                .with(MqaiSelector(sys::MQCAMO_START_DATE), "yesterday")
+                .with(MqaiSelector(sys::MQCAMO_START_TIME), "10.00.00"),
+        );
+        assert!(matches!(
+            Record::from_message(&message),
+            Err(StatisticsError::InvalidString(MqaiSelector(sys::MQCAMO_START_DATE)))
+        ));
+    }
+}
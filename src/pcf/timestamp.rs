@@ -0,0 +1,120 @@
+use std::fmt::Display;
+
+use crate::is_valid_date_time;
+
+/// Date and time in the local time zone of the queue manager, with a resolution of seconds.
+///
+/// Accounting, statistics and activity report parameters do not include the time zone of the
+/// queue manager, so unlike [`PutTimestamp`](crate::PutTimestamp) there is no conversion to
+/// [`SystemTime`](std::time::SystemTime).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalTimestamp {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl LocalTimestamp {
+    /// Timestamp from its parts. Returns `None` when a part is out of range or the year is after 9999.
+    #[must_use]
+    pub const fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if is_valid_date_time(year, month, day, hour, minute, second) {
+            Some(Self {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            })
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    #[must_use]
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    #[must_use]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    #[must_use]
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    #[must_use]
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    #[must_use]
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Parse a `yyyy-mm-dd` date and `hh.mm.ss` time parameter pair. Separators are ignored.
+    pub(super) fn parse(date: &str, time: &str) -> Option<Self> {
+        let digits = |value: &str| {
+            value
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .map(|digit| digit - b'0')
+                .collect::<Vec<_>>()
+        };
+        let number = |digits: &[u8]| digits.iter().fold(0, |acc: u16, &digit| acc * 10 + u16::from(digit));
+        let (date, time) = (digits(date), digits(time));
+        if date.len() != 8 || time.len() != 6 {
+            return None;
+        }
+        Self::new(
+            number(&date[..4]),
+            number(&date[4..6]).try_into().ok()?,
+            number(&date[6..]).try_into().ok()?,
+            number(&time[..2]).try_into().ok()?,
+            number(&time[2..4]).try_into().ok()?,
+            number(&time[4..]).try_into().ok()?,
+        )
+    }
+}
+
+impl Display for LocalTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LocalTimestamp;
+
+    #[test]
+    fn parse() {
+        let timestamp = LocalTimestamp::parse("2024-02-29", "23.59.58").expect("timestamp should parse");
+        assert_eq!(LocalTimestamp::new(2024, 2, 29, 23, 59, 58), Some(timestamp));
+        assert_eq!(timestamp.to_string(), "2024-02-29T23:59:58");
+
+        assert_eq!(LocalTimestamp::parse("2024-02-29", "23.59"), None);
+        assert_eq!(LocalTimestamp::parse("2024-13-01", "00.00.00"), None);
+        assert_eq!(LocalTimestamp::parse("2024-02-30", "00.00.00"), None);
+        assert_eq!(LocalTimestamp::parse("2023-02-29", "00.00.00"), None);
+        assert_eq!(LocalTimestamp::new(2024, 6, 31, 0, 0, 0), None);
+        assert_eq!(LocalTimestamp::parse("", ""), None);
+    }
+}
@@ -5,3 +5,7 @@ pub use super::mqstr;
 
 #[cfg(feature = "mqai")]
 pub use super::admin::QueueManagerAdmin as _;
+#[cfg(feature = "mqai")]
+pub use super::admin::InquireAdmin as _;
+#[cfg(feature = "mqai")]
+pub use super::admin::MqscAdmin as _;
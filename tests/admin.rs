@@ -50,3 +50,126 @@ fn list_local_queues() -> Result<(), Box<dyn std::error::Error>> {
     }
     Ok(())
 }
+
+#[test]
+fn inquire_typed() -> Result<(), Box<dyn std::error::Error>> {
+    use mqi::admin::{AttributeFilter, QueueFilter};
+    use mqi::core::mqai::Filter;
+
+    let qm = mqi::connect::<ThreadNone>((
+        ApplName(mqstr!("rust_testing")),
+        MqServer::try_from("DEV.ADMIN.SVRCONN/TCP/192.168.92.15(1414)")?,
+        Credentials::user("admin", "admin"),
+    ))
+    .warn_as_error()?;
+
+    let qmgr = qm.inquire_queue_manager().warn_as_error()?;
+    println!("Queue manager: {} ({})", *qmgr.name, qmgr.command_level);
+
+    let filter = QueueFilter::new("DEV.*")
+        .with_type(values::MQQT(sys::MQQT_LOCAL))
+        .with_attribute(AttributeFilter::integer(sys::MQIA_CURRENT_Q_DEPTH, Filter::not_less(0)));
+    for queue in qm.inquire_queues(filter).warn_as_error()? {
+        println!("{} {} depth={:?}", *queue.name, queue.queue_type, queue.current_depth);
+    }
+
+    for channel in qm.inquire_channels("DEV.*").warn_as_error()? {
+        println!("{} {}", *channel.name, channel.channel_type);
+    }
+
+    for topic in qm.inquire_topics("*").warn_as_error()? {
+        println!("{} '{}'", topic.name, topic.topic_string);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn mqsc_script() -> Result<(), Box<dyn std::error::Error>> {
+    let qm = mqi::connect::<ThreadNone>((
+        ApplName(mqstr!("rust_testing")),
+        MqServer::try_from("DEV.ADMIN.SVRCONN/TCP/192.168.92.15(1414)")?,
+        Credentials::user("admin", "admin"),
+    ))
+    .warn_as_error()?;
+
+    let outputs = qm
+        .run_mqsc_script("* Display the development queues\nDISPLAY QLOCAL(DEV.*) +\n  MAXDEPTH CURDEPTH")
+        .warn_as_error()?;
+    for output in outputs {
+        println!("{}", output.command);
+        for block in output.blocks {
+            println!("{:?}: {} {:?}", block.message_id, block.message, block.value("CURDEPTH"));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn put_get_bag() -> Result<(), Box<dyn std::error::Error>> {
+    use mqi::types::{MessageId, QueueName};
+    use mqi::{get, Object};
+
+    const QUEUE: QueueName = QueueName(mqstr!("DEV.QUEUE.1"));
+
+    let qm = mqi::connect::<ThreadNone>((
+        ApplName(mqstr!("rust_testing")),
+        MqServer::try_from("DEV.APP.SVRCONN/TCP/192.168.92.15(1414)")?,
+        Credentials::user("app", "app"),
+    ))
+    .warn_as_error()?;
+    let object = Object::open(&qm, (QUEUE, values::MQOO(sys::MQOO_INPUT_AS_Q_DEF | sys::MQOO_OUTPUT)))?;
+
+    let bag = Bag::new(values::MQCBO(sys::MQCBO_USER_BAG)).warn_as_error()?;
+    bag.add(values::MqaiSelector(sys::MQCA_Q_NAME), "APP.QUEUE")?
+        .discard_warning();
+    let msgid: MessageId = object
+        .put_bag_with(values::MQPMO(sys::MQPMO_NO_SYNCPOINT), &bag)
+        .warn_as_error()?;
+    println!("Put PCF message {msgid} as {} bytes", bag.to_buffer().warn_as_error()?.len());
+
+    let received = object
+        .get_bag(
+            values::MQCBO(sys::MQCBO_USER_BAG),
+            (values::MQGMO(sys::MQGMO_NO_SYNCPOINT), get::GetWait::Wait(2000)),
+        )
+        .warn_as_error()?;
+    if let Some(received) = received {
+        print!("{}", received.tree()?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn execute_checked() -> Result<(), Box<dyn std::error::Error>> {
+    use mqi::admin::AdminError;
+
+    let qm = mqi::connect::<ThreadNone>((
+        ApplName(mqstr!("rust_testing")),
+        MqServer::try_from("DEV.ADMIN.SVRCONN/TCP/192.168.92.15(1414)")?,
+        Credentials::user("admin", "admin"),
+    ))
+    .warn_as_error()?;
+
+    let admin_bag = Bag::new(values::MQCBO(sys::MQCBO_ADMIN_BAG)).warn_as_error()?;
+    admin_bag
+        .add(values::MqaiSelector(sys::MQCA_Q_NAME), "RUST.DOES.NOT.EXIST")?
+        .discard_warning();
+    admin_bag
+        .add(values::MqaiSelector(sys::MQIA_Q_TYPE), &sys::MQQT_LOCAL)?
+        .discard_warning();
+
+    match qm.execute_checked(&admin_bag, values::MQCMD(sys::MQCMD_CHANGE_Q)) {
+        Err(AdminError::Failed(failures)) => {
+            for failure in &failures {
+                println!("{failure}");
+            }
+            assert!(!failures.is_empty());
+        }
+        other => panic!("expected failed responses: {:?}", other.map(|_| ())),
+    }
+
+    Ok(())
+}
@@ -19,8 +19,9 @@ fn qm() -> Result<(), Box<dyn Error>> {
                 | sys::MQCBDO_DEREGISTER_CALL, // | sys::MQCBDO_START_CALL
                                                // | sys::MQCBDO_STOP_CALL,
         ),
-        move |connection, options| {
+        move |connection, event, options| {
             println!("{connection:?}");
+            println!("{event:?}");
             println!("{}", values::MQCBCT(options.CallType));
             println!("{}", values::MQCS(options.State));
             println!("{}", values::MQCC(options.CompCode));
@@ -0,0 +1,64 @@
+#![cfg(all(feature = "derive", feature = "link"))]
+
+use mqi::admin::{Bag, BagMapError, FromBag, IntoBag};
+use mqi::core::mqai::Filter;
+use mqi::prelude::*;
+use mqi::types::ObjectName;
+use mqi::values::{MqaiSelector, MQCBO, MQCFOP};
+use mqi::{sys, MqStr};
+
+#[derive(Debug, PartialEq, Eq, FromBag, IntoBag)]
+struct Status {
+    #[mq(selector = "MQIACF_REASON_CODE")]
+    reason: sys::MQLONG,
+}
+
+#[derive(Debug, PartialEq, Eq, FromBag, IntoBag)]
+struct Queue {
+    #[mq(selector = "MQCA_Q_NAME")]
+    name: ObjectName,
+    #[mq(selector = "MQCA_Q_DESC")]
+    description: Option<MqStr<64>>,
+    #[mq(selector = "MQIA_CURRENT_Q_DEPTH")]
+    depth: Filter<sys::MQLONG>,
+    #[mq(selector = "MQIACF_Q_ATTRS", list)]
+    attributes: Vec<sys::MQLONG>,
+    #[mq(selector = "MQHA_BAG_HANDLE", group, list)]
+    statuses: Vec<Status>,
+    #[mq(skip)]
+    note: String,
+}
+
+#[test]
+fn round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let queue = Queue {
+        name: mqstr!("DEV.QUEUE.1"),
+        description: None,
+        depth: Filter::new(10, MQCFOP(sys::MQCFOP_GREATER)),
+        attributes: vec![sys::MQCA_Q_NAME, sys::MQIA_CURRENT_Q_DEPTH],
+        statuses: vec![
+            Status { reason: sys::MQRC_NONE },
+            Status {
+                reason: sys::MQRC_Q_FULL,
+            },
+        ],
+        note: String::new(),
+    };
+
+    let bag = Bag::new(MQCBO(sys::MQCBO_USER_BAG)).warn_as_error()?;
+    queue.to_bag(&bag)?;
+    assert_eq!(Queue::from_bag(&bag)?, queue);
+
+    Ok(())
+}
+
+#[test]
+fn missing() -> Result<(), Box<dyn std::error::Error>> {
+    let bag = Bag::new(MQCBO(sys::MQCBO_USER_BAG)).warn_as_error()?;
+    assert!(matches!(
+        Status::from_bag(&bag),
+        Err(BagMapError::Missing(MqaiSelector(sys::MQIACF_REASON_CODE)))
+    ));
+
+    Ok(())
+}
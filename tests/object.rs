@@ -12,9 +12,9 @@ use mqi::attribute::{AttributeType, AttributeValue, InqResItem};
 use mqi::values::{self, CCSID};
 use mqi::open_options::SelectionString;
 use mqi::properties_options::{Attributes, Metadata, Name};
-use mqi::types::{MessageFormat, MessageId, QueueManagerName, QueueName};
+use mqi::types::{BackoutCount, CorrelationId, MessageFormat, MessageId, QueueManagerName, QueueName, ReplyTo};
 use mqi::{get, Properties};
-use mqi::{attribute, sys, Object};
+use mqi::{attribute, sys, MessageDescriptor, Object, Persistence, Priority, PutTimestamp};
 
 #[test]
 fn object() {
@@ -154,3 +154,32 @@ fn transaction() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn message_descriptor() -> Result<(), Box<dyn Error>> {
+    const QUEUE: QueueName = QueueName(mqstr!("DEV.QUEUE.1"));
+    const REPLY_QUEUE: QueueName = QueueName(mqstr!("DEV.QUEUE.2"));
+
+    let connection = mqi::connect_lib::<ThreadNone, _>(mq_library(), credentials_app()).warn_as_error()?;
+    let object = Object::open(connection, (QUEUE, values::MQOO(sys::MQOO_OUTPUT | sys::MQOO_INPUT_AS_Q_DEF))).warn_as_error()?;
+
+    let mut md = MessageDescriptor::new();
+    md.set_persistence(Persistence::NotPersistent)
+        .set_priority(Priority::new(3).expect("priority should be valid"))
+        .set_reply_to_queue(Some(&REPLY_QUEUE));
+    let (msgid, put_timestamp) = object
+        .put_message_with::<(MessageId, Option<PutTimestamp>)>(md, "descriptor")
+        .warn_as_error()?;
+    println!("Put timestamp: {put_timestamp:?}");
+
+    let msg = object
+        .get_data_with::<(CorrelationId, BackoutCount, ReplyTo, Priority, Persistence), _>(msgid, vec![0; 1024])
+        .warn_as_error()?;
+    let (_, (correl_id, backout_count, reply_to, priority, persistence)) = msg.expect("message should be available");
+    println!("CorrelationId: {correl_id}, BackoutCount: {backout_count}, ReplyTo: {reply_to:?}");
+    assert_eq!(reply_to.queue, Some(REPLY_QUEUE));
+    assert_eq!(priority, Priority::new(3).expect("priority should be valid"));
+    assert_eq!(persistence, Persistence::NotPersistent);
+
+    Ok(())
+}
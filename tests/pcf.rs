@@ -0,0 +1,34 @@
+#![cfg(feature = "pcf")]
+
+mod helpers;
+
+use helpers::mq_library;
+
+use mqi::connect_options::Credentials;
+use mqi::pcf::{CommandClient, Parameters};
+use mqi::values::{MqaiSelector, MQCMD};
+use mqi::{prelude::*, sys, ThreadNone};
+
+#[test]
+fn inquire_queue_names() -> Result<(), Box<dyn std::error::Error>> {
+    let qm = mqi::connect_lib::<ThreadNone, _>(mq_library(), Credentials::user("admin", "admin")).warn_as_error()?;
+    let client = CommandClient::open(&qm).warn_as_error()?;
+
+    let responses = client.execute(
+        MQCMD(sys::MQCMD_INQUIRE_Q_NAMES),
+        Parameters::new()
+            .with(MqaiSelector(sys::MQCA_Q_NAME), "*")
+            .with(MqaiSelector(sys::MQIA_Q_TYPE), sys::MQQT_LOCAL),
+    )?;
+
+    for response in responses {
+        let parameters = response?;
+        if let Some(mqi::pcf::Value::StringList(_, names)) = parameters.get(MqaiSelector(sys::MQCACF_Q_NAMES)) {
+            for name in names {
+                println!("Queue Name: {}", String::from_utf8_lossy(name).trim_end());
+            }
+        }
+    }
+
+    Ok(())
+}